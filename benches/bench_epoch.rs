@@ -59,6 +59,22 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         })
     });
 
+    let start = Epoch::from_gregorian_utc_at_midnight(1970, 1, 1);
+    let epochs: Vec<Epoch> = (0..10_000).map(|i| start + Unit::Hour * (i * 43)).collect();
+    let mut unix_s = vec![0.0; epochs.len()];
+
+    c.bench_function("UNIX seconds of 10k sorted epochs, one by one", |b| {
+        b.iter(|| {
+            for (epoch, out) in black_box(&epochs).iter().zip(unix_s.iter_mut()) {
+                *out = epoch.to_unix_seconds();
+            }
+        })
+    });
+
+    c.bench_function("UNIX seconds of 10k sorted epochs, batch", |b| {
+        b.iter(|| Epoch::to_unix_seconds_batch(black_box(&epochs), &mut unix_s))
+    });
+
     #[cfg(feature = "std")]
     {
         c.bench_function("RFC3339 with seconds", |b| {
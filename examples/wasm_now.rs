@@ -0,0 +1,16 @@
+//! Prints the current time using `Epoch::now()`.
+//!
+//! With the `wasm` feature, this example also works on `wasm32-unknown-unknown` (e.g. in a browser), where the time is
+//! read from `Date.now()` instead of `SystemTime::now()`, which would panic:
+//!
+//! ```text
+//! cargo build --example wasm_now --features wasm --target wasm32-unknown-unknown
+//! ```
+//! On other targets, it can simply be run with `cargo run --example wasm_now --features wasm`.
+
+use hifitime::Epoch;
+
+fn main() {
+    let now = Epoch::now().expect("could not get the current time");
+    println!("{now}");
+}
@@ -0,0 +1,62 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2022 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use crate::{Epoch, Errors};
+
+/// A source of the current epoch.
+///
+/// Code parameterized over a clock, e.g. via `Epoch::now_with`, uses the `SystemClock` in production,
+/// and can be tested deterministically with a `FixedClock` instead of the real system time.
+/// This trait does not require any allocation, so it can be implemented in `no_std` environments, e.g. on top of a hardware clock.
+///
+/// # Example
+/// ```
+/// use hifitime::{Clock, Epoch, Errors, FixedClock, TimeUnits};
+///
+/// fn is_expired(deadline: Epoch, clock: &impl Clock) -> Result<bool, Errors> {
+///     Ok(Epoch::now_with(clock)? >= deadline)
+/// }
+///
+/// let deadline = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+/// assert!(!is_expired(deadline, &FixedClock(deadline - 1.seconds())).unwrap());
+/// assert!(is_expired(deadline, &FixedClock(deadline)).unwrap());
+/// ```
+pub trait Clock {
+    /// Returns the current epoch of this clock.
+    fn now(&self) -> Result<Epoch, Errors>;
+}
+
+impl<T: Clock + ?Sized> Clock for &T {
+    fn now(&self) -> Result<Epoch, Errors> {
+        (**self).now()
+    }
+}
+
+/// The system wall clock, i.e. `Epoch::now()`.
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> Result<Epoch, Errors> {
+        Epoch::now()
+    }
+}
+
+/// A clock which always returns the same epoch, e.g. for reproducible tests.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FixedClock(pub Epoch);
+
+impl Clock for FixedClock {
+    fn now(&self) -> Result<Epoch, Errors> {
+        Ok(self.0)
+    }
+}
@@ -29,6 +29,16 @@ impl Duration {
 }
 
 impl Epoch {
+    #[must_use]
+    #[deprecated(
+        note = "Prefer with_time_scale, which makes clear that only the time scale label changes",
+        since = "4.0.0"
+    )]
+    /// Copies this epoch and sets it to the new time scale provided.
+    pub fn in_time_scale(&self, new_time_scale: TimeScale) -> Self {
+        self.with_time_scale(new_time_scale)
+    }
+
     #[must_use]
     /// Get the accumulated number of leap seconds up to this Epoch accounting only for the IERS leap seconds.
     /// For the leap seconds _and_ the scaling in "prehistoric" times from 1960 to 1972, use `leap_seconds()`.
@@ -29,9 +29,15 @@ use core::str::FromStr;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "python")]
+use pyo3::exceptions::PyZeroDivisionError;
+
 #[cfg(feature = "python")]
 use pyo3::pyclass::CompareOp;
 
+#[cfg(feature = "python")]
+use std::collections::hash_map::DefaultHasher;
+
 #[cfg(not(feature = "std"))]
 use num_traits::Float;
 
@@ -60,7 +66,7 @@ pub const NANOSECONDS_PER_CENTURY: u64 = DAYS_PER_CENTURY_U64 * NANOSECONDS_PER_
 /// 2. It was also decided that opposite durations are equal, e.g. -15 minutes == 15 minutes. If the direction of time matters, use the signum function.
 #[derive(Clone, Copy, Debug, PartialOrd, Eq, Ord)]
 #[repr(C)]
-#[cfg_attr(feature = "python", pyclass)]
+#[cfg_attr(feature = "python", pyclass(module = "hifitime"))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Duration {
     pub(crate) centuries: i16,
@@ -78,6 +84,24 @@ impl Arbitrary for Duration {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Duration {
+    /// Generates a normalized duration: the nanoseconds are always strictly less than one century.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let centuries: i16 = u.arbitrary()?;
+        let nanoseconds: u64 = u.int_in_range(0..=NANOSECONDS_PER_CENTURY - 1)?;
+
+        Ok(Duration::from_parts(centuries, nanoseconds))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(
+            <i16 as arbitrary::Arbitrary>::size_hint(depth),
+            <u64 as arbitrary::Arbitrary>::size_hint(depth),
+        )
+    }
+}
+
 impl PartialEq for Duration {
     fn eq(&self, other: &Self) -> bool {
         if self.centuries == other.centuries {
@@ -99,10 +123,11 @@ impl PartialEq for Duration {
     }
 }
 
+/// Hashes the magnitude of the duration in nanoseconds: durations around the zero crossing are equal to their opposite
+/// (cf. the conventions of `Duration`), so hashing the centuries and nanoseconds would break the consistency with the equality.
 impl Hash for Duration {
     fn hash<H: Hasher>(&self, hasher: &mut H) {
-        self.centuries.hash(hasher);
-        self.nanoseconds.hash(hasher);
+        self.total_nanoseconds().unsigned_abs().hash(hasher);
     }
 }
 
@@ -112,6 +137,47 @@ impl Default for Duration {
     }
 }
 
+impl num_traits::Zero for Duration {
+    fn zero() -> Self {
+        Duration::ZERO
+    }
+
+    /// Exact check: only a total of zero nanoseconds is zero.
+    fn is_zero(&self) -> bool {
+        self.total_nanoseconds() == 0
+    }
+}
+
+impl num_traits::Bounded for Duration {
+    fn min_value() -> Self {
+        Duration::MIN
+    }
+
+    fn max_value() -> Self {
+        Duration::MAX
+    }
+}
+
+/// Defines how a duration is rounded to a multiple of a step, cf. `Duration::round_with`.
+///
+/// The `Half*` modes round to the nearest multiple of the step and only differ in how exact ties are broken.
+/// The other modes always round in the given direction, regardless of how close the nearest multiple is.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "python", pyclass)]
+pub enum RoundMode {
+    /// Round to the nearest multiple, ties are rounded away from zero
+    #[default]
+    HalfUp,
+    /// Round to the nearest multiple, ties are rounded toward zero
+    HalfDown,
+    /// Round to the nearest multiple, ties are rounded to the even multiple (banker's rounding)
+    HalfEven,
+    /// Always round toward zero (truncation)
+    TowardZero,
+    /// Always round away from zero
+    AwayFromZero,
+}
+
 // Defines the methods that should be staticmethods in Python, but must be redefined as per https://github.com/PyO3/pyo3/issues/1003#issuecomment-844433346
 impl Duration {
     /// Builds a new duration from the number of centuries and the number of nanoseconds
@@ -176,12 +242,51 @@ impl Duration {
         }
     }
 
+    /// Creates a new duration from the provided integer number of seconds, computed exactly in nanoseconds without going through a floating point value.
+    ///
+    /// ```
+    /// use hifitime::{Duration, Unit};
+    ///
+    /// assert_eq!(Duration::from_integer_seconds(86_400), Unit::Day * 1);
+    /// assert_eq!(Duration::from_integer_seconds(-1).total_nanoseconds(), -1_000_000_000);
+    /// ```
+    #[must_use]
+    pub fn from_integer_seconds(seconds: i64) -> Self {
+        // The product always fits on an i128, and `from_total_nanoseconds` saturates if needed.
+        Self::from_total_nanoseconds(i128::from(seconds) * i128::from(NANOSECONDS_PER_SECOND))
+    }
+
     /// Creates a new duration from the provided unit
     #[must_use]
     pub fn from_f64(value: f64, unit: Unit) -> Self {
         unit * value
     }
 
+    /// Attempts to create a new duration from the provided unit, guaranteeing nanosecond precision.
+    ///
+    /// A 64-bit float represents every integer number of nanoseconds only up to 2^53 nanoseconds (about 104 days): beyond that
+    /// magnitude, this returns `Errors::PrecisionLoss` instead of silently quantizing the value. Non finite values return `Errors::Overflow`.
+    ///
+    /// ```
+    /// use hifitime::{Duration, Errors, TimeUnits, Unit};
+    ///
+    /// assert_eq!(Duration::try_from_f64(1.5, Unit::Day), Ok(36.hours()));
+    /// assert_eq!(Duration::try_from_f64(365.0, Unit::Day), Err(Errors::PrecisionLoss));
+    /// assert_eq!(Duration::try_from_f64(f64::NAN, Unit::Second), Err(Errors::Overflow));
+    /// ```
+    pub fn try_from_f64(value: f64, unit: Unit) -> Result<Self, Errors> {
+        if !value.is_finite() {
+            return Err(Errors::Overflow);
+        }
+        // Largest magnitude up to which every integer number of nanoseconds is exactly representable in an f64.
+        const MAX_EXACT_NANOSECONDS: f64 = 9_007_199_254_740_992.0; // 2^53
+        if (value * unit.in_seconds() * 1e9).abs() > MAX_EXACT_NANOSECONDS {
+            Err(Errors::PrecisionLoss)
+        } else {
+            Ok(unit * value)
+        }
+    }
+
     /// Creates a new duration from the provided number of days
     #[must_use]
     pub fn from_days(value: f64) -> Self {
@@ -280,6 +385,266 @@ impl Duration {
             dur
         }
     }
+
+    /// Creates a new duration from hours, minutes, and seconds, e.g. `Duration::from_hms(1, 30, 0)` is one hour and a half.
+    ///
+    /// The sign of the leading non-zero component applies to the whole duration, like in `-01:30:00`, so
+    /// `Duration::from_hms(-1, 30, 0)` is minus one hour and a half. The signs of the following components are ignored.
+    #[must_use]
+    pub fn from_hms(hours: i64, minutes: i64, seconds: i64) -> Self {
+        Self::from_dhmsn(0, hours, minutes, seconds, 0)
+    }
+
+    /// Creates a new duration from hours, minutes, seconds, and nanoseconds, with the same sign convention as `from_hms`.
+    #[must_use]
+    pub fn from_hmsn(hours: i64, minutes: i64, seconds: i64, nanoseconds: i64) -> Self {
+        Self::from_dhmsn(0, hours, minutes, seconds, nanoseconds)
+    }
+
+    /// Creates a new duration from days, hours, minutes, and seconds, with the same sign convention as `from_hms`.
+    /// This is the inverse of `decompose` for durations without subseconds.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, TimeUnits};
+    ///
+    /// let duration = 3.days() + 4.hours() + 5.minutes() + 6.seconds();
+    /// for duration in [duration, -duration] {
+    ///     let (sign, days, hours, minutes, seconds, _, _, _) = duration.decompose();
+    ///     // The sign of the leading component applies to the whole duration.
+    ///     let days = if sign < 0 { -(days as i64) } else { days as i64 };
+    ///     let rebuilt = Duration::from_dhms(days, hours as i64, minutes as i64, seconds as i64);
+    ///     assert_eq!(rebuilt.total_nanoseconds(), duration.total_nanoseconds());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_dhms(days: i64, hours: i64, minutes: i64, seconds: i64) -> Self {
+        Self::from_dhmsn(days, hours, minutes, seconds, 0)
+    }
+
+    fn from_dhmsn(days: i64, hours: i64, minutes: i64, seconds: i64, nanoseconds: i64) -> Self {
+        let components = [
+            (days, NANOSECONDS_PER_DAY),
+            (hours, NANOSECONDS_PER_HOUR),
+            (minutes, NANOSECONDS_PER_MINUTE),
+            (seconds, NANOSECONDS_PER_SECOND),
+            (nanoseconds, 1),
+        ];
+        let negative = components
+            .iter()
+            .find(|(value, _)| *value != 0)
+            .is_some_and(|(value, _)| *value < 0);
+        let magnitude_ns: i128 = components
+            .iter()
+            .map(|(value, unit_ns)| i128::from(value.unsigned_abs()) * i128::from(*unit_ns))
+            .sum();
+
+        Self::from_total_nanoseconds(if negative {
+            -magnitude_ns
+        } else {
+            magnitude_ns
+        })
+    }
+
+    /// Parses a time of day formatted as `HH:MM:SS` with optional subseconds (e.g. `00:31:55.811`) into the duration since midnight.
+    ///
+    /// The seconds may be 60 to represent a leap second (e.g. `23:59:60`), in which case the duration is exactly one day.
+    /// Subseconds with more than nine digits are truncated to the nanosecond.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, Epoch, TimeScale, TimeUnits};
+    ///
+    /// let time_of_day = Duration::from_time_of_day_str("00:31:55.811").unwrap();
+    /// assert_eq!(time_of_day, 31.minutes() + 55.seconds() + 811.milliseconds());
+    ///
+    /// let date = Epoch::from_date_str("2017-01-14", TimeScale::UTC).unwrap();
+    /// assert_eq!(
+    ///     date + time_of_day,
+    ///     Epoch::from_gregorian_utc(2017, 1, 14, 0, 31, 55, 811_000_000)
+    /// );
+    /// ```
+    pub fn from_time_of_day_str(s: &str) -> Result<Self, Errors> {
+        let err = Errors::ParseError(ParsingErrors::ISO8601);
+
+        let mut parts = s.trim().splitn(3, ':');
+        let (hours, minutes, seconds) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(hours), Some(minutes), Some(seconds)) => (hours, minutes, seconds),
+            _ => return Err(err),
+        };
+        let (seconds, subseconds) = match seconds.split_once(['.', ',']) {
+            Some((seconds, subseconds)) => (seconds, subseconds),
+            None => (seconds, "0"),
+        };
+
+        let is_two_digits =
+            |part: &str| part.len() == 2 && part.bytes().all(|b| b.is_ascii_digit());
+        if !is_two_digits(hours)
+            || !is_two_digits(minutes)
+            || !is_two_digits(seconds)
+            || subseconds.is_empty()
+            || !subseconds.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(err);
+        }
+
+        let hours: u64 = hours.parse()?;
+        let minutes: u64 = minutes.parse()?;
+        let seconds: u64 = seconds.parse()?;
+        if hours > 23 || minutes > 59 || seconds > 60 {
+            return Err(err);
+        }
+
+        // Right-pad the subseconds to nine digits, truncating anything past the nanosecond.
+        let mut nanoseconds = 0;
+        for pos in 0..9 {
+            nanoseconds *= 10;
+            if let Some(digit) = subseconds.as_bytes().get(pos) {
+                nanoseconds += u64::from(digit - b'0');
+            }
+        }
+
+        Ok(Self::from_parts(
+            0,
+            hours * NANOSECONDS_PER_HOUR
+                + minutes * NANOSECONDS_PER_MINUTE
+                + seconds * NANOSECONDS_PER_SECOND
+                + nanoseconds,
+        ))
+    }
+
+    /// Adds the other duration to this one, saturating to `Duration::MAX` or `Duration::MIN` instead of overflowing.
+    ///
+    /// This is what the `+` operator does, spelled out to make the intent explicit, as with the integer types of the standard library.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, TimeUnits};
+    ///
+    /// assert_eq!(Duration::MAX.saturating_add(1.nanoseconds()), Duration::MAX);
+    /// assert_eq!(Duration::MIN.saturating_add(-1.days()), Duration::MIN);
+    /// assert_eq!(1.days().saturating_add(12.hours()), 36.hours());
+    /// ```
+    #[must_use]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    /// Multiplies this duration by the provided integer, saturating to `Duration::MAX` or `Duration::MIN` instead of overflowing.
+    ///
+    /// This is what the `*` operator does, spelled out to make the intent explicit, as with the integer types of the standard library.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, TimeUnits};
+    ///
+    /// assert_eq!(Duration::MAX.saturating_mul(2), Duration::MAX);
+    /// assert_eq!(Duration::MAX.saturating_mul(-2), Duration::MIN);
+    /// assert_eq!(1.days().saturating_mul(3), 3.days());
+    /// ```
+    #[must_use]
+    pub fn saturating_mul(self, rhs: i64) -> Self {
+        self * rhs
+    }
+
+    /// Multiplies this duration by the fraction `num / den`, rounding to the nearest nanosecond with ties away from zero.
+    ///
+    /// This is computed on the total nanoseconds in integer arithmetic, so fractions like thirds and sevenths are exact,
+    /// unlike multiplying by an f64. The result saturates to `Duration::MAX` or `Duration::MIN`.
+    ///
+    /// # Panics
+    /// Like the integer division, this panics if the denominator is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, TimeUnits};
+    ///
+    /// assert_eq!(1.hours().mul_rational(1, 3), 20.minutes());
+    /// assert_eq!(1.days().mul_rational(-3, 4), -18.hours());
+    /// assert_eq!(Duration::MAX.mul_rational(2, 1), Duration::MAX);
+    /// ```
+    #[must_use]
+    pub fn mul_rational(self, num: i64, den: i64) -> Self {
+        assert!(
+            den != 0,
+            "Duration::mul_rational called with a zero denominator"
+        );
+        // Keep the denominator positive so that the sign of the result is that of the product
+        let (num, den) = if den < 0 {
+            (-i128::from(num), -i128::from(den))
+        } else {
+            (i128::from(num), i128::from(den))
+        };
+
+        // Split the total nanoseconds by the denominator so that the product of the remainder always fits in an i128,
+        // and only the whole part may saturate.
+        let total_ns = self.total_nanoseconds();
+        let whole = (total_ns / den).saturating_mul(num);
+        let part = (total_ns % den) * num;
+        let mut quotient = whole.saturating_add(part / den);
+        if 2 * (part % den).abs() >= den {
+            quotient = quotient.saturating_add(part.signum());
+        }
+        Duration::from_total_nanoseconds(quotient)
+    }
+
+    /// Returns this duration wrapped so that it is displayed in scientific notation in the provided unit, e.g. `1.2345e4 days`.
+    ///
+    /// This is useful to log durations which span many orders of magnitude. The precision of the format, if any, is the
+    /// number of decimals of the mantissa, as with the `LowerExp` formatting of `f64`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{TimeUnits, Unit};
+    ///
+    /// let span = 12_345.days() + 6.hours();
+    /// assert_eq!(format!("{}", span.scientific(Unit::Day)), "1.234525e4 days");
+    /// assert_eq!(format!("{:.3}", span.scientific(Unit::Day)), "1.235e4 days");
+    /// assert_eq!(format!("{:.3e}", span.scientific(Unit::Second)), "1.067e9 s");
+    /// ```
+    #[must_use]
+    pub const fn scientific(self, unit: Unit) -> ScientificDuration {
+        ScientificDuration {
+            duration: self,
+            unit,
+        }
+    }
+}
+
+/// A duration displayed in scientific notation in a given unit, as returned by `Duration::scientific`.
+///
+/// Both the `Display` and the `LowerExp` formatting print the same output, and honor the requested precision.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScientificDuration {
+    duration: Duration,
+    unit: Unit,
+}
+
+impl fmt::Display for ScientificDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Divide the total nanoseconds to avoid the rounding error of the reciprocal of the unit
+        let value =
+            self.duration.total_nanoseconds() as f64 / (self.unit * 1).total_nanoseconds() as f64;
+        fmt::LowerExp::fmt(&value, f)?;
+        let unit = match self.unit {
+            Unit::Century => "centuries",
+            Unit::Week => "weeks",
+            Unit::Day => "days",
+            Unit::Hour => "h",
+            Unit::Minute => "min",
+            Unit::Second => "s",
+            Unit::Millisecond => "ms",
+            Unit::Microsecond => "μs",
+            Unit::Nanosecond => "ns",
+        };
+        write!(f, " {unit}")
+    }
+}
+
+impl fmt::LowerExp for ScientificDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
 }
 
 #[cfg_attr(feature = "python", pymethods)]
@@ -320,49 +685,56 @@ impl Duration {
     #[must_use]
     /// Returns the centuries and nanoseconds of this duration
     /// NOTE: These items are not public to prevent incorrect durations from being created by modifying the values of the structure directly.
+    ///
+    /// The parts are always normalized, i.e. the nanoseconds are less than one century except for `Duration::MAX`, even if
+    /// this duration is not (e.g. when deserialized from unnormalized parts), so `from_parts(to_parts())` is always the same duration.
     pub const fn to_parts(&self) -> (i16, u64) {
-        (self.centuries, self.nanoseconds)
+        if self.is_normalized() {
+            (self.centuries, self.nanoseconds)
+        } else {
+            // Same as `normalize`, where the extra centuries are at most five and the centuries saturate to the MAX.
+            let centuries =
+                self.centuries as i32 + (self.nanoseconds / NANOSECONDS_PER_CENTURY) as i32;
+            if centuries > i16::MAX as i32 {
+                (Self::MAX.centuries, Self::MAX.nanoseconds)
+            } else {
+                (centuries as i16, self.nanoseconds % NANOSECONDS_PER_CENTURY)
+            }
+        }
+    }
+
+    #[must_use]
+    /// Returns whether this duration is in its canonical form, i.e. its nanoseconds are less than one century, except for `Duration::MAX`
+    /// whose nanoseconds are exactly one century.
+    ///
+    /// All of the durations built by this library are normalized, but deserializing unnormalized parts bypasses the normalization.
+    ///
+    /// ```
+    /// use hifitime::{Duration, NANOSECONDS_PER_CENTURY};
+    ///
+    /// let duration = Duration::from_parts(0, 2 * NANOSECONDS_PER_CENTURY + 5);
+    /// assert!(duration.is_normalized());
+    /// assert_eq!(duration.to_parts(), (2, 5));
+    /// assert!(Duration::MAX.is_normalized());
+    /// ```
+    pub const fn is_normalized(&self) -> bool {
+        self.nanoseconds < NANOSECONDS_PER_CENTURY
+            || (self.centuries == i16::MAX && self.nanoseconds == NANOSECONDS_PER_CENTURY)
     }
 
     /// Returns the total nanoseconds in a signed 128 bit integer
     #[must_use]
     pub fn total_nanoseconds(&self) -> i128 {
-        if self.centuries == -1 {
-            -i128::from(NANOSECONDS_PER_CENTURY - self.nanoseconds)
-        } else if self.centuries >= 0 {
-            i128::from(self.centuries) * i128::from(NANOSECONDS_PER_CENTURY)
-                + i128::from(self.nanoseconds)
-        } else {
-            // Centuries negative by a decent amount
-            i128::from(self.centuries) * i128::from(NANOSECONDS_PER_CENTURY)
-                - i128::from(self.nanoseconds)
-        }
+        // The nanoseconds are always counted forward from the start of the century, even for negative durations.
+        i128::from(self.centuries) * i128::from(NANOSECONDS_PER_CENTURY)
+            + i128::from(self.nanoseconds)
     }
 
     /// Returns the truncated nanoseconds in a signed 64 bit integer, if the duration fits.
     pub fn try_truncated_nanoseconds(&self) -> Result<i64, Errors> {
-        // If it fits, we know that the nanoseconds also fit. abs() will fail if the centuries are min'ed out.
-        if self.centuries == i16::MIN || self.centuries.abs() >= 3 {
-            Err(Errors::Overflow)
-        } else if self.centuries == -1 {
-            Ok(-((NANOSECONDS_PER_CENTURY - self.nanoseconds) as i64))
-        } else if self.centuries >= 0 {
-            match i64::from(self.centuries).checked_mul(NANOSECONDS_PER_CENTURY as i64) {
-                Some(centuries_as_ns) => {
-                    match centuries_as_ns.checked_add(self.nanoseconds as i64) {
-                        Some(truncated_ns) => Ok(truncated_ns),
-                        None => Err(Errors::Overflow),
-                    }
-                }
-                None => Err(Errors::Overflow),
-            }
-        } else {
-            // Centuries negative by a decent amount
-            Ok(
-                i64::from(self.centuries + 1) * NANOSECONDS_PER_CENTURY as i64
-                    + self.nanoseconds as i64,
-            )
-        }
+        // The total nanoseconds always fit on an i128, and the conversion fails only if they don't fit on an i64.
+        // This also supports i64::MIN, which lies within the minus third century.
+        i64::try_from(self.total_nanoseconds()).map_err(|_| Errors::Overflow)
     }
 
     /// Returns the truncated nanoseconds in a signed 64 bit integer, if the duration fits.
@@ -403,6 +775,45 @@ impl Duration {
         self.to_seconds() * unit.from_seconds()
     }
 
+    /// Returns this duration in weeks f64, i.e. `to_unit(Unit::Week)`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::TimeUnits;
+    ///
+    /// assert_eq!(14.days().to_weeks(), 2.0);
+    /// ```
+    #[must_use]
+    pub fn to_weeks(&self) -> f64 {
+        self.to_unit(Unit::Week)
+    }
+
+    /// Returns this duration in days f64, i.e. `to_unit(Unit::Day)`.
+    ///
+    /// This is the same as `total_days`, named like the other `to_*` conversions to f64 (`to_seconds`, `to_weeks`)
+    /// so that all of them are found together.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::TimeUnits;
+    ///
+    /// assert_eq!(1.weeks().to_days(), 7.0);
+    /// assert_eq!(1.weeks().to_days(), 1.weeks().total_days());
+    /// ```
+    #[must_use]
+    pub fn to_days(&self) -> f64 {
+        self.to_unit(Unit::Day)
+    }
+
+    /// Returns this duration in hours f64, i.e. `to_unit(Unit::Hour)`.
+    ///
+    /// This is the same as `total_hours`, named like the other `to_*` conversions to f64 (`to_seconds`, `to_weeks`)
+    /// so that all of them are found together.
+    #[must_use]
+    pub fn to_hours(&self) -> f64 {
+        self.to_unit(Unit::Hour)
+    }
+
     /// Returns the absolute value of this duration
     #[must_use]
     pub fn abs(&self) -> Self {
@@ -428,7 +839,8 @@ impl Duration {
         let sign = self.signum();
 
         match self.try_truncated_nanoseconds() {
-            Ok(total_ns) => {
+            // The absolute value of i64::MIN does not fit on an i64, so it's handled with the i128 below.
+            Ok(total_ns) if total_ns != i64::MIN => {
                 let ns_left = total_ns.abs();
 
                 let (days, ns_left) = div_rem_i64(ns_left, NANOSECONDS_PER_DAY as i64);
@@ -452,7 +864,7 @@ impl Duration {
                     ns_left.try_into().unwrap(),
                 )
             }
-            Err(_) => {
+            _ => {
                 // Doesn't fit on a i64, so let's use the slower i128
                 let total_ns = self.total_nanoseconds();
                 let ns_left = total_ns.abs();
@@ -481,7 +893,65 @@ impl Duration {
         }
     }
 
-    /// Floors this duration to the closest duration from the bottom
+    /// Returns the number of whole seconds in this duration, truncated toward zero.
+    ///
+    /// ```
+    /// use hifitime::TimeUnits;
+    ///
+    /// assert_eq!((1.5).seconds().whole_seconds(), 1);
+    /// assert_eq!((-1.5).seconds().whole_seconds(), -1);
+    /// ```
+    #[must_use]
+    pub fn whole_seconds(&self) -> i64 {
+        // This always fits on an i64 since the maximum duration is about 1e14 seconds.
+        (self.total_nanoseconds() / i128::from(NANOSECONDS_PER_SECOND)) as i64
+    }
+
+    /// Returns the nanoseconds within the current second of this duration.
+    ///
+    /// The sign of the sub-second nanoseconds is the same as the sign of the duration, such that
+    /// `whole_seconds() * 1e9 + subsec_nanoseconds()` is exactly the total number of nanoseconds of this duration.
+    ///
+    /// ```
+    /// use hifitime::TimeUnits;
+    ///
+    /// assert_eq!((1.5).seconds().subsec_nanoseconds(), 500_000_000);
+    /// assert_eq!((-1.5).seconds().subsec_nanoseconds(), -500_000_000);
+    /// ```
+    #[must_use]
+    pub fn subsec_nanoseconds(&self) -> i64 {
+        (self.total_nanoseconds() % i128::from(NANOSECONDS_PER_SECOND)) as i64
+    }
+
+    /// Returns the whole milliseconds within the current second of this duration, with the same sign convention as `subsec_nanoseconds`.
+    #[must_use]
+    pub fn subsec_millis(&self) -> i64 {
+        self.subsec_nanoseconds() / NANOSECONDS_PER_MILLISECOND as i64
+    }
+
+    /// Returns this duration in days as an f64, like `to_days`.
+    #[must_use]
+    pub fn total_days(&self) -> f64 {
+        self.to_unit(Unit::Day)
+    }
+
+    /// Returns this duration in hours as an f64, like `to_hours`.
+    #[must_use]
+    pub fn total_hours(&self) -> f64 {
+        self.to_unit(Unit::Hour)
+    }
+
+    /// Returns this duration in minutes as an f64.
+    #[must_use]
+    pub fn total_minutes(&self) -> f64 {
+        self.to_unit(Unit::Minute)
+    }
+
+    /// Floors this duration to the closest multiple of the provided duration toward negative infinity, like `f64::floor`.
+    ///
+    /// Negative durations are therefore floored away from zero, e.g. flooring -90 minutes to the hour gives -2 hours,
+    /// which is what keeps the flooring of epochs before 1900 (stored as negative durations) consistent with the epochs after it.
+    /// The sign of the provided duration is ignored, and a duration of zero returns this duration unchanged.
     ///
     /// # Example
     /// ```
@@ -495,25 +965,23 @@ impl Duration {
     /// assert_eq!(two_hours_three_min.floor(1.seconds()), two_hours_three_min);
     /// assert_eq!(two_hours_three_min.floor(1.hours() + 1.minutes()), 2.hours() + 2.minutes());
     /// assert_eq!(two_hours_three_min.floor(1.hours() + 5.minutes()), 1.hours() + 5.minutes());
+    /// assert_eq!((-90).minutes().floor(1.hours()), -2.hours());
     /// ```
     pub fn floor(&self, duration: Self) -> Self {
-        // Note that we don't use checked_sub because, at most, this will be zero.
-        // match self
-        //     .total_nanoseconds()
-        //     .checked_sub(self.total_nanoseconds() % duration.abs().total_nanoseconds())
-        // {
-        //     Some(total_ns) => Self::from_total_nanoseconds(total_ns),
-        //     None => Self::MIN,
-        // }
-
-        Self::from_total_nanoseconds(
-            self.total_nanoseconds() - self.total_nanoseconds() % duration.total_nanoseconds(),
-        )
+        let step_ns = duration.total_nanoseconds().abs();
+        if step_ns == 0 {
+            return *self;
+        }
+        let total_ns = self.total_nanoseconds();
+        // The Euclidean remainder is never negative, so this never rounds up, and the i128 cannot overflow.
+        Self::from_total_nanoseconds(total_ns - total_ns.rem_euclid(step_ns))
     }
 
-    /// Ceils this duration to the closest provided duration
+    /// Ceils this duration to the closest multiple of the provided duration toward positive infinity, like `f64::ceil`.
     ///
-    /// This simply floors then adds the requested duration
+    /// A duration which already is a multiple is returned unchanged, and negative durations are ceiled toward zero,
+    /// e.g. ceiling -90 minutes to the hour gives -1 hour.
+    /// The sign of the provided duration is ignored, and a duration of zero returns this duration unchanged.
     ///
     /// # Example
     /// ```
@@ -523,23 +991,27 @@ impl Duration {
     /// assert_eq!(two_hours_three_min.ceil(1.hours()), 3.hours());
     /// assert_eq!(two_hours_three_min.ceil(30.minutes()), 2.hours() + 30.minutes());
     /// assert_eq!(two_hours_three_min.ceil(4.hours()), 4.hours());
-    /// assert_eq!(two_hours_three_min.ceil(1.seconds()), two_hours_three_min + 1.seconds());
+    /// assert_eq!(two_hours_three_min.ceil(1.seconds()), two_hours_three_min);
     /// assert_eq!(two_hours_three_min.ceil(1.hours() + 5.minutes()), 2.hours() + 10.minutes());
+    /// assert_eq!((-90).minutes().ceil(1.hours()), -1.hours());
     /// ```
     pub fn ceil(&self, duration: Self) -> Self {
-        let floored = self.floor(duration);
-        match floored
-            .total_nanoseconds()
-            .checked_add(duration.abs().total_nanoseconds())
-        {
-            Some(total_ns) => Self::from_total_nanoseconds(total_ns),
-            None => Self::MAX,
+        let step_ns = duration.total_nanoseconds().abs();
+        if step_ns == 0 {
+            return *self;
+        }
+        let total_ns = self.total_nanoseconds();
+        match total_ns.rem_euclid(step_ns) {
+            0 => *self,
+            // This saturates to the maximum duration if the next multiple does not fit.
+            remainder => Self::from_total_nanoseconds(total_ns + (step_ns - remainder)),
         }
     }
 
     /// Rounds this duration to the closest provided duration
     ///
     /// This performs both a `ceil` and `floor` and returns the value which is the closest to current one.
+    /// Ties are broken toward the ceiled value, i.e. toward positive infinity; use `round_with` to pick another rounding mode.
     /// # Example
     /// ```
     /// use hifitime::{Duration, TimeUnits};
@@ -561,6 +1033,119 @@ impl Duration {
         }
     }
 
+    /// Rounds this duration to a multiple of the provided step with the provided rounding mode.
+    /// The sign of the step is ignored, and a step of zero returns this duration unchanged.
+    ///
+    /// The mode matters most for exact ties, e.g. when rounding 30 minutes to the hour.
+    ///
+    /// ```
+    /// use hifitime::{RoundMode, TimeUnits};
+    ///
+    /// assert_eq!(30.minutes().round_with(1.hours(), RoundMode::HalfUp), 1.hours());
+    /// assert_eq!(30.minutes().round_with(1.hours(), RoundMode::HalfDown), 0.hours());
+    /// assert_eq!(90.minutes().round_with(1.hours(), RoundMode::HalfEven), 2.hours());
+    /// assert_eq!(150.minutes().round_with(1.hours(), RoundMode::HalfEven), 2.hours());
+    /// assert_eq!(61.minutes().round_with(1.hours(), RoundMode::AwayFromZero), 2.hours());
+    /// ```
+    pub fn round_with(&self, step: Self, mode: RoundMode) -> Self {
+        let step_ns = step.total_nanoseconds().abs();
+        if step_ns == 0 {
+            return *self;
+        }
+        let total_ns = self.total_nanoseconds();
+        // Both are truncated toward zero, so the remainder has the same sign as this duration.
+        let quotient = total_ns / step_ns;
+        let remainder = total_ns % step_ns;
+        if remainder == 0 {
+            return *self;
+        }
+
+        let away_from_zero = match mode {
+            RoundMode::TowardZero => false,
+            RoundMode::AwayFromZero => true,
+            _ => match (2 * remainder.abs()).cmp(&step_ns) {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => match mode {
+                    RoundMode::HalfUp => true,
+                    RoundMode::HalfDown => false,
+                    // The multiple toward zero is even, so we keep it.
+                    _ => quotient % 2 != 0,
+                },
+            },
+        };
+
+        let quotient = if away_from_zero {
+            quotient + total_ns.signum()
+        } else {
+            quotient
+        };
+
+        match quotient.checked_mul(step_ns) {
+            Some(total_ns) => Self::from_total_nanoseconds(total_ns),
+            None => {
+                if total_ns.is_negative() {
+                    Self::MIN
+                } else {
+                    Self::MAX
+                }
+            }
+        }
+    }
+
+    /// Floors this duration to the closest multiple of the provided unit, i.e. `self.floor(unit * 1)`.
+    ///
+    /// ```
+    /// use hifitime::{TimeUnits, Unit};
+    ///
+    /// let two_hours_three_min = 2.hours() + 3.minutes();
+    /// assert_eq!(two_hours_three_min.floor_to(Unit::Hour), 2.hours());
+    /// ```
+    pub fn floor_to(&self, unit: Unit) -> Self {
+        self.floor(unit * 1)
+    }
+
+    /// Ceils this duration to the closest multiple of the provided unit, i.e. `self.ceil(unit * 1)`.
+    ///
+    /// ```
+    /// use hifitime::{TimeUnits, Unit};
+    ///
+    /// let two_hours_three_min = 2.hours() + 3.minutes();
+    /// assert_eq!(two_hours_three_min.ceil_to(Unit::Hour), 3.hours());
+    /// ```
+    pub fn ceil_to(&self, unit: Unit) -> Self {
+        self.ceil(unit * 1)
+    }
+
+    /// Rounds this duration to the closest multiple of the provided unit, i.e. `self.round(unit * 1)`.
+    ///
+    /// ```
+    /// use hifitime::{TimeUnits, Unit};
+    ///
+    /// let two_hours_three_min = 2.hours() + 3.minutes();
+    /// assert_eq!(two_hours_three_min.round_to(Unit::Hour), 2.hours());
+    /// ```
+    pub fn round_to(&self, unit: Unit) -> Self {
+        self.round(unit * 1)
+    }
+
+    /// Splits this duration into its part floored to the provided unit, like `floor_to`, and the remainder below that unit.
+    ///
+    /// Both parts sum back to this duration exactly. As with `floor`, the remainder is never negative, so a negative duration
+    /// is floored away from zero, e.g. -90 minutes split at the hour is -2 hours and 30 minutes.
+    ///
+    /// ```
+    /// use hifitime::{TimeUnits, Unit};
+    ///
+    /// let (hours, rem) = (1.hours() + 23.minutes() + 45.seconds()).split_at_unit(Unit::Hour);
+    /// assert_eq!(hours, 1.hours());
+    /// assert_eq!(rem, 23.minutes() + 45.seconds());
+    /// ```
+    pub fn split_at_unit(&self, unit: Unit) -> (Self, Self) {
+        let floored = self.floor_to(unit);
+        (floored, *self - floored)
+    }
+
     /// Rounds this duration to the largest units represented in this duration.
     ///
     /// This is useful to provide an approximate human duration. Under the hood, this function uses `round`,
@@ -723,6 +1308,39 @@ impl Duration {
         *self / other
     }
 
+    #[cfg(feature = "python")]
+    fn __rmul__(&self, other: f64) -> Duration {
+        *self * other
+    }
+
+    #[cfg(feature = "python")]
+    /// Divides this duration by a number, returning a duration, or by another duration, returning their ratio as a float.
+    /// Like for the Python numbers, dividing by zero raises a ZeroDivisionError.
+    fn __truediv__(&self, py: Python, other: &PyAny) -> PyResult<PyObject> {
+        if let Ok(other) = other.extract::<Self>() {
+            if other.total_nanoseconds() == 0 {
+                return Err(PyZeroDivisionError::new_err("division by a zero duration"));
+            }
+            Ok((self.total_nanoseconds() as f64 / other.total_nanoseconds() as f64).into_py(py))
+        } else {
+            let other: f64 = other.extract()?;
+            if other == 0.0 {
+                return Err(PyZeroDivisionError::new_err("division by zero"));
+            }
+            Ok((*self / other).into_py(py))
+        }
+    }
+
+    #[cfg(feature = "python")]
+    fn __neg__(&self) -> Duration {
+        -*self
+    }
+
+    #[cfg(feature = "python")]
+    fn __abs__(&self) -> Duration {
+        self.abs()
+    }
+
     #[cfg(feature = "python")]
     fn __eq__(&self, other: Self) -> bool {
         *self == other
@@ -740,6 +1358,30 @@ impl Duration {
         }
     }
 
+    #[cfg(feature = "python")]
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[cfg(feature = "python")]
+    fn __getstate__(&self) -> (i16, u64) {
+        self.to_parts()
+    }
+
+    #[cfg(feature = "python")]
+    fn __setstate__(&mut self, state: (i16, u64)) {
+        *self = Self::from_parts(state.0, state.1);
+    }
+
+    #[cfg(feature = "python")]
+    /// Pickles this duration as its parts, rebuilt with `Duration.init_from_parts`.
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (i16, u64))> {
+        let init = py.get_type::<Self>().getattr("init_from_parts")?;
+        Ok((init.into(), self.to_parts()))
+    }
+
     // Python constructors
 
     #[cfg(feature = "python")]
@@ -838,28 +1480,56 @@ impl Mul<i64> for Duration {
 
 impl Mul<f64> for Duration {
     type Output = Duration;
+
+    /// Multiplies this duration by a floating point value, rounding to the nearest nanosecond.
+    ///
+    /// ## Limitations
+    /// 1. Only the first fifteen significant digits of the multiplier are used, which matches the precision of an f64.
+    /// 2. A NaN multiplier returns a zero duration, and an infinite multiplier saturates to Duration::MAX or Duration::MIN.
     fn mul(self, q: f64) -> Self::Output {
-        // Make sure that we don't trim the number by finding its precision
+        let total_ns = self.total_nanoseconds();
+        if q.is_nan() || total_ns == 0 {
+            return Duration::ZERO;
+        } else if q.is_infinite() {
+            return if (q > 0.0) == (total_ns > 0) {
+                Duration::MAX
+            } else {
+                Duration::MIN
+            };
+        }
+
+        // Make sure that we don't trim the number by finding its precision, but stop once the
+        // multiplier has as many significant digits as an f64 can hold.
+        const MAX_SIGNIFICANT: f64 = 1e15;
+        const MAX_PRECISION: i32 = 30;
         let mut p: i32 = 0;
         let mut new_val = q;
         let ten: f64 = 10.0;
 
-        loop {
-            if (new_val.floor() - new_val).abs() < f64::EPSILON {
-                // Yay, we've found the precision of this number
-                break;
-            }
+        while (new_val.floor() - new_val).abs() >= f64::EPSILON
+            && new_val.abs() < MAX_SIGNIFICANT
+            && p < MAX_PRECISION
+        {
             // Multiply by the precision
             // https://play.rust-lang.org/?version=stable&mode=debug&edition=2018&gist=b760579f103b7192c20413ebbe167b90
             p += 1;
             new_val = q * ten.powi(p);
         }
 
-        Duration::from_total_nanoseconds(
-            self.total_nanoseconds()
-                .saturating_mul(new_val as i128)
-                .saturating_div(10_i128.pow(p.try_into().unwrap())),
-        )
+        let divisor = 10_i128.pow(p as u32);
+        match total_ns.checked_mul(new_val.round() as i128) {
+            Some(product) => {
+                // Round the quotient to the nearest nanosecond, away from zero on ties
+                let half = if product < 0 {
+                    -divisor / 2
+                } else {
+                    divisor / 2
+                };
+                Duration::from_total_nanoseconds(product.saturating_add(half) / divisor)
+            }
+            // Too large to be computed exactly: the result will saturate or lose sub-nanosecond precision anyway
+            None => Duration::from_total_nanoseconds((total_ns as f64 * q).round() as i128),
+        }
     }
 }
 
@@ -1175,6 +1845,9 @@ impl SubAssign for Duration {
 }
 
 // Allow adding with a Unit directly
+forward_ref_binop! { impl Add, add for Duration, Duration }
+forward_ref_binop! { impl Sub, sub for Duration, Duration }
+
 impl Add<Unit> for Duration {
     type Output = Self;
 
@@ -1229,6 +1902,31 @@ impl PartialOrd<Unit> for Duration {
     }
 }
 
+/// Compares a duration with an integer number of **seconds**, e.g. `duration > 0` or `duration == 5`.
+///
+/// ```
+/// use hifitime::TimeUnits;
+///
+/// assert!(1.nanoseconds() > 0);
+/// assert!((-1).nanoseconds() < 0);
+/// assert!(5.seconds() == 5);
+/// assert!(5.seconds() != -5);
+/// ```
+impl PartialEq<i32> for Duration {
+    fn eq(&self, seconds: &i32) -> bool {
+        self.total_nanoseconds() == i128::from(*seconds) * i128::from(NANOSECONDS_PER_SECOND)
+    }
+}
+
+impl PartialOrd<i32> for Duration {
+    fn partial_cmp(&self, seconds: &i32) -> Option<Ordering> {
+        Some(
+            self.total_nanoseconds()
+                .cmp(&(i128::from(*seconds) * i128::from(NANOSECONDS_PER_SECOND))),
+        )
+    }
+}
+
 impl Neg for Duration {
     type Output = Self;
 
@@ -1483,6 +2181,58 @@ fn test_serdes() {
     assert_eq!(dt, parsed);
 }
 
+#[test]
+fn test_unnormalized_parts() {
+    // Unnormalized durations cannot be built with the public API, but may be deserialized.
+    let unnormalized = Duration {
+        centuries: 1,
+        nanoseconds: 2 * NANOSECONDS_PER_CENTURY + 5,
+    };
+    assert!(!unnormalized.is_normalized());
+    assert_eq!(unnormalized.to_parts(), (3, 5));
+    let rebuilt = Duration::from_parts(3, 5);
+    assert!(rebuilt.is_normalized());
+    assert_eq!(
+        rebuilt.total_nanoseconds(),
+        unnormalized.total_nanoseconds()
+    );
+
+    let unnormalized = Duration {
+        centuries: -2,
+        nanoseconds: NANOSECONDS_PER_CENTURY,
+    };
+    assert!(!unnormalized.is_normalized());
+    assert_eq!(unnormalized.to_parts(), (-1, 0));
+
+    // Saturates like the normalization
+    for centuries in [i16::MAX, i16::MAX - 1] {
+        let unnormalized = Duration {
+            centuries,
+            nanoseconds: 3 * NANOSECONDS_PER_CENTURY,
+        };
+        assert!(!unnormalized.is_normalized());
+        assert_eq!(unnormalized.to_parts(), Duration::MAX.to_parts());
+        assert_eq!(
+            Duration::from_parts(centuries, 3 * NANOSECONDS_PER_CENTURY).to_parts(),
+            Duration::MAX.to_parts()
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_serdes_unnormalized() {
+    let content = format!(
+        r#"{{"centuries":0,"nanoseconds":{}}}"#,
+        NANOSECONDS_PER_CENTURY + 7
+    );
+    let parsed: Duration = serde_json::from_str(&content).unwrap();
+    assert!(!parsed.is_normalized());
+    assert_eq!(parsed.to_parts(), (1, 7));
+    let (centuries, nanoseconds) = parsed.to_parts();
+    assert!(Duration::from_parts(centuries, nanoseconds).is_normalized());
+}
+
 #[test]
 fn test_bounds() {
     let min = Duration::MIN;
@@ -1523,36 +2273,9 @@ fn formal_duration_truncated_ns_reciprocity() {
     let nanoseconds: i64 = kani::any();
     let dur_from_part = Duration::from_truncated_nanoseconds(nanoseconds);
 
-    let u_ns = dur_from_part.nanoseconds;
-    let centuries = dur_from_part.centuries;
-    if centuries <= -3 || centuries >= 3 {
-        // Then it does not fit on a i64, so this function should return an error
-        assert_eq!(
-            dur_from_part.try_truncated_nanoseconds(),
-            Err(Errors::Overflow)
-        );
-    } else if centuries == -1 {
-        // If we are negative by just enough that the centuries is negative, then the truncated seconds
-        // should be the unsigned nanoseconds wrapped by the number of nanoseconds per century.
-
-        let expect_rslt = -((NANOSECONDS_PER_CENTURY - u_ns) as i64);
-
-        let recip_ns = dur_from_part.try_truncated_nanoseconds().unwrap();
-        assert_eq!(recip_ns, expect_rslt);
-    } else if centuries < 0 {
-        // We fit on a i64 but we need to account for the number of nanoseconds wrapped to the negative centuries.
-
-        let nanos = u_ns.rem_euclid(NANOSECONDS_PER_CENTURY);
-        let expect_rslt = i64::from(centuries + 1) * NANOSECONDS_PER_CENTURY as i64 + nanos as i64;
-
-        let recip_ns = dur_from_part.try_truncated_nanoseconds().unwrap();
-        assert_eq!(recip_ns, expect_rslt);
-    } else {
-        // Positive duration but enough to fit on an i64.
-        let recip_ns = dur_from_part.try_truncated_nanoseconds().unwrap();
-
-        assert_eq!(recip_ns, nanoseconds);
-    }
+    // Every i64, including i64::MIN, must be recovered exactly.
+    let recip_ns = dur_from_part.try_truncated_nanoseconds().unwrap();
+    assert_eq!(recip_ns, nanoseconds);
 }
 
 // #[cfg(kani)]
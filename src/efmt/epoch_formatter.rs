@@ -0,0 +1,180 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2022 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::fmt;
+
+use crate::epoch::{is_gregorian_valid_with_registered, is_leap_year, usual_days_per_month};
+use crate::{Epoch, TimeScale};
+
+/// A reusable configuration of the Gregorian representation of epochs, e.g. for report generation.
+///
+/// By default, the output is that of the `Display` of `Epoch` in the provided time scale: the subseconds are printed with
+/// nine digits only if they are not zero, the date is separated by hyphens, and the time scale is appended.
+/// The trailing zeros of the subseconds may be trimmed for human readable logs, e.g. `55.811` instead of `55.811000000`:
+/// the parsers accept any number of subsecond digits, so this output can still be parsed back.
+///
+/// # Example
+/// ```
+/// use hifitime::efmt::EpochFormatter;
+/// use hifitime::{Epoch, TimeScale};
+///
+/// let fmt = EpochFormatter::new(TimeScale::UTC)
+///     .decimals(3)
+///     .date_sep('/')
+///     .append_scale(false);
+///
+/// let e = Epoch::from_gregorian_utc(2023, 3, 14, 15, 9, 26, 535_897_932);
+/// assert_eq!(format!("{}", fmt.format(e)), "2023/03/14T15:09:26.536");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EpochFormatter {
+    time_scale: TimeScale,
+    decimals: Option<u8>,
+    date_sep: char,
+    append_scale: bool,
+    trim_zeros: bool,
+}
+
+impl EpochFormatter {
+    /// Creates a new formatter of the epochs in the provided time scale.
+    #[must_use]
+    pub const fn new(time_scale: TimeScale) -> Self {
+        Self {
+            time_scale,
+            decimals: None,
+            date_sep: '-',
+            append_scale: true,
+            trim_zeros: false,
+        }
+    }
+
+    /// Sets the number of digits of the fractional seconds, which are always printed, and rounded to the nearest value on that many digits.
+    /// Zero decimals prints the whole seconds only, and more than nine decimals is treated as nine, i.e. the nanosecond.
+    #[must_use]
+    pub const fn decimals(mut self, decimals: u8) -> Self {
+        self.decimals = Some(if decimals > 9 { 9 } else { decimals });
+        self
+    }
+
+    /// Sets the separator of the year, month and day.
+    #[must_use]
+    pub const fn date_sep(mut self, sep: char) -> Self {
+        self.date_sep = sep;
+        self
+    }
+
+    /// Sets whether the time scale is appended to the output, after a space.
+    #[must_use]
+    pub const fn append_scale(mut self, append: bool) -> Self {
+        self.append_scale = append;
+        self
+    }
+
+    /// Sets whether the trailing zeros of the fractional seconds are trimmed, after rounding to the requested decimals.
+    /// The fractional seconds are omitted altogether if they are zero.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::efmt::EpochFormatter;
+    /// use hifitime::{Epoch, TimeScale};
+    ///
+    /// let e = Epoch::from_gregorian_utc(2023, 1, 14, 0, 31, 55, 811_000_000);
+    /// let fmt = EpochFormatter::new(TimeScale::UTC).trim_zeros(true);
+    /// assert_eq!(format!("{}", fmt.format(e)), "2023-01-14T00:31:55.811 UTC");
+    /// ```
+    #[must_use]
+    pub const fn trim_zeros(mut self, trim: bool) -> Self {
+        self.trim_zeros = trim;
+        self
+    }
+
+    /// Returns the provided epoch wrapped so that it is displayed with this configuration.
+    #[must_use]
+    pub const fn format(&self, epoch: Epoch) -> FormattedEpoch {
+        FormattedEpoch {
+            epoch,
+            formatter: *self,
+        }
+    }
+}
+
+/// An epoch displayed with the configuration of an `EpochFormatter`, as returned by `EpochFormatter::format`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FormattedEpoch {
+    epoch: Epoch,
+    formatter: EpochFormatter,
+}
+
+impl fmt::Display for FormattedEpoch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fmt = self.formatter;
+        let (mut y, mut mm, mut dd, mut hh, mut min, mut s, nanos) =
+            self.epoch.compute_gregorian_in(fmt.time_scale);
+
+        let (decimals, subseconds) = match fmt.decimals {
+            None => (if nanos == 0 { 0 } else { 9 }, nanos),
+            Some(decimals) => {
+                let step = 10_u32.pow(9 - u32::from(decimals));
+                // Round to the nearest, away from zero on ties
+                let mut rounded = (nanos + step / 2) / step;
+                if rounded * step == 1_000_000_000 {
+                    rounded = 0;
+                    // Carry over to the next second, which is a leap second at the end of some UTC days.
+                    let leap_second = fmt.time_scale == TimeScale::UTC
+                        && s == 59
+                        && is_gregorian_valid_with_registered(y, mm, dd, hh, min, 60, 0);
+                    if leap_second || s < 59 {
+                        s += 1;
+                    } else {
+                        s = 0;
+                        min += 1;
+                    }
+                    if min == 60 {
+                        min = 0;
+                        hh += 1;
+                    }
+                    if hh == 24 {
+                        hh = 0;
+                        dd += 1;
+                    }
+                    let days_in_month =
+                        usual_days_per_month(mm - 1) + u8::from(mm == 2 && is_leap_year(y));
+                    if dd > days_in_month {
+                        dd = 1;
+                        mm += 1;
+                    }
+                    if mm == 13 {
+                        mm = 1;
+                        y += 1;
+                    }
+                }
+                (decimals, rounded)
+            }
+        };
+
+        let (mut decimals, mut subseconds) = (decimals, subseconds);
+        if fmt.trim_zeros {
+            while decimals > 0 && subseconds % 10 == 0 {
+                subseconds /= 10;
+                decimals -= 1;
+            }
+        }
+
+        let sep = fmt.date_sep;
+        write!(f, "{y:04}{sep}{mm:02}{sep}{dd:02}T{hh:02}:{min:02}:{s:02}")?;
+        if decimals > 0 {
+            write!(f, ".{:0width$}", subseconds, width = usize::from(decimals))?;
+        }
+        if fmt.append_scale {
+            write!(f, " {}", fmt.time_scale)?;
+        }
+        Ok(())
+    }
+}
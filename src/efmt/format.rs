@@ -10,7 +10,7 @@
 
 use super::formatter::Item;
 use crate::{parser::Token, ParsingErrors};
-use crate::{Epoch, Errors, MonthName, TimeScale, Unit, Weekday};
+use crate::{Duration, Epoch, Errors, MonthName, TimeScale, Unit, Weekday};
 use core::fmt;
 use core::str::FromStr;
 
@@ -145,8 +145,8 @@ impl Format {
         let mut decomposed = [0_i32; MAX_TOKENS];
         // The parsed time scale, defaults to UTC
         let mut ts = TimeScale::UTC;
-        // The offset sign, defaults to positive.
-        let mut offset_sign = 1;
+        // The duration to add to the parsed time to undo its UTC offset, if any.
+        let mut tz = Duration::ZERO;
         let mut day_of_year: Option<f64> = None;
         let mut weekday: Option<Weekday> = None;
 
@@ -289,24 +289,28 @@ impl Format {
                     }
                 }
 
-                prev_idx = idx + 1;
-                // If we are about to parse an hours offset, we need to set the sign now.
                 if cur_token == Token::OffsetHours {
-                    if &s[idx..idx + 1] == "-" {
-                        offset_sign = -1;
+                    // The rest of the string is the offset, whose sign is the separator we just found,
+                    // optionally followed by the time scale.
+                    let rest = s.get(idx..).unwrap_or_default();
+                    let rest = if char == '+' || char == '-' {
+                        let (offset, rest) = parse_offset(rest)?;
+                        tz = offset;
+                        rest
+                    } else {
+                        rest
+                    };
+                    if !rest.trim().is_empty() {
+                        ts = TimeScale::from_str(rest.trim())?;
                     }
-                    prev_idx += 1;
+                    break;
                 }
+
+                // The start of the next value is after this separator.
+                prev_idx = idx + 1;
             }
         }
 
-        let tz = if offset_sign > 0 {
-            // We oppose the sign in the string to undo the offset
-            -(i64::from(decomposed[7]) * Unit::Hour + i64::from(decomposed[8]) * Unit::Minute)
-        } else {
-            i64::from(decomposed[7]) * Unit::Hour + i64::from(decomposed[8]) * Unit::Minute
-        };
-
         let epoch = match day_of_year {
             Some(days) => Epoch::from_day_of_year(decomposed[0], days, ts),
             None => Epoch::maybe_from_gregorian(
@@ -335,6 +339,37 @@ impl Format {
     }
 }
 
+/// Parses a UTC offset formatted as `+HH`, `+HHMM` or `+HH:MM` (or with a minus sign), and returns the duration
+/// to add to the local time to get the UTC time, along with the rest of the string.
+fn parse_offset(s: &str) -> Result<(Duration, &str), Errors> {
+    let err = Errors::ParseError(ParsingErrors::InvalidTimeZone);
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => (-1, s),
+        None => (1, s.strip_prefix('+').ok_or(err)?),
+    };
+    let two_digits = |s: &str| -> Option<i32> {
+        s.get(..2)
+            .filter(|part| part.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|part| part.parse().ok())
+    };
+
+    let hours = two_digits(s).ok_or(err)?;
+    let s = &s[2..];
+    let (minutes, rest) = match s.strip_prefix(':') {
+        Some(s) => (two_digits(s).ok_or(err)?, &s[2..]),
+        None => match two_digits(s) {
+            Some(minutes) => (minutes, &s[2..]),
+            None => (0, s),
+        },
+    };
+    Token::OffsetHours.value_ok(hours)?;
+    Token::OffsetMinutes.value_ok(minutes)?;
+
+    // We oppose the sign in the string to undo the offset
+    let offset = i64::from(hours) * Unit::Hour + i64::from(minutes) * Unit::Minute;
+    Ok((if sign > 0 { -offset } else { offset }, rest))
+}
+
 impl fmt::Debug for Format {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "EpochFormat:`")?;
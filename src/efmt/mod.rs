@@ -9,8 +9,10 @@
  */
 
 pub mod consts;
+pub mod epoch_formatter;
 pub mod format;
 pub mod formatter;
 
+pub use epoch_formatter::{EpochFormatter, FormattedEpoch};
 pub use format::Format;
 pub use formatter::Formatter;
@@ -8,23 +8,30 @@
  * Documentation: https://nyxspace.com/
  */
 
-use crate::duration::{Duration, Unit};
-use crate::leap_seconds::{LatestLeapSeconds, LeapSecondProvider};
+use crate::duration::{Duration, RoundMode, Unit};
+use crate::leap_seconds::{
+    ends_with_any_leap_second, ends_with_leap_second, LatestLeapSeconds, LeapSecondProvider,
+    SortedLeapSecondsCursor,
+};
 use crate::parser::Token;
 use crate::{
-    Errors, MonthName, TimeScale, BDT_REF_EPOCH, DAYS_PER_YEAR_NLD, ET_EPOCH_S, GPST_REF_EPOCH,
-    GST_REF_EPOCH, J1900_OFFSET, J2000_TO_J1900_DURATION, MJD_OFFSET, NANOSECONDS_PER_DAY,
-    NANOSECONDS_PER_MICROSECOND, NANOSECONDS_PER_MILLISECOND, NANOSECONDS_PER_SECOND_U32,
-    UNIX_REF_EPOCH,
+    Errors, Gregorian, LocalTime, MonthName, MonthlySeries, TimeScale, BDT_REF_EPOCH,
+    DAYS_PER_CENTURY, DAYS_PER_CENTURY_I64, DAYS_PER_YEAR, ET_EPOCH_S, GPST_REF_EPOCH,
+    GST_REF_EPOCH, J1900_OFFSET, J1900_REF_EPOCH, J2000_OFFSET, J2000_REF_EPOCH,
+    J2000_TO_J1900_DURATION, MJD_OFFSET, NANOSECONDS_PER_DAY, NANOSECONDS_PER_HOUR,
+    NANOSECONDS_PER_MICROSECOND, NANOSECONDS_PER_MILLISECOND, NANOSECONDS_PER_MINUTE,
+    NANOSECONDS_PER_SECOND, NANOSECONDS_PER_SECOND_U32, UNIX_REF_EPOCH,
 };
 
 use crate::efmt::format::Format;
 
 use core::cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd};
+use core::f64::consts::TAU;
 use core::fmt;
 use core::hash::{Hash, Hasher};
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 
+use crate::Clock;
 use crate::ParsingErrors;
 use crate::Weekday;
 
@@ -34,6 +41,15 @@ use pyo3::prelude::*;
 #[cfg(feature = "python")]
 use pyo3::pyclass::CompareOp;
 
+#[cfg(feature = "python")]
+use std::collections::hash_map::DefaultHasher;
+
+#[cfg(feature = "python")]
+use pyo3::{buffer::PyBuffer, exceptions::PyValueError};
+
+#[cfg(feature = "python")]
+use pyo3::types::{PyDateAccess, PyDateTime, PyTimeAccess, PyTzInfo, PyTzInfoAccess};
+
 #[cfg(feature = "python")]
 use crate::leap_seconds_file::LeapSecondsFile;
 
@@ -52,6 +68,10 @@ use crate::ut1::Ut1Provider;
 
 const TT_OFFSET_MS: i64 = 32_184;
 const ET_OFFSET_US: i64 = 32_184_935;
+/// Maximum number of iterations when converting to the ET and TDB time scales.
+const MAX_ITERATIONS: usize = 10;
+/// The ET and TDB iterations stop once the offset changes by less than one nanosecond.
+const CONVERGENCE_S: f64 = 1e-9;
 
 /// NAIF leap second kernel data for M_0 used to calculate the mean anomaly of the heliocentric orbit of the Earth-Moon barycenter.
 pub const NAIF_M0: f64 = 6.239996;
@@ -62,42 +82,11 @@ pub const NAIF_EB: f64 = 1.671e-2;
 /// NAIF leap second kernel data used to calculate the difference between ET and TAI.
 pub const NAIF_K: f64 = 1.657e-3;
 
-/// Years when January had the leap second
-const fn january_years(year: i32) -> bool {
-    matches!(
-        year,
-        1972 | 1973
-            | 1974
-            | 1975
-            | 1976
-            | 1977
-            | 1978
-            | 1979
-            | 1980
-            | 1988
-            | 1990
-            | 1991
-            | 1996
-            | 1999
-            | 2006
-            | 2009
-            | 2017
-    )
-}
-
-/// Years when July had the leap second
-const fn july_years(year: i32) -> bool {
-    matches!(
-        year,
-        1972 | 1981 | 1982 | 1983 | 1985 | 1992 | 1993 | 1994 | 1997 | 2012 | 2015
-    )
-}
-
 /// Returns the usual days in a given month (zero indexed, i.e. January is month zero and December is month 11)
 ///
 /// # Warning
 /// This will return 0 days if the month is invalid.
-const fn usual_days_per_month(month: u8) -> u8 {
+pub(crate) const fn usual_days_per_month(month: u8) -> u8 {
     match month + 1 {
         1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
         4 | 6 | 9 | 11 => 30,
@@ -107,7 +96,7 @@ const fn usual_days_per_month(month: u8) -> u8 {
 }
 
 /// Calculates the prefix-sum of days counted up to the month start
-const CUMULATIVE_DAYS_FOR_MONTH: [u16; 12] = {
+pub(crate) const CUMULATIVE_DAYS_FOR_MONTH: [u16; 12] = {
     let mut days = [0; 12];
     let mut month = 1;
     while month < 12 {
@@ -117,12 +106,15 @@ const CUMULATIVE_DAYS_FOR_MONTH: [u16; 12] = {
     days
 };
 
+/// Number of days between 0000 March 01 and 1900 January 01 in the proleptic Gregorian calendar, used to compute the Gregorian dates.
+const DAYS_FROM_0000_03_01_TO_1900_01_01: i128 = 693_901;
+
 /// Defines a nanosecond-precision Epoch.
 ///
 /// Refer to the appropriate functions for initializing this Epoch from different time scales or representations.
 #[derive(Copy, Clone, Eq, Default)]
 #[repr(C)]
-#[cfg_attr(feature = "python", pyclass)]
+#[cfg_attr(feature = "python", pyclass(module = "hifitime"))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Epoch {
     /// An Epoch is always stored as the duration of since J1900 in the TAI time scale.
@@ -131,6 +123,24 @@ pub struct Epoch {
     pub time_scale: TimeScale,
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Epoch {
+    /// Generates an epoch from an arbitrary TAI duration past J1900 and an arbitrary time scale.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            duration_since_j1900_tai: u.arbitrary()?,
+            time_scale: u.arbitrary()?,
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(
+            <Duration as arbitrary::Arbitrary>::size_hint(depth),
+            <TimeScale as arbitrary::Arbitrary>::size_hint(depth),
+        )
+    }
+}
+
 impl Sub for Epoch {
     type Output = Duration;
 
@@ -155,6 +165,22 @@ impl Sub<Duration> for Epoch {
 
 /// WARNING: For speed, there is a possibility to add seconds directly to an Epoch. These will be added in the time scale the Epoch was initialized in.
 /// Using this is _discouraged_ and should only be used if you have facing bottlenecks with the units.
+///
+/// Like adding a `Duration`, the seconds are added to the duration since the reference epoch of that time scale. Hence, in UTC,
+/// the wall clock advances by the provided seconds and the leap seconds are skipped: the instant in TAI may advance by one more second.
+/// In the dynamical time scales (TT, ET and TDB), the instant in TAI advances by the provided seconds in that time scale.
+///
+/// ```
+/// use hifitime::prelude::*;
+///
+/// let before = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 0);
+/// // One second later on the UTC wall clock, but two seconds later in TAI because of the leap second.
+/// assert_eq!(before + 1.0, Epoch::from_gregorian_utc_at_midnight(2017, 1, 1));
+/// assert_eq!((before + 1.0) - before, 2.seconds());
+/// // Add to the epoch in TAI to advance the instant by exactly one second.
+/// let in_leap = before.with_time_scale(TimeScale::TAI) + 1.0;
+/// assert_eq!(in_leap, Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0));
+/// ```
 impl Add<f64> for Epoch {
     type Output = Self;
 
@@ -163,6 +189,32 @@ impl Add<f64> for Epoch {
     }
 }
 
+/// WARNING: For speed, there is a possibility to add seconds directly to an Epoch. These will be added in the time scale the Epoch was initialized in.
+/// Using this is _discouraged_ and should only be used if you have facing bottlenecks with the units.
+impl AddAssign<f64> for Epoch {
+    fn add_assign(&mut self, seconds: f64) {
+        *self = *self + seconds;
+    }
+}
+
+/// WARNING: For speed, there is a possibility to subtract seconds directly from an Epoch. These will be subtracted in the time scale the Epoch was initialized in.
+/// Using this is _discouraged_ and should only be used if you have facing bottlenecks with the units.
+impl Sub<f64> for Epoch {
+    type Output = Self;
+
+    fn sub(self, seconds: f64) -> Self {
+        self + (-seconds)
+    }
+}
+
+/// WARNING: For speed, there is a possibility to subtract seconds directly from an Epoch. These will be subtracted in the time scale the Epoch was initialized in.
+/// Using this is _discouraged_ and should only be used if you have facing bottlenecks with the units.
+impl SubAssign<f64> for Epoch {
+    fn sub_assign(&mut self, seconds: f64) {
+        *self = *self - seconds;
+    }
+}
+
 impl Add<Duration> for Epoch {
     type Output = Self;
 
@@ -209,6 +261,10 @@ impl AddAssign<Duration> for Epoch {
     }
 }
 
+forward_ref_binop! { impl Sub, sub for Epoch, Epoch }
+forward_ref_binop! { impl Add, add for Epoch, Duration }
+forward_ref_binop! { impl Sub, sub for Epoch, Duration }
+
 /// Equality only checks the duration since J1900 match in TAI, because this is how all of the epochs are referenced.
 impl PartialEq for Epoch {
     fn eq(&self, other: &Self) -> bool {
@@ -216,6 +272,7 @@ impl PartialEq for Epoch {
     }
 }
 
+/// Hashes the TAI duration only, like the equality which ignores the time scale.
 impl Hash for Epoch {
     fn hash<H: Hasher>(&self, hasher: &mut H) {
         self.duration_since_j1900_tai.hash(hasher);
@@ -240,24 +297,134 @@ impl Ord for Epoch {
 
 // Defines the methods that should be staticmethods in Python, but must be redefined as per https://github.com/PyO3/pyo3/issues/1003#issuecomment-844433346
 impl Epoch {
+    /// The earliest representable epoch, `Duration::MIN` before J1900 in TAI, i.e. 32,768 centuries before 1900 (in the Gregorian year -3,274,968).
+    pub const MIN: Self = Self::from_tai_duration(Duration::MIN);
+
+    /// The latest representable epoch, `Duration::MAX` after J1900 in TAI, i.e. 32,768 centuries after 1900 (in the Gregorian year 3,278,767).
+    /// Adding a positive duration to it saturates.
+    pub const MAX: Self = Self::from_tai_duration(Duration::MAX);
+
+    /// The reference epoch of all epochs, 1900 January 01 at midnight TAI.
+    pub const J1900_TAI: Self = J1900_REF_EPOCH;
+
+    /// The J2000 reference epoch, 2000 January 01 at noon TAI.
+    pub const J2000_TAI: Self = J2000_REF_EPOCH;
+
+    /// The reference epoch of the GPS time scale, 1980 January 06 at midnight UTC, in GPST.
+    pub const GPST_EPOCH: Self = Self {
+        duration_since_j1900_tai: GPST_REF_EPOCH.duration_since_j1900_tai,
+        time_scale: TimeScale::GPST,
+    };
+
+    /// The reference epoch of the Galileo time scale, 13 seconds before 1999 August 22 at midnight UTC, in GST.
+    pub const GST_EPOCH: Self = Self {
+        duration_since_j1900_tai: GST_REF_EPOCH.duration_since_j1900_tai,
+        time_scale: TimeScale::GST,
+    };
+
+    /// The reference epoch of the BeiDou time scale, 2006 January 01 at midnight UTC, in BDT.
+    pub const BDT_EPOCH: Self = Self {
+        duration_since_j1900_tai: BDT_REF_EPOCH.duration_since_j1900_tai,
+        time_scale: TimeScale::BDT,
+    };
+
+    /// The reference epoch of UNIX timestamps, 1970 January 01 at midnight UTC.
+    pub const UNIX_EPOCH: Self = Self {
+        duration_since_j1900_tai: UNIX_REF_EPOCH.duration_since_j1900_tai,
+        time_scale: TimeScale::UTC,
+    };
+
     /// Get the accumulated number of leap seconds up to this Epoch from the provided LeapSecondProvider.
     /// Returns None if the epoch is before 1960, year at which UTC was defined.
     ///
     /// # Why does this function return an `Option` when the other returns a value
     /// This is to match the `iauDat` function of SOFA (src/dat.c). That function will return a warning and give up if the start date is before 1960.
+    ///
+    /// The provider may be passed by value, by reference, or as a `&dyn LeapSecondProvider`.
     pub fn leap_seconds_with<L: LeapSecondProvider>(
         &self,
         iers_only: bool,
         provider: L,
     ) -> Option<f64> {
-        for leap_second in provider.rev() {
-            if self.duration_since_j1900_tai.to_seconds() >= leap_second.timestamp_tai_s
-                && (!iers_only || leap_second.announced_by_iers)
-            {
-                return Some(leap_second.delta_at);
-            }
+        provider.leap_seconds_at(self.duration_since_j1900_tai.to_seconds(), iers_only)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided UTC duration since 1900 January 01 at midnight, using the provided LeapSecondProvider
+    /// instead of the leap seconds embedded in this version of hifitime.
+    pub fn from_utc_duration_with<L: LeapSecondProvider>(duration: Duration, provider: L) -> Self {
+        let mut e = Self::from_tai_duration(duration);
+        // Compute the TAI to UTC offset at this time.
+        // We have the time in TAI. But we were given UTC.
+        // Hence, we need to _add_ the leap seconds to get the actual TAI time.
+        // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
+        e.duration_since_j1900_tai +=
+            e.leap_seconds_with(true, provider).unwrap_or(0.0) * Unit::Second;
+        e.time_scale = TimeScale::UTC;
+        e
+    }
+
+    #[must_use]
+    /// Returns this time in a Duration past J1900 counted in UTC, using the provided LeapSecondProvider
+    /// instead of the leap seconds embedded in this version of hifitime.
+    pub fn to_utc_duration_with<L: LeapSecondProvider>(&self, provider: L) -> Duration {
+        self.utc_duration_and_leap_second_with(&provider).0
+    }
+
+    /// Attempts to initialize an Epoch from the provided UTC duration since 1900 January 01 at midnight.
+    ///
+    /// UTC was only defined in 1960, and there are no leap seconds before that. Hence, the lenient `from_utc_duration` silently
+    /// treats any earlier UTC time as TAI, which is ambiguous for historical data. Instead, this function returns `Errors::UtcUndefined`
+    /// if the provided duration is before 1960 January 01.
+    pub fn try_from_utc_duration(duration: Duration) -> Result<Self, Errors> {
+        let e = Self::from_utc_duration(duration);
+        match e.leap_seconds(false) {
+            Some(_) => Ok(e),
+            None => Err(Errors::UtcUndefined),
+        }
+    }
+
+    /// Attempts to return this time in a Duration past J1900 counted in UTC.
+    ///
+    /// Unlike the lenient `to_utc_duration`, which treats epochs before 1960 as if UTC were TAI, this function returns
+    /// `Errors::UtcUndefined` if this epoch is before 1960 January 01, when UTC was not yet defined.
+    pub fn try_to_utc_duration(&self) -> Result<Duration, Errors> {
+        match self.leap_seconds(false) {
+            Some(_) => Ok(self.to_utc_duration()),
+            None => Err(Errors::UtcUndefined),
+        }
+    }
+
+    /// Returns this time in a Duration past J1900 counted in UTC and whether this epoch falls within an inserted leap second,
+    /// in which case the returned duration is that of the last second of the day (i.e. 23:59:59 instead of 23:59:60).
+    pub(crate) fn utc_duration_and_leap_second_with<L: LeapSecondProvider>(
+        &self,
+        provider: &L,
+    ) -> (Duration, bool) {
+        // The leap second table is indexed by UTC, so we first compute an approximate UTC time
+        // to find the number of leap seconds applicable at this TAI instant.
+        let tai_s = self.duration_since_j1900_tai.to_seconds();
+        let approx_delta_at = provider.leap_seconds_at(tai_s, true).unwrap_or(0.0);
+        let delta_at = provider
+            .leap_seconds_at(tai_s - approx_delta_at, true)
+            .unwrap_or(0.0);
+        // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
+        let utc = self.duration_since_j1900_tai - delta_at * Unit::Second;
+        let delta_at_utc = provider
+            .leap_seconds_at(utc.to_seconds(), true)
+            .unwrap_or(0.0);
+        // Providers which model the drift of UTC before 1972 lead to negligible differences here, which must not be
+        // mistaken for a leap second.
+        if delta_at_utc - delta_at > 1e-9 {
+            // This UTC time would already be in the next day, so we're in the inserted leap second.
+            let in_leap_second = utc - (delta_at_utc - delta_at) * Unit::Second;
+            // Only the IERS leap seconds are accounted for here, so the offset of UTC jumps by ten seconds in 1972:
+            // this entire step is reported as the last second of 1971, instead of overlapping the ten seconds before it.
+            let last_second = in_leap_second.ceil_to(Unit::Day) - Unit::Second;
+            (in_leap_second.max(last_second), true)
+        } else {
+            (utc, false)
         }
-        None
     }
 
     /// Makes a copy of self and sets the duration and time scale appropriately given the new duration
@@ -275,6 +442,36 @@ impl Epoch {
         }
     }
 
+    #[must_use]
+    /// Creates a new Epoch from the duration past J2000 (2000 January 01 at noon) on the clock of the provided time scale.
+    /// This is the inverse of `since_j2000`: refer to it for the reference of each time scale.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale, TimeUnits};
+    ///
+    /// assert_eq!(
+    ///     Epoch::from_since_j2000(1.days(), TimeScale::TT),
+    ///     Epoch::from_gregorian(2000, 1, 2, 12, 0, 0, 0, TimeScale::TT)
+    /// );
+    /// ```
+    pub fn from_since_j2000(duration: Duration, time_scale: TimeScale) -> Self {
+        let duration_wrt_1900 = duration + J2000_TO_J1900_DURATION;
+        match time_scale {
+            TimeScale::ET | TimeScale::TDB => Self::from_duration(duration, time_scale),
+            TimeScale::GPST => {
+                Self::from_gpst_duration(duration_wrt_1900 - GPST_REF_EPOCH.to_tai_duration())
+            }
+            TimeScale::GST => {
+                Self::from_gst_duration(duration_wrt_1900 - GST_REF_EPOCH.to_tai_duration())
+            }
+            TimeScale::BDT => {
+                Self::from_bdt_duration(duration_wrt_1900 - BDT_REF_EPOCH.to_tai_duration())
+            }
+            _ => Self::from_duration(duration_wrt_1900, time_scale),
+        }
+    }
+
     #[must_use]
     /// Creates a new Epoch from a Duration as the time difference between this epoch and TAI reference epoch.
     pub const fn from_tai_duration(duration: Duration) -> Self {
@@ -312,15 +509,10 @@ impl Epoch {
 
     #[must_use]
     /// Initialize an Epoch from the provided UTC seconds since 1900 January 01 at midnight
+    ///
+    /// Before 1960, UTC was not defined and is treated as TAI: use `try_from_utc_duration` to reject such durations.
     pub fn from_utc_duration(duration: Duration) -> Self {
-        let mut e = Self::from_tai_duration(duration);
-        // Compute the TAI to UTC offset at this time.
-        // We have the time in TAI. But we were given UTC.
-        // Hence, we need to _add_ the leap seconds to get the actual TAI time.
-        // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
-        e.duration_since_j1900_tai += e.leap_seconds(true).unwrap_or(0.0) * Unit::Second;
-        e.time_scale = TimeScale::UTC;
-        e
+        Self::from_utc_duration_with(duration, LatestLeapSeconds::default())
     }
 
     #[must_use]
@@ -368,6 +560,16 @@ impl Epoch {
         Self::from_tai_duration((days - J1900_OFFSET) * Unit::Day)
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the provided MJD in Terrestrial Time (TT)
+    pub fn from_mjd_tt(days: f64) -> Self {
+        assert!(
+            days.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tt_duration((days - J1900_OFFSET) * Unit::Day)
+    }
+
     fn from_mjd_in_time_scale(days: f64, time_scale: TimeScale) -> Self {
         // always refer to TAI/mjd
         let mut e = Self::from_mjd_tai(days);
@@ -404,6 +606,16 @@ impl Epoch {
         Self::from_tai_duration((days - J1900_OFFSET - MJD_OFFSET) * Unit::Day)
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the provided JDE in Terrestrial Time (TT)
+    pub fn from_jde_tt(days: f64) -> Self {
+        assert!(
+            days.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tt_duration((days - J1900_OFFSET - MJD_OFFSET) * Unit::Day)
+    }
+
     fn from_jde_in_time_scale(days: f64, time_scale: TimeScale) -> Self {
         // always refer to TAI/jde
         let mut e = Self::from_jde_tai(days);
@@ -431,6 +643,46 @@ impl Epoch {
         Self::from_jde_in_time_scale(days, TimeScale::BDT)
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the provided Modified Julian Date in days in any time scale.
+    /// This is the inverse of `to_mjd(time_scale, Unit::Day)`.
+    pub fn from_mjd(days: f64, time_scale: TimeScale) -> Self {
+        assert!(
+            days.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        match time_scale {
+            TimeScale::TT => Self::from_mjd_tt(days),
+            TimeScale::ET => {
+                Self::from_et_duration((days - J1900_OFFSET) * Unit::Day - J2000_TO_J1900_DURATION)
+            }
+            TimeScale::TDB => {
+                Self::from_tdb_duration((days - J1900_OFFSET) * Unit::Day - J2000_TO_J1900_DURATION)
+            }
+            _ => Self::from_mjd_in_time_scale(days, time_scale),
+        }
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Julian Days in any time scale.
+    /// This is the inverse of `to_jde(time_scale, Unit::Day)`.
+    pub fn from_jde(days: f64, time_scale: TimeScale) -> Self {
+        assert!(
+            days.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        match time_scale {
+            TimeScale::TT => Self::from_jde_tt(days),
+            TimeScale::ET => Self::from_et_duration(
+                (days - J1900_OFFSET - MJD_OFFSET) * Unit::Day - J2000_TO_J1900_DURATION,
+            ),
+            TimeScale::TDB => Self::from_tdb_duration(
+                (days - J1900_OFFSET - MJD_OFFSET) * Unit::Day - J2000_TO_J1900_DURATION,
+            ),
+            _ => Self::from_jde_in_time_scale(days, time_scale),
+        }
+    }
+
     #[must_use]
     /// Initialize an Epoch from the provided TT seconds (approximated to 32.184s delta from TAI)
     pub fn from_tt_seconds(seconds: f64) -> Self {
@@ -450,6 +702,21 @@ impl Epoch {
         }
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the provided Julian epoch, i.e. a decimal year of 365.25 days in Terrestrial Time (TT), such as J2000.0 or J2015.5.
+    ///
+    /// This is the inverse of `to_julian_year`: `J = 2000.0 + (JD_TT - 2451545.0) / 365.25`.
+    pub fn from_julian_year(year: f64) -> Self {
+        assert!(
+            year.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tt_duration(
+            Unit::Day * (J2000_OFFSET - J1900_OFFSET)
+                + Unit::Day * ((year - 2000.0) * DAYS_PER_YEAR),
+        )
+    }
+
     #[must_use]
     /// Initialize an Epoch from the Ephemeris Time seconds past 2000 JAN 01 (J2000 reference)
     pub fn from_et_seconds(seconds_since_j2000: f64) -> Epoch {
@@ -459,8 +726,9 @@ impl Epoch {
     /// Initializes an Epoch from the duration between J2000 and the current epoch as per NAIF SPICE.
     ///
     /// # Limitation
-    /// This method uses a Newton Raphson iteration to find the appropriate TAI duration. This method is only accuracy to a few nanoseconds.
-    /// Hence, when calling `as_et_duration()` and re-initializing it with `from_et_duration` you may have a few nanoseconds of difference (expect less than 10 ns).
+    /// The difference between ET and TAI is computed from the ET seconds directly, so no iteration is needed here. However,
+    /// `to_et_duration` iterates until that difference converges to within a nanosecond, so calling `to_et_duration` and
+    /// re-initializing it with `from_et_duration` may lead to a few nanoseconds of difference (expect less than 10 ns).
     ///
     /// # Warning
     /// The et2utc function of NAIF SPICE will assume that there are 9 leap seconds before 01 JAN 1972,
@@ -470,25 +738,12 @@ impl Epoch {
     /// In order to match SPICE, the as_et_duration() function will manually get rid of that difference.
     #[must_use]
     pub fn from_et_duration(duration_since_j2000: Duration) -> Self {
-        // Run a Newton Raphston to convert find the correct value of the
-        let mut seconds_j2000 = duration_since_j2000.to_seconds();
-        for _ in 0..5 {
-            seconds_j2000 += -NAIF_K
-                * (NAIF_M0
-                    + NAIF_M1 * seconds_j2000
-                    + NAIF_EB * (NAIF_M0 + NAIF_M1 * seconds_j2000).sin())
-                .sin();
-        }
-
-        // At this point, we have a good estimate of the number of seconds of this epoch.
-        // Reverse the algorithm:
-        let delta_et_tai =
-            Self::delta_et_tai(seconds_j2000 - (TT_OFFSET_MS * Unit::Millisecond).to_seconds());
+        // The mean anomaly is computed from the ET seconds, which we know, so the offset is direct.
+        let delta_et_tai = Self::delta_et_tai(duration_since_j2000.to_seconds());
 
-        // Match SPICE by changing the UTC definition.
+        // Only the offset is computed in floating point, so the full precision of the duration is kept.
         Self {
-            duration_since_j1900_tai: (duration_since_j2000.to_seconds() - delta_et_tai)
-                * Unit::Second
+            duration_since_j1900_tai: duration_since_j2000 - delta_et_tai * Unit::Second
                 + J2000_TO_J1900_DURATION,
             time_scale: TimeScale::ET,
         }
@@ -639,6 +894,69 @@ impl Epoch {
         Self::from_utc_duration(UNIX_REF_EPOCH.to_utc_duration() + millisecond * Unit::Millisecond)
     }
 
+    /// Initialize an Epoch from a UNIX second timestamp string, e.g. `1651487955` or `-1.5`.
+    ///
+    /// Unlike `from_unix_seconds`, the fractional part is converted to nanoseconds exactly and never goes through a float.
+    /// Digits beyond the ninth decimal are truncated since they cannot be represented.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let e = Epoch::from_unix_str("1651487955.123456789").unwrap();
+    /// assert_eq!(e, Epoch::from_gregorian_utc(2022, 5, 2, 10, 39, 15, 123_456_789));
+    /// assert_eq!(
+    ///     Epoch::from_unix_str("-1.5").unwrap(),
+    ///     Epoch::from_gregorian_utc_at_midnight(1970, 1, 1) - 1.5.seconds()
+    /// );
+    /// ```
+    pub fn from_unix_str(s: &str) -> Result<Self, Errors> {
+        let s = s.trim();
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let (int_str, frac_str) = match unsigned.split_once('.') {
+            Some((int_str, frac_str)) => (int_str, frac_str),
+            None => (unsigned, ""),
+        };
+
+        if (int_str.is_empty() && frac_str.is_empty())
+            || !int_str.bytes().all(|b| b.is_ascii_digit())
+            || !frac_str.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(Errors::ParseError(ParsingErrors::ValueError));
+        }
+
+        let seconds: i128 = if int_str.is_empty() {
+            0
+        } else {
+            match int_str.parse::<u64>() {
+                Ok(seconds) => i128::from(seconds),
+                Err(_) => return Err(Errors::ParseError(ParsingErrors::ValueError)),
+            }
+        };
+
+        // Right-pad the fraction to exactly nine digits, truncating anything past the nanosecond.
+        let mut nanoseconds: i128 = 0;
+        for pos in 0..9 {
+            nanoseconds *= 10;
+            if let Some(digit) = frac_str.as_bytes().get(pos) {
+                nanoseconds += i128::from(digit - b'0');
+            }
+        }
+
+        let mut total_ns = seconds * i128::from(NANOSECONDS_PER_SECOND) + nanoseconds;
+        if negative {
+            total_ns = -total_ns;
+        }
+
+        Ok(Self::from_utc_duration(
+            UNIX_REF_EPOCH.to_utc_duration() + Duration::from_total_nanoseconds(total_ns),
+        ))
+    }
+
     /// Attempts to build an Epoch from the provided Gregorian date and time in TAI.
     pub fn maybe_from_gregorian_tai(
         year: i32,
@@ -663,6 +981,9 @@ impl Epoch {
 
     /// Attempts to build an Epoch from the provided Gregorian date and time in the provided time scale.
     /// NOTE: If the time scale is TDB, this function assumes that the SPICE format is used
+    ///
+    /// Returns `Errors::Carry` if the date or time is invalid, and `Errors::Overflow` if the year is too far from 1900 to be represented,
+    /// i.e. more than about 3.2 million years.
     #[allow(clippy::too_many_arguments)]
     pub fn maybe_from_gregorian(
         year: i32,
@@ -674,39 +995,16 @@ impl Epoch {
         nanos: u32,
         time_scale: TimeScale,
     ) -> Result<Self, Errors> {
-        if !is_gregorian_valid(year, month, day, hour, minute, second, nanos) {
+        if !is_gregorian_valid_with_registered(year, month, day, hour, minute, second, nanos) {
             return Err(Errors::Carry);
         }
 
-        let years_since_1900 = year - 1900;
-        let mut duration_wrt_1900 = Unit::Day * i64::from(365 * years_since_1900);
-
-        // count leap years
-        if years_since_1900 > 0 {
-            // we don't count the leap year in 1904, since jan 1904 hasn't had the leap yet,
-            // so we push it back to 1905, same for all other leap years
-            let years_after_1900 = years_since_1900 - 1;
-            duration_wrt_1900 += Unit::Day * i64::from(years_after_1900 / 4);
-            duration_wrt_1900 -= Unit::Day * i64::from(years_after_1900 / 100);
-            // every 400 years we correct our correction. The first one after 1900 is 2000 (years_since_1900 = 100)
-            // so we add 300 to correct the offset
-            duration_wrt_1900 += Unit::Day * i64::from((years_after_1900 + 300) / 400);
-        } else {
-            // we don't need to fix the offset, since jan 1896 has had the leap, when counting back from 1900
-            duration_wrt_1900 += Unit::Day * i64::from(years_since_1900 / 4);
-            duration_wrt_1900 -= Unit::Day * i64::from(years_since_1900 / 100);
-            // every 400 years we correct our correction. The first one before 1900 is 1600 (years_since_1900 = -300)
-            // so we subtract 100 to correct the offset
-            duration_wrt_1900 += Unit::Day * i64::from((years_since_1900 - 100) / 400);
-        };
-
-        // Add the seconds for the months prior to the current month
-        duration_wrt_1900 += Unit::Day * i64::from(CUMULATIVE_DAYS_FOR_MONTH[(month - 1) as usize]);
-        if is_leap_year(year) && month > 2 {
-            // NOTE: If on 29th of February, then the day is not finished yet, and therefore
-            // the extra seconds are added below as per a normal day.
-            duration_wrt_1900 += Unit::Day;
+        // Count the days in i64, which cannot overflow for any i32 year, and reject the dates which do not fit in a Duration.
+        let days = days_since_j1900(year, month, 1);
+        if days.unsigned_abs() >= i16::MAX as u64 * DAYS_PER_CENTURY_I64 as u64 {
+            return Err(Errors::Overflow);
         }
+        let mut duration_wrt_1900 = Unit::Day * days;
         duration_wrt_1900 += Unit::Day * i64::from(day - 1)
             + Unit::Hour * i64::from(hour)
             + Unit::Minute * i64::from(minute)
@@ -724,7 +1022,14 @@ impl Epoch {
             TimeScale::TT => Self::from_tt_duration(duration_wrt_1900),
             TimeScale::ET => Self::from_et_duration(duration_wrt_1900 - J2000_TO_J1900_DURATION),
             TimeScale::TDB => Self::from_tdb_duration(duration_wrt_1900 - J2000_TO_J1900_DURATION),
-            TimeScale::UTC => Self::from_utc_duration(duration_wrt_1900),
+            TimeScale::UTC => {
+                let mut e = Self::from_utc_duration(duration_wrt_1900);
+                if second == 60 {
+                    // The leap second itself is one second after the last second of the day.
+                    e.duration_since_j1900_tai += Unit::Second;
+                }
+                e
+            }
             TimeScale::GPST => {
                 Self::from_gpst_duration(duration_wrt_1900 - GPST_REF_EPOCH.to_tai_duration())
             }
@@ -782,6 +1087,8 @@ impl Epoch {
     }
 
     /// Attempts to build an Epoch from the provided Gregorian date and time in UTC.
+    ///
+    /// Before 1960, UTC was not defined and the date is treated as a TAI date: use `maybe_from_gregorian_utc_strict` to reject such dates.
     pub fn maybe_from_gregorian_utc(
         year: i32,
         month: u8,
@@ -790,6 +1097,43 @@ impl Epoch {
         minute: u8,
         second: u8,
         nanos: u32,
+    ) -> Result<Self, Errors> {
+        Self::maybe_from_gregorian_utc_with(
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanos,
+            LatestLeapSeconds::default(),
+        )
+    }
+
+    /// Attempts to build an Epoch from the provided Gregorian date and time in UTC, using the provided LeapSecondProvider
+    /// instead of the leap seconds embedded in this version of hifitime.
+    ///
+    /// For example, use the `SofaLeapSeconds` provider to account for the offsets and drift of UTC between 1960 and 1972.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::leap_seconds::SofaLeapSeconds;
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let e = Epoch::maybe_from_gregorian_utc_with(1965, 6, 15, 0, 0, 0, 0, SofaLeapSeconds).unwrap();
+    /// // SOFA's iauDat returns 3.85397 seconds for this date.
+    /// assert_eq!(e - Epoch::from_gregorian_tai_at_midnight(1965, 6, 15), 3.85397.seconds());
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn maybe_from_gregorian_utc_with<L: LeapSecondProvider>(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+        provider: L,
     ) -> Result<Self, Errors> {
         let mut if_tai =
             Self::maybe_from_gregorian_tai(year, month, day, hour, minute, second, nanos)?;
@@ -797,11 +1141,47 @@ impl Epoch {
         // We have the time in TAI. But we were given UTC.
         // Hence, we need to _add_ the leap seconds to get the actual TAI time.
         // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
-        if_tai.duration_since_j1900_tai += if_tai.leap_seconds(true).unwrap_or(0.0) * Unit::Second;
+        if_tai.duration_since_j1900_tai +=
+            if_tai.leap_seconds_with(true, provider).unwrap_or(0.0) * Unit::Second;
+        if second == 60 {
+            // The leap second itself is one second after the last second of the day.
+            if_tai.duration_since_j1900_tai += Unit::Second;
+        }
         if_tai.time_scale = TimeScale::UTC;
         Ok(if_tai)
     }
 
+    /// Attempts to build an Epoch from the provided Gregorian date and time in UTC, failing if that date is before 1960.
+    ///
+    /// UTC was only defined in 1960, so `maybe_from_gregorian_utc` silently treats any earlier date as a TAI date.
+    /// Instead, this function returns `Errors::UtcUndefined` for such dates, and `Errors::Carry` for invalid dates.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, Errors};
+    ///
+    /// assert_eq!(
+    ///     Epoch::maybe_from_gregorian_utc_strict(1955, 1, 1, 0, 0, 0, 0),
+    ///     Err(Errors::UtcUndefined)
+    /// );
+    /// assert!(Epoch::maybe_from_gregorian_utc_strict(1965, 1, 1, 0, 0, 0, 0).is_ok());
+    /// ```
+    pub fn maybe_from_gregorian_utc_strict(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> Result<Self, Errors> {
+        let e = Self::maybe_from_gregorian_utc(year, month, day, hour, minute, second, nanos)?;
+        match e.leap_seconds(false) {
+            Some(_) => Ok(e),
+            None => Err(Errors::UtcUndefined),
+        }
+    }
+
     #[must_use]
     /// Builds an Epoch from the provided Gregorian date and time in UTC. If invalid date is provided, this function will panic.
     /// Use maybe_from_gregorian_utc if unsure.
@@ -864,15 +1244,122 @@ impl Epoch {
             .expect("invalid Gregorian date")
     }
 
+    #[allow(clippy::too_many_arguments)]
     #[must_use]
-    /// Initialize from Gregorian date in UTC at midnight
-    pub fn from_gregorian_at_midnight(
+    /// Builds an Epoch from the provided Gregorian date and time in the provided time scale, carrying the out-of-range fields into the next unit.
+    ///
+    /// Unlike the strict `from_gregorian` and `maybe_from_gregorian`, this is lenient, e.g. for computed date fields: the nanoseconds,
+    /// seconds, minutes and hours in excess carry into the following days, a month of 13 is January of the following year, and a day
+    /// past the end of the month carries into the following months. Likewise, a day or month of zero is the last one of the previous month or year.
+    /// The second 60 is the first second of the next minute, so use `from_gregorian` to build an epoch within a UTC leap second.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale};
+    ///
+    /// assert_eq!(
+    ///     Epoch::from_gregorian_normalized(2023, 1, 32, 0, 0, 0, 0, TimeScale::UTC),
+    ///     Epoch::from_gregorian_utc_at_midnight(2023, 2, 1)
+    /// );
+    /// ```
+    pub fn from_gregorian_normalized(
         year: i32,
         month: u8,
         day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
         time_scale: TimeScale,
     ) -> Self {
-        Self::maybe_from_gregorian(year, month, day, 0, 0, 0, 0, time_scale)
+        let time_of_day_ns = u64::from(hour) * NANOSECONDS_PER_HOUR
+            + u64::from(minute) * NANOSECONDS_PER_MINUTE
+            + u64::from(second) * NANOSECONDS_PER_SECOND
+            + u64::from(nanos);
+
+        let month_idx = i32::from(month) - 1;
+        let year = year.saturating_add(month_idx.div_euclid(12));
+        let month = month_idx.rem_euclid(12) as u8 + 1;
+
+        let days = days_since_j1900(year, month, 1) + i64::from(day) - 1
+            + (time_of_day_ns / NANOSECONDS_PER_DAY) as i64;
+        let (year, month, day, _, _, _, _) = Self::compute_gregorian(Unit::Day * days);
+
+        let time_of_day_ns = time_of_day_ns % NANOSECONDS_PER_DAY;
+        Self::from_gregorian(
+            year,
+            month,
+            day,
+            (time_of_day_ns / NANOSECONDS_PER_HOUR) as u8,
+            (time_of_day_ns % NANOSECONDS_PER_HOUR / NANOSECONDS_PER_MINUTE) as u8,
+            (time_of_day_ns % NANOSECONDS_PER_MINUTE / NANOSECONDS_PER_SECOND) as u8,
+            (time_of_day_ns % NANOSECONDS_PER_SECOND) as u32,
+            time_scale,
+        )
+    }
+
+    /// Attempts to build an Epoch from the provided Gregorian date and time in the provided time scale, where the seconds
+    /// (including their fraction) are provided as a floating point value, e.g. `55.811` as read from a spreadsheet.
+    ///
+    /// The seconds are rounded to the nearest nanosecond, with ties rounded away from zero, before being split into whole seconds
+    /// and nanoseconds. The result is then validated like `maybe_from_gregorian`, so a value rounding up to 60 seconds is only valid
+    /// during a leap second.
+    ///
+    /// # Errors
+    /// `Errors::Carry` if the seconds are negative, not finite, or if the date and time are invalid.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale};
+    ///
+    /// assert_eq!(
+    ///     Epoch::from_gregorian_fractional(2017, 1, 14, 0, 31, 55.811, TimeScale::TAI).unwrap(),
+    ///     Epoch::from_gregorian(2017, 1, 14, 0, 31, 55, 811_000_000, TimeScale::TAI)
+    /// );
+    /// assert!(Epoch::from_gregorian_fractional(2017, 1, 14, 0, 31, 59.999_999_999_9, TimeScale::TAI).is_err());
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_gregorian_fractional(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        seconds: f64,
+        time_scale: TimeScale,
+    ) -> Result<Self, Errors> {
+        // Anything beyond 60.999999999 seconds is invalid anyway.
+        if !(0.0..61.0).contains(&seconds) {
+            return Err(Errors::Carry);
+        }
+        let total_ns = (seconds * 1e9).round() as u64;
+        let second = (total_ns / NANOSECONDS_PER_SECOND) as u8;
+        let nanos = (total_ns % NANOSECONDS_PER_SECOND) as u32;
+        Self::maybe_from_gregorian(year, month, day, hour, minute, second, nanos, time_scale)
+    }
+
+    /// Attempts to build an Epoch from the provided Gregorian date and time in UTC, where the seconds (including their fraction)
+    /// are provided as a floating point value. Refer to `from_gregorian_fractional` for the rounding and validation.
+    pub fn from_gregorian_utc_fractional(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        seconds: f64,
+    ) -> Result<Self, Errors> {
+        Self::from_gregorian_fractional(year, month, day, hour, minute, seconds, TimeScale::UTC)
+    }
+
+    #[must_use]
+    /// Initialize from Gregorian date in UTC at midnight
+    pub fn from_gregorian_at_midnight(
+        year: i32,
+        month: u8,
+        day: u8,
+        time_scale: TimeScale,
+    ) -> Self {
+        Self::maybe_from_gregorian(year, month, day, 0, 0, 0, 0, time_scale)
             .expect("invalid Gregorian date")
     }
 
@@ -909,6 +1396,8 @@ impl Epoch {
     /// A time scale may be specified _in addition_ to the format unless
     /// The `T` which separates the date from the time can be replaced with a single whitespace character (`\W`).
     /// The offset is also optional, cf. the examples below.
+    /// Subseconds with more than nine digits (e.g. picoseconds) are truncated to the nanosecond.
+    /// The ordinal dates of ISO 8601, with exactly three digits for the day of the year (e.g. `2017-014T00:31:55`), are also supported.
     ///
     /// # Example
     /// ```
@@ -963,8 +1452,40 @@ impl Epoch {
         let mut cur_token = Token::Year;
 
         let s = s_in.trim();
+        // Expanded years of ISO 8601 have an explicit sign, e.g. `+012023` or `-000300`.
+        let (year_sign, s) = match s.strip_prefix('-') {
+            Some(s) => (-1, s),
+            None => (1, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        // Ordinal dates of ISO 8601 have exactly three digits for the day of the year instead of the month and day,
+        // e.g. `2023-045`, so the time (if any) is parsed from the character after the day of the year.
+        let mut start_idx = 0;
+        if let Some(sep) = s.find('-') {
+            let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+            let ordinal = s.get(sep + 1..sep + 4).filter(|part| all_digits(part));
+            let next = s.as_bytes().get(sep + 4);
+            if let Some(ordinal) = ordinal.filter(|_| {
+                sep >= 4
+                    && all_digits(&s[..sep])
+                    && !next.is_some_and(|b| b.is_ascii_digit() || *b == b'-')
+            }) {
+                let year = s[..sep].parse::<i32>()?;
+                let (month, day) = month_day_from_ordinal(year_sign * year, ordinal.parse()?)
+                    .ok_or(Errors::ParseError(ParsingErrors::DayOutOfRange))?;
+                decomposed[0] = year;
+                decomposed[1] = i32::from(month);
+                decomposed[2] = i32::from(day);
+                if let Some(next) = next {
+                    cur_token = Token::Day;
+                    cur_token.advance_with(char::from(*next))?;
+                }
+                start_idx = sep + 5;
+                prev_idx = start_idx;
+            }
+        }
 
-        for (idx, char) in s.chars().enumerate() {
+        for (idx, char) in s.chars().enumerate().skip(start_idx) {
             if !char.is_numeric() || idx == s.len() - 1 {
                 if cur_token == Token::Timescale {
                     // Then we match the timescale directly.
@@ -986,6 +1507,13 @@ impl Epoch {
                     idx + 1
                 };
 
+                // Subseconds beyond the nanosecond cannot be represented, so they are truncated.
+                let end_idx = if prev_token == Token::Subsecond {
+                    end_idx.min(prev_idx + 9)
+                } else {
+                    end_idx
+                };
+
                 match lexical_core::parse(s[prev_idx..end_idx].as_bytes()) {
                     Ok(val) => {
                         // Check that this valid is OK for the token we're reading it as.
@@ -1002,15 +1530,20 @@ impl Epoch {
                             decomposed[pos] = val
                         }
                     }
-                    Err(_) => return Err(Errors::ParseError(ParsingErrors::ISO8601)),
+                    Err(_) => {
+                        return Err(Errors::ParseError(match prev_token {
+                            Token::OffsetHours | Token::OffsetMinutes => {
+                                ParsingErrors::InvalidTimeZone
+                            }
+                            _ => ParsingErrors::ISO8601,
+                        }))
+                    }
                 }
+                // The start of the next value is after this separator, which is the sign of the offset if one follows.
                 prev_idx = idx + 1;
                 // If we are about to parse an hours offset, we need to set the sign now.
-                if cur_token == Token::OffsetHours {
-                    if &s[idx..idx + 1] == "-" {
-                        offset_sign = -1;
-                    }
-                    prev_idx += 1;
+                if cur_token == Token::OffsetHours && &s[idx..idx + 1] == "-" {
+                    offset_sign = -1;
                 }
             }
         }
@@ -1022,18 +1555,191 @@ impl Epoch {
             i64::from(decomposed[7]) * Unit::Hour + i64::from(decomposed[8]) * Unit::Minute
         };
 
-        let epoch = Self::maybe_from_gregorian(
-            decomposed[0],
+        let (year, month, day, hour, minute, second, nanos) = (
+            year_sign * decomposed[0],
             decomposed[1].try_into().unwrap(),
             decomposed[2].try_into().unwrap(),
             decomposed[3].try_into().unwrap(),
             decomposed[4].try_into().unwrap(),
             decomposed[5].try_into().unwrap(),
             decomposed[6].try_into().unwrap(),
-            ts,
         );
 
-        Ok(epoch? + tz)
+        let epoch = Self::maybe_from_gregorian(year, month, day, hour, minute, second, nanos, ts)
+            .map_err(|err| match err {
+            // Each component was individually checked while parsing, so either the day is after the end of its month,
+            // or this is a 60th second on a day without a leap second.
+            Errors::Carry => Errors::ParseError(
+                if is_gregorian_valid(year, month, day, hour, minute, 0, nanos) {
+                    ParsingErrors::SecondOutOfRange
+                } else {
+                    ParsingErrors::DayOutOfRange
+                },
+            ),
+            err => err,
+        })?;
+        if tz == Duration::ZERO {
+            // Do not go through the UTC duration to preserve the leap second, if any.
+            Ok(epoch)
+        } else {
+            Ok(epoch + tz)
+        }
+    }
+
+    /// Initializes an Epoch at midnight of the provided date formatted as `YYYY-MM-DD` (e.g. `2017-01-14`) in the provided time scale.
+    ///
+    /// Unlike `from_gregorian_str`, no time component is expected. Expanded years with an explicit sign (e.g. `-000300-01-01`) are supported.
+    /// Use `Duration::from_time_of_day_str` to add a time of day to this date.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale};
+    ///
+    /// assert_eq!(
+    ///     Epoch::from_date_str("2017-01-14", TimeScale::TAI).unwrap(),
+    ///     Epoch::from_gregorian_tai_at_midnight(2017, 1, 14)
+    /// );
+    /// assert!(Epoch::from_date_str("2017-02-30", TimeScale::UTC).is_err());
+    /// ```
+    pub fn from_date_str(s: &str, time_scale: TimeScale) -> Result<Self, Errors> {
+        let err = Errors::ParseError(ParsingErrors::ISO8601);
+
+        let s = s.trim();
+        let (year_sign, s) = match s.strip_prefix('-') {
+            Some(s) => (-1, s),
+            None => (1, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let mut parts = s.splitn(3, '-');
+        let (year, month, day) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(year), Some(month), Some(day)) => (year, month, day),
+            _ => return Err(err),
+        };
+        let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if year.len() < 4
+            || month.len() != 2
+            || day.len() != 2
+            || !all_digits(year)
+            || !all_digits(month)
+            || !all_digits(day)
+        {
+            return Err(err);
+        }
+
+        let year: i32 = year.parse()?;
+        Self::maybe_from_gregorian(
+            year_sign * year,
+            month.parse()?,
+            day.parse()?,
+            0,
+            0,
+            0,
+            0,
+            time_scale,
+        )
+    }
+
+    /// Initializes an Epoch from an RFC2822 date and time, e.g. `Sat, 14 Jan 2017 00:31:55 +0000`, as used in email and HTTP headers.
+    ///
+    /// The day of the week is optional, but if provided it must match the date. The zone may be a numerical `+HHMM` or `-HHMM` offset,
+    /// or one of the obsolete zone names of RFC2822: `UT` and `GMT` are UTC, and the US zones (e.g. `EST`) have their fixed offset.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// let e = Epoch::from_gregorian_utc_hms(2017, 1, 14, 0, 31, 55);
+    /// assert_eq!(Epoch::from_rfc2822("Sat, 14 Jan 2017 00:31:55 +0000").unwrap(), e);
+    /// assert_eq!(Epoch::from_rfc2822("14 Jan 2017 01:31:55 +0100").unwrap(), e);
+    /// assert_eq!(Epoch::from_rfc2822("Fri, 13 Jan 2017 19:31:55 EST").unwrap(), e);
+    /// ```
+    pub fn from_rfc2822(s_in: &str) -> Result<Self, Errors> {
+        let err = Errors::ParseError(ParsingErrors::RFC2822);
+
+        let (weekday, s) = match s_in.trim().split_once(',') {
+            Some((weekday, s)) => (
+                Some(Weekday::from_str(weekday).map_err(Errors::ParseError)?),
+                s,
+            ),
+            None => (None, s_in.trim()),
+        };
+
+        let mut parts = s.split_whitespace();
+        let day: u8 = parts.next().ok_or(err)?.parse().map_err(|_| err)?;
+        let month = MonthName::from_str(parts.next().ok_or(err)?).map_err(Errors::ParseError)?;
+        let year_str = parts.next().ok_or(err)?;
+        let year: i32 = year_str.parse().map_err(|_| err)?;
+        // Obsolete two and three digit years, cf. section 4.3 of RFC2822
+        let year = match year_str.len() {
+            2 if year < 50 => year + 2000,
+            2 | 3 => year + 1900,
+            4 => year,
+            _ => return Err(err),
+        };
+
+        let mut time = parts.next().ok_or(err)?.split(':');
+        let hour: u8 = time.next().ok_or(err)?.parse().map_err(|_| err)?;
+        let minute: u8 = time.next().ok_or(err)?.parse().map_err(|_| err)?;
+        let second: u8 = match time.next() {
+            Some(second) => second.parse().map_err(|_| err)?,
+            None => 0,
+        };
+        if time.next().is_some() {
+            return Err(err);
+        }
+
+        // Offset of the local time with respect to UTC, in minutes
+        let zone = parts.next().ok_or(err)?;
+        let offset_min: i64 = match zone {
+            "UT" | "GMT" | "Z" => 0,
+            "EDT" => -4 * 60,
+            "EST" | "CDT" => -5 * 60,
+            "CST" | "MDT" => -6 * 60,
+            "MST" | "PDT" => -7 * 60,
+            "PST" => -8 * 60,
+            _ => {
+                // Check that the zone is ASCII before slicing it at byte indices.
+                if !zone.is_ascii()
+                    || zone.len() != 5
+                    || !zone[1..].bytes().all(|b| b.is_ascii_digit())
+                {
+                    return Err(err);
+                }
+                let hhmm: i64 = zone[1..].parse().map_err(|_| err)?;
+                if hhmm % 100 > 59 {
+                    return Err(err);
+                }
+                let minutes = (hhmm / 100) * 60 + hhmm % 100;
+                match &zone[..1] {
+                    "+" => minutes,
+                    "-" => -minutes,
+                    _ => return Err(err),
+                }
+            }
+        };
+        if parts.next().is_some() {
+            return Err(err);
+        }
+
+        let local =
+            Self::maybe_from_gregorian_utc(year, month.as_u8(), day, hour, minute, second, 0)?;
+
+        if let Some(found) = weekday {
+            let expected = local.weekday_utc();
+            if found != expected {
+                return Err(Errors::ParseError(ParsingErrors::WeekdayMismatch {
+                    found,
+                    expected,
+                }));
+            }
+        }
+
+        if offset_min == 0 {
+            // Do not go through the UTC duration to preserve the leap second, if any.
+            Ok(local)
+        } else {
+            Ok(local - offset_min * Unit::Minute)
+        }
     }
 
     /// Initializes an Epoch from the provided Format.
@@ -1067,6 +1773,43 @@ impl Epoch {
         (TT_OFFSET_MS * Unit::Millisecond).to_seconds() + NAIF_K * e.sin()
     }
 
+    #[must_use]
+    /// Returns this epoch as a local time with the provided fixed offset from UTC, e.g. `-5.hours()` for UTC-05:00.
+    pub fn to_local(&self, offset: Duration) -> LocalTime {
+        LocalTime::new(*self, offset)
+    }
+
+    #[must_use]
+    /// Returns the Gregorian date and time of this epoch in the provided time scale, with named fields.
+    ///
+    /// In UTC, the second is 60 during a leap second. The result can be converted back with `Gregorian::to_epoch` or `Epoch::try_from`,
+    /// which return this exact epoch, cf. `Gregorian::to_epoch` for the precision of the dynamical time scales.
+    pub fn to_gregorian(&self, time_scale: TimeScale) -> Gregorian {
+        let (year, month, day, hour, minute, second, nanos) = self.compute_gregorian_in(time_scale);
+        Gregorian {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanos,
+            time_scale,
+        }
+    }
+
+    /// Returns the Gregorian representation of this epoch in the provided time scale, accounting for leap seconds in UTC.
+    pub(crate) fn compute_gregorian_in(
+        &self,
+        time_scale: TimeScale,
+    ) -> (i32, u8, u8, u8, u8, u8, u32) {
+        if time_scale == TimeScale::UTC {
+            self.to_gregorian_utc()
+        } else {
+            Self::compute_gregorian(self.to_duration_since_j1900_in_time_scale(time_scale))
+        }
+    }
+
     fn inner_g(seconds: f64) -> f64 {
         use core::f64::consts::TAU;
         let g = TAU / 360.0 * 357.528 + 1.990_910_018_065_731e-7 * seconds;
@@ -1075,115 +1818,44 @@ impl Epoch {
     }
 
     pub(crate) fn compute_gregorian(duration_j1900: Duration) -> (i32, u8, u8, u8, u8, u8, u32) {
-        let (sign, days, hours, minutes, seconds, milliseconds, microseconds, nanos) =
-            duration_j1900.decompose();
-
-        let days_f64 = if sign < 0 {
-            -(days as f64)
+        // Split the duration into whole days since 1900 January 01 and the time of day, which is never negative.
+        let total_ns = duration_j1900.total_nanoseconds();
+        let days = total_ns.div_euclid(i128::from(NANOSECONDS_PER_DAY));
+        let time_of_day_ns = total_ns.rem_euclid(i128::from(NANOSECONDS_PER_DAY)) as u64;
+
+        // Convert the days into a date in the proleptic Gregorian calendar, using eras of 400 years.
+        // The shift makes the day zero fall on 0000 March 01, so that leap days are at the end of each year.
+        // Algorithm from http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = days + DAYS_FROM_0000_03_01_TO_1900_01_01;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        // Month starting at zero for March
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
         } else {
-            days as f64
+            shifted_month - 9
         };
+        let year = year_of_era + era * 400 + i128::from(month <= 2);
 
-        let (mut year, mut days_in_year) = div_rem_f64(days_f64, DAYS_PER_YEAR_NLD);
-        // TAI is defined at 1900, so a negative time is before 1900 and positive is after 1900.
-        year += 1900;
-
-        // Base calculation was on 365 days, so we need to remove one day in seconds per leap year
-        // between 1900 and `year`
-        if year >= 1900 {
-            for year in 1900..year {
-                if is_leap_year(year) {
-                    days_in_year -= 1.0;
-                }
-            }
-        } else {
-            for year in year..1900 {
-                if is_leap_year(year) {
-                    days_in_year += 1.0;
-                }
-            }
-        }
-
-        // Get the month from the exact number of seconds between the start of the year and now
-        let mut month = 1;
-        let mut day;
+        let hours = time_of_day_ns / NANOSECONDS_PER_HOUR;
+        let minutes = (time_of_day_ns % NANOSECONDS_PER_HOUR) / NANOSECONDS_PER_MINUTE;
+        let seconds = (time_of_day_ns % NANOSECONDS_PER_MINUTE) / NANOSECONDS_PER_SECOND;
+        let nanos = time_of_day_ns % NANOSECONDS_PER_SECOND;
 
-        let mut days_so_far = 0.0;
-        loop {
-            let mut days_next_month = usual_days_per_month(month - 1) as f64;
-            if month == 2 && is_leap_year(year) {
-                days_next_month += 1.0;
-            }
-
-            if days_so_far + days_next_month > days_in_year || month == 12 {
-                // We've found the month and can calculate the days
-                day = if sign >= 0 {
-                    days_in_year - days_so_far + 1.0
-                } else {
-                    days_in_year - days_so_far - 1.0
-                };
-                break;
-            }
-
-            // Otherwise, count up the number of days this year so far and keep track of the month.
-            days_so_far += days_next_month;
-            month += 1;
-        }
-
-        if day <= 0.0 || days_in_year < 0.0 {
-            // We've overflowed backward
-            month = 12;
-            year -= 1;
-            // NOTE: Leap year is already accounted for in the TAI duration when counting backward.
-            day = if days_in_year < 0.0 {
-                days_in_year + usual_days_per_month(11) as f64 + 1.0
-            } else {
-                usual_days_per_month(11) as f64
-            };
-        } else if sign < 0 {
-            // Must add one day because just below, we'll be ignoring the days when rebuilding the time.
-            day += 1.0;
-        }
-
-        if sign < 0 {
-            let time = Duration::compose(
-                sign,
-                0,
-                hours,
-                minutes,
-                seconds,
-                milliseconds,
-                microseconds,
-                nanos,
-            );
-
-            let (_, _, hours, minutes, seconds, milliseconds, microseconds, nanos) =
-                (24 * Unit::Hour + time).decompose();
-
-            (
-                year,
-                month,
-                day as u8,
-                hours as u8,
-                minutes as u8,
-                seconds as u8,
-                (nanos
-                    + microseconds * NANOSECONDS_PER_MICROSECOND
-                    + milliseconds * NANOSECONDS_PER_MILLISECOND) as u32,
-            )
-        } else {
-            (
-                year,
-                month,
-                day as u8,
-                hours as u8,
-                minutes as u8,
-                seconds as u8,
-                (nanos
-                    + microseconds * NANOSECONDS_PER_MICROSECOND
-                    + milliseconds * NANOSECONDS_PER_MILLISECOND) as u32,
-            )
-        }
+        (
+            year as i32,
+            month as u8,
+            day as u8,
+            hours as u8,
+            minutes as u8,
+            seconds as u8,
+            nanos as u32,
+        )
     }
 
     /// Builds an Epoch from given `week`: elapsed weeks counter into the desired Time scale, and the amount of nanoseconds within that week.
@@ -1214,68 +1886,364 @@ impl Epoch {
         let start_of_year = Self::from_gregorian(year, 1, 1, 0, 0, 0, 0, time_scale);
         start_of_year + days * Unit::Day
     }
-}
 
-#[cfg_attr(feature = "python", pymethods)]
-impl Epoch {
-    #[must_use]
-    /// Get the accumulated number of leap seconds up to this Epoch accounting only for the IERS leap seconds.
-    pub fn leap_seconds_iers(&self) -> i32 {
-        match self.leap_seconds(true) {
-            Some(v) => v as i32,
-            None => 0,
+    /// Builds an Epoch from the provided year and fractional day of the year in the provided time scale, as used in
+    /// two-line element sets (TLE) and many astronomy formats.
+    ///
+    /// The day of year is 1-based: day 1.0 is January 01 at midnight, and day 45.5 is February 14 at noon.
+    /// The fractional part of the day is added as a duration in the provided time scale.
+    ///
+    /// # Errors
+    /// `Errors::Carry` if the day of year is not finite, before 1.0, or after the end of the year (e.g. 366.0 in a non-leap year).
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale};
+    ///
+    /// let epoch = Epoch::from_year_day(2023, 45.5, TimeScale::UTC).unwrap();
+    /// assert_eq!(epoch, Epoch::from_gregorian_utc_hms(2023, 2, 14, 12, 0, 0));
+    /// assert!(Epoch::from_year_day(2023, 366.0, TimeScale::UTC).is_err());
+    /// ```
+    pub fn from_year_day(
+        year: i32,
+        day_of_year: f64,
+        time_scale: TimeScale,
+    ) -> Result<Self, Errors> {
+        let days_in_year = if is_leap_year(year) { 366.0 } else { 365.0 };
+        if !day_of_year.is_finite() || !(1.0..days_in_year + 1.0).contains(&day_of_year) {
+            return Err(Errors::Carry);
         }
-    }
 
-    /// Get the accumulated number of leap seconds up to this Epoch accounting only for the IERS leap seconds and the SOFA scaling from 1960 to 1972, depending on flag.
-    /// Returns None if the epoch is before 1960, year at which UTC was defined.
+        let whole_day = day_of_year.floor();
+        // Zero-based day of the year, which is less than 366 per the check above.
+        let day_idx = whole_day as u16 - 1;
+        // Days counted up to the start of the provided month index, including February 29 in leap years.
+        let days_before_month = |month_idx: usize| {
+            CUMULATIVE_DAYS_FOR_MONTH[month_idx] + u16::from(is_leap_year(year) && month_idx >= 2)
+        };
+        let month_idx = (0..12)
+            .rev()
+            .find(|&month_idx| days_before_month(month_idx) <= day_idx)
+            .unwrap_or(0);
+        let day = day_idx - days_before_month(month_idx) + 1;
+
+        let start_of_day = Self::maybe_from_gregorian(
+            year,
+            month_idx as u8 + 1,
+            day as u8,
+            0,
+            0,
+            0,
+            0,
+            time_scale,
+        )?;
+        Ok(start_of_day + (day_of_year - whole_day) * Unit::Day)
+    }
+
+    /// Converts each of the provided epochs to its UTC duration since 1900 January 01 at midnight, storing it in `out`.
     ///
-    /// # Why does this function return an `Option` when the other returns a value
-    /// This is to match the `iauDat` function of SOFA (src/dat.c). That function will return a warning and give up if the start date is before 1960.
-    pub fn leap_seconds(&self, iers_only: bool) -> Option<f64> {
-        self.leap_seconds_with(iers_only, LatestLeapSeconds::default())
-    }
+    /// The results are identical to calling `to_utc_duration` on each epoch. However, if the epochs are sorted
+    /// in chronological order (e.g. a column of telemetry timestamps), the leap seconds table is walked through once
+    /// for the whole slice instead of being searched for each epoch, which is significantly faster for large slices.
+    /// Unsorted epochs are converted one by one.
+    ///
+    /// # Panics
+    /// If `epochs` and `out` have different lengths.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, Epoch, TimeUnits};
+    ///
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2016, 12, 31);
+    /// let epochs = [start, start + 1.days(), start + 2.days()];
+    /// let mut out = [Duration::ZERO; 3];
+    /// Epoch::to_utc_duration_batch(&epochs, &mut out);
+    /// for (epoch, utc) in epochs.iter().zip(out) {
+    ///     assert_eq!(epoch.to_utc_duration(), utc);
+    /// }
+    /// ```
+    pub fn to_utc_duration_batch(epochs: &[Self], out: &mut [Duration]) {
+        assert_eq!(
+            epochs.len(),
+            out.len(),
+            "to_utc_duration_batch requires an output slice of the same length as the epochs"
+        );
 
-    #[cfg(feature = "ut1")]
-    /// Get the accumulated offset between this epoch and UT1, assuming that the provider includes all data.
-    pub fn ut1_offset(&self, provider: Ut1Provider) -> Option<Duration> {
-        for delta_tai_ut1 in provider.rev() {
-            if self > &delta_tai_ut1.epoch {
-                return Some(delta_tai_ut1.delta_tai_minus_ut1);
+        if epochs.windows(2).all(|pair| pair[0] <= pair[1]) {
+            let cursor = SortedLeapSecondsCursor::new();
+            for (epoch, utc) in epochs.iter().zip(out.iter_mut()) {
+                *utc = epoch.utc_duration_and_leap_second_with(&cursor).0;
+            }
+        } else {
+            for (epoch, utc) in epochs.iter().zip(out.iter_mut()) {
+                *utc = epoch.to_utc_duration();
             }
         }
-        None
     }
 
-    /// Get the accumulated number of leap seconds up to this Epoch from the provided LeapSecondProvider.
-    /// Returns None if the epoch is before 1960, year at which UTC was defined.
+    /// Converts each of the provided epochs to its number of seconds since the UNIX epoch, storing it in `out`.
     ///
-    /// # Why does this function return an `Option` when the other returns a value
-    /// This is to match the `iauDat` function of SOFA (src/dat.c). That function will return a warning and give up if the start date is before 1960.
-    #[cfg(feature = "python")]
-    pub fn leap_seconds_with_file(
-        &self,
-        iers_only: bool,
-        provider: LeapSecondsFile,
-    ) -> Option<f64> {
-        self.leap_seconds_with(iers_only, provider)
-    }
+    /// The results are identical to calling `to_unix_seconds` on each epoch, and sorted epochs benefit from the
+    /// same fast path as `to_utc_duration_batch`.
+    ///
+    /// # Panics
+    /// If `epochs` and `out` have different lengths.
+    pub fn to_unix_seconds_batch(epochs: &[Self], out: &mut [f64]) {
+        assert_eq!(
+            epochs.len(),
+            out.len(),
+            "to_unix_seconds_batch requires an output slice of the same length as the epochs"
+        );
 
-    #[cfg(feature = "python")]
-    #[staticmethod]
-    /// Creates a new Epoch from a Duration as the time difference between this epoch and TAI reference epoch.
-    const fn init_from_tai_duration(duration: Duration) -> Self {
-        Self::from_tai_duration(duration)
+        let unix_ref_utc = UNIX_REF_EPOCH.to_utc_duration();
+        if epochs.windows(2).all(|pair| pair[0] <= pair[1]) {
+            let cursor = SortedLeapSecondsCursor::new();
+            for (epoch, unix_s) in epochs.iter().zip(out.iter_mut()) {
+                let utc = epoch.utc_duration_and_leap_second_with(&cursor).0;
+                *unix_s = (utc - unix_ref_utc).to_seconds();
+            }
+        } else {
+            for (epoch, unix_s) in epochs.iter().zip(out.iter_mut()) {
+                *unix_s = epoch.to_unix_seconds();
+            }
+        }
     }
 
-    #[cfg(feature = "python")]
-    #[staticmethod]
-    /// Creates a new Epoch from its centuries and nanosecond since the TAI reference epoch.
-    fn init_from_tai_parts(centuries: i16, nanoseconds: u64) -> Self {
-        Self::from_tai_parts(centuries, nanoseconds)
+    #[must_use]
+    /// Returns an iterator of `count` epochs starting at this epoch and stepping by one calendar month, e.g. for billing or reporting cadences.
+    ///
+    /// Unlike a `TimeSeries`, the step is not a fixed duration since the months vary in length. The day of the month of the start
+    /// is clamped to the last day of shorter months but remembered for the following months, cf. `MonthlySeries`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// let start = Epoch::from_gregorian_utc_at_noon(2024, 1, 31);
+    /// let months: Vec<Epoch> = Epoch::monthly(start, 3).collect();
+    /// assert_eq!(
+    ///     months,
+    ///     [
+    ///         start,
+    ///         Epoch::from_gregorian_utc_at_noon(2024, 2, 29),
+    ///         Epoch::from_gregorian_utc_at_noon(2024, 3, 31)
+    ///     ]
+    /// );
+    /// ```
+    pub const fn monthly(self, count: usize) -> MonthlySeries {
+        MonthlySeries::new(self, count)
     }
 
-    #[cfg(feature = "python")]
+    /// Checks that this epoch is rebuilt from its own duration in the time scale it was initialized in, i.e. that
+    /// `Epoch::from_duration(self.to_duration(), self.time_scale)` is this epoch, returning `Errors::InconsistentConversion` otherwise.
+    ///
+    /// This is the invariant that all of the conversions of this library maintain, and this diagnostic may be called in test suites
+    /// to catch regressions after upgrades. The round trip is exact to the nanosecond, except in ET and TDB whose conversions are computed
+    /// with floating point values, where it may differ by up to 10 nanoseconds. An inserted UTC leap second has no UTC duration of its own,
+    /// so it is instead rebuilt from its Gregorian representation.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale};
+    ///
+    /// let e = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000);
+    /// assert!(e.debug_consistency().is_ok());
+    /// assert!(e.with_time_scale(TimeScale::TDB).debug_consistency().is_ok());
+    /// ```
+    pub fn debug_consistency(&self) -> Result<(), Errors> {
+        let rebuilt = match self.time_scale {
+            TimeScale::UTC => {
+                let (y, mm, dd, hh, min, s, nanos) = self.to_gregorian_utc();
+                if s == 60 {
+                    Self::maybe_from_gregorian_utc(y, mm, dd, hh, min, s, nanos)?
+                } else {
+                    Self::from_utc_duration(self.to_utc_duration())
+                }
+            }
+            time_scale => Self::from_duration(self.to_duration(), time_scale),
+        };
+
+        let tolerance = match self.time_scale {
+            TimeScale::ET | TimeScale::TDB => 10 * Unit::Nanosecond,
+            _ => Duration::ZERO,
+        };
+        if rebuilt.time_scale == self.time_scale && (rebuilt - *self).abs() <= tolerance {
+            Ok(())
+        } else {
+            Err(Errors::InconsistentConversion)
+        }
+    }
+
+    /// Returns the leap seconds announced by the IERS whose instant falls in `[start, end)`, in chronological order,
+    /// as tuples of the UTC midnight at which the new offset applies (like `next_leap_second`) and that offset ΔAT (TAI - UTC) in seconds.
+    ///
+    /// This is scoped to the built-in leap seconds table, like `leap_seconds_table`, and does not allocate.
+    /// Note that the boundaries of `leap_seconds_table` are the stored timestamps instead, which are earlier by ΔAT.
+    /// Use `leap_seconds_in_range` to collect them in a vector.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2016, 1, 1);
+    /// let end = Epoch::from_gregorian_utc_at_midnight(2018, 1, 1);
+    /// let (boundary, delta_at) = Epoch::leap_seconds_in_range_iter(start, end).next().unwrap();
+    /// assert_eq!(boundary, Epoch::from_gregorian_utc_at_midnight(2017, 1, 1));
+    /// assert_eq!(delta_at, 37.0);
+    /// ```
+    pub fn leap_seconds_in_range_iter(
+        start: Self,
+        end: Self,
+    ) -> impl DoubleEndedIterator<Item = (Self, f64)> {
+        LatestLeapSeconds::default()
+            .filter(|leap_second| leap_second.announced_by_iers)
+            .map(|leap_second| {
+                // TAI = UTC + leap_seconds, with the new ΔAT applying from that UTC midnight.
+                let boundary =
+                    Self::from_tai_seconds(leap_second.timestamp_tai_s + leap_second.delta_at)
+                        .with_time_scale(TimeScale::UTC);
+                (boundary, leap_second.delta_at)
+            })
+            .filter(move |(boundary, _)| (start..end).contains(boundary))
+    }
+
+    #[cfg(feature = "std")]
+    #[must_use]
+    /// Returns the leap seconds announced by the IERS whose instant falls in `[start, end)`, in chronological order,
+    /// as tuples of the UTC midnight at which the new offset applies and that offset ΔAT (TAI - UTC) in seconds.
+    ///
+    /// Refer to `leap_seconds_in_range_iter` for the variant which does not allocate.
+    pub fn leap_seconds_in_range(start: Self, end: Self) -> Vec<(Self, f64)> {
+        Self::leap_seconds_in_range_iter(start, end).collect()
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Epoch {
+    #[must_use]
+    /// Get the accumulated number of leap seconds up to this Epoch accounting only for the IERS leap seconds.
+    pub fn leap_seconds_iers(&self) -> i32 {
+        match self.leap_seconds(true) {
+            Some(v) => v as i32,
+            None => 0,
+        }
+    }
+
+    #[must_use]
+    /// Returns the offset of TAI with respect to UTC (ΔAT) at this epoch as a Duration, i.e. the accumulated IERS leap seconds.
+    /// This is zero before 1972, when only the IERS leap seconds are accounted for. During an inserted leap second,
+    /// this is already the offset applicable after that leap second.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// assert_eq!(epoch.tai_minus_utc(), 37.seconds());
+    /// assert_eq!(epoch.to_tai_duration() - epoch.to_utc_duration(), epoch.tai_minus_utc());
+    /// ```
+    pub fn tai_minus_utc(&self) -> Duration {
+        self.duration_since_j1900_tai - self.to_utc_duration()
+    }
+
+    #[must_use]
+    /// Returns the offset of UTC with respect to TAI at this epoch as a Duration, i.e. the opposite of `tai_minus_utc`.
+    pub fn utc_minus_tai(&self) -> Duration {
+        -self.tai_minus_utc()
+    }
+
+    /// Get the accumulated number of leap seconds up to this Epoch accounting only for the IERS leap seconds and the SOFA scaling from 1960 to 1972, depending on flag.
+    /// Returns None if the epoch is before 1960, year at which UTC was defined.
+    ///
+    /// # Why does this function return an `Option` when the other returns a value
+    /// This is to match the `iauDat` function of SOFA (src/dat.c). That function will return a warning and give up if the start date is before 1960.
+    pub fn leap_seconds(&self, iers_only: bool) -> Option<f64> {
+        self.leap_seconds_with(iers_only, LatestLeapSeconds::default())
+    }
+
+    #[must_use]
+    /// Returns the number of leap seconds announced by the IERS which were inserted between the `other` epoch and this epoch,
+    /// i.e. the difference between the accumulated IERS leap seconds at this epoch and at the other epoch.
+    /// This is the number of seconds by which a UTC interval differs from the TAI interval between these two epochs.
+    /// The result is negative if this epoch is before the other epoch.
+    ///
+    /// Returns None if either epoch is before 1960, year at which UTC was defined.
+    pub fn leap_seconds_since(&self, other: Self) -> Option<f64> {
+        // Check that both epochs are covered by the table, including the SOFA leap seconds.
+        self.leap_seconds(false)?;
+        other.leap_seconds(false)?;
+        // There were no IERS leap seconds prior to 1972.
+        Some(self.leap_seconds(true).unwrap_or(0.0) - other.leap_seconds(true).unwrap_or(0.0))
+    }
+
+    #[must_use]
+    /// Returns the instant of the next leap second announced by the IERS strictly after this epoch, i.e. the UTC midnight at which the new ΔAT applies.
+    /// Returns None if there is no known leap second after this epoch: the built-in table only knows of leap seconds through January 2017.
+    pub fn next_leap_second(&self) -> Option<Self> {
+        LatestLeapSeconds::default()
+            .filter(|leap_second| leap_second.announced_by_iers)
+            // TAI = UTC + leap_seconds, with the new ΔAT applying from that UTC midnight.
+            .map(|leap_second| {
+                Self::from_tai_seconds(leap_second.timestamp_tai_s + leap_second.delta_at)
+                    .with_time_scale(TimeScale::UTC)
+            })
+            .find(|boundary| boundary > self)
+    }
+
+    #[must_use]
+    /// Returns the instant of the most recent leap second announced by the IERS at or before this epoch, i.e. the UTC midnight at which the new ΔAT applies.
+    /// Returns None if this epoch is before the first IERS leap second of January 1972.
+    pub fn previous_leap_second(&self) -> Option<Self> {
+        LatestLeapSeconds::default()
+            .rev()
+            .filter(|leap_second| leap_second.announced_by_iers)
+            .map(|leap_second| {
+                Self::from_tai_seconds(leap_second.timestamp_tai_s + leap_second.delta_at)
+                    .with_time_scale(TimeScale::UTC)
+            })
+            .find(|boundary| boundary <= self)
+    }
+
+    #[cfg(feature = "ut1")]
+    /// Get the accumulated offset between this epoch and UT1, assuming that the provider includes all data.
+    pub fn ut1_offset(&self, provider: Ut1Provider) -> Option<Duration> {
+        for delta_tai_ut1 in provider.rev() {
+            if self > &delta_tai_ut1.epoch {
+                return Some(delta_tai_ut1.delta_tai_minus_ut1);
+            }
+        }
+        None
+    }
+
+    /// Get the accumulated number of leap seconds up to this Epoch from the provided LeapSecondProvider.
+    /// Returns None if the epoch is before 1960, year at which UTC was defined.
+    ///
+    /// # Why does this function return an `Option` when the other returns a value
+    /// This is to match the `iauDat` function of SOFA (src/dat.c). That function will return a warning and give up if the start date is before 1960.
+    #[cfg(feature = "python")]
+    pub fn leap_seconds_with_file(
+        &self,
+        iers_only: bool,
+        provider: LeapSecondsFile,
+    ) -> Option<f64> {
+        self.leap_seconds_with(iers_only, provider)
+    }
+
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    /// Creates a new Epoch from a Duration as the time difference between this epoch and TAI reference epoch.
+    const fn init_from_tai_duration(duration: Duration) -> Self {
+        Self::from_tai_duration(duration)
+    }
+
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    /// Creates a new Epoch from its centuries and nanosecond since the TAI reference epoch.
+    fn init_from_tai_parts(centuries: i16, nanoseconds: u64) -> Self {
+        Self::from_tai_parts(centuries, nanoseconds)
+    }
+
+    #[cfg(feature = "python")]
     #[staticmethod]
     /// Initialize an Epoch from the provided TAI seconds since 1900 January 01 at midnight
     fn init_from_tai_seconds(seconds: f64) -> Self {
@@ -1317,6 +2285,13 @@ impl Epoch {
         Self::from_mjd_utc(days)
     }
 
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    /// Initialize an Epoch from given MJD in TT time scale
+    fn init_from_mjd_tt(days: f64) -> Self {
+        Self::from_mjd_tt(days)
+    }
+
     #[cfg(feature = "python")]
     #[staticmethod]
     /// Initialize an Epoch from given JDE in TAI time scale
@@ -1331,6 +2306,27 @@ impl Epoch {
         Self::from_jde_utc(days)
     }
 
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    /// Initialize an Epoch from given JDE in TT time scale
+    fn init_from_jde_tt(days: f64) -> Self {
+        Self::from_jde_tt(days)
+    }
+
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    /// Initialize an Epoch from given MJD in the provided time scale
+    fn init_from_mjd(days: f64, time_scale: TimeScale) -> Self {
+        Self::from_mjd(days, time_scale)
+    }
+
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    /// Initialize an Epoch from given JDE in the provided time scale
+    fn init_from_jde(days: f64, time_scale: TimeScale) -> Self {
+        Self::from_jde(days, time_scale)
+    }
+
     #[cfg(feature = "python")]
     #[staticmethod]
     /// Initialize an Epoch from the provided TT seconds (approximated to 32.184s delta from TAI)
@@ -1345,6 +2341,13 @@ impl Epoch {
         Self::from_tt_duration(duration)
     }
 
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    /// Initialize an Epoch from the provided Julian epoch, i.e. a decimal year of 365.25 days in Terrestrial Time (TT)
+    fn init_from_julian_year(year: f64) -> Self {
+        Self::from_julian_year(year)
+    }
+
     #[cfg(feature = "python")]
     #[staticmethod]
     /// Initialize an Epoch from the Ephemeris Time seconds past 2000 JAN 01 (J2000 reference)
@@ -1481,6 +2484,36 @@ impl Epoch {
         Self::from_unix_milliseconds(milliseconds)
     }
 
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    /// Initialize a list of Epochs from a one dimensional array of UNIX second timestamps since UTC midnight 1970 January 01.
+    ///
+    /// The array may be any object supporting the buffer protocol with float64 items, e.g. a numpy `float64` array or an `array.array("d")`,
+    /// so numpy is not a dependency. The conversion is done in Rust, without any per-element Python overhead.
+    fn init_from_unix_seconds_array(py: Python, seconds: &PyAny) -> PyResult<Vec<Self>> {
+        let buffer = PyBuffer::<f64>::get(seconds)?;
+        if buffer.dimensions() != 1 {
+            return Err(PyValueError::new_err(
+                "expected a one dimensional array of UNIX seconds",
+            ));
+        }
+        Ok(buffer
+            .to_vec(py)?
+            .into_iter()
+            .map(Self::from_unix_seconds)
+            .collect())
+    }
+
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    /// Returns the UNIX second timestamps of the provided list of Epochs, e.g. to build a numpy array with `numpy.array(...)`.
+    /// Sorted epochs benefit from the fast path of `Epoch::to_unix_seconds_batch`.
+    fn to_unix_seconds_array(epochs: Vec<Self>) -> Vec<f64> {
+        let mut seconds = vec![0.0; epochs.len()];
+        Self::to_unix_seconds_batch(&epochs, &mut seconds);
+        seconds
+    }
+
     #[cfg(feature = "python")]
     #[staticmethod]
     fn init_from_gregorian(
@@ -1660,6 +2693,13 @@ impl Epoch {
         self.to_duration_in_time_scale(self.time_scale)
     }
 
+    #[must_use]
+    /// Returns the value of this epoch in the provided time scale, i.e. the duration past the reference epoch of that time scale.
+    /// This is the canonical way to convert an epoch into another time scale, and is an alias of `to_duration_in_time_scale`.
+    pub fn duration_in(&self, time_scale: TimeScale) -> Duration {
+        self.to_duration_in_time_scale(time_scale)
+    }
+
     #[must_use]
     /// Returns this epoch with respect to the provided time scale.
     /// This is needed to correctly perform duration conversions in dynamical time scales (e.g. TDB).
@@ -1711,6 +2751,29 @@ impl Epoch {
         }
     }
 
+    /// Returns the duration past J2000 (2000 January 01 at noon) on the clock of the provided time scale.
+    ///
+    /// This is the same reference as the Gregorian representation in that time scale, so the J2000 instant differs by time scale:
+    /// + TT, ET and TDB: this is the usual J2000 of astrodynamics, e.g. `since_j2000(TimeScale::TT)` is `to_tt_since_j2k`, and
+    ///   `since_j2000(TimeScale::TDB)` is `to_tdb_duration`;
+    /// + TAI and UTC: noon on their own clock, 32.184 seconds and 64.184 seconds after J2000 TT respectively;
+    /// + GPST, GST and BDT: like their Gregorian representation, the same as TAI.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale, TimeUnits};
+    ///
+    /// let e = Epoch::from_gregorian_utc(2000, 1, 1, 12, 0, 0, 0);
+    /// assert_eq!(e.since_j2000(TimeScale::UTC), 0.seconds());
+    /// assert_eq!(e.since_j2000(TimeScale::TAI), 32.seconds());
+    /// assert_eq!(e.since_j2000(TimeScale::TT), e.to_tt_since_j2k());
+    /// assert_eq!(Epoch::from_since_j2000(e.since_j2000(TimeScale::TDB), TimeScale::TDB), e);
+    /// ```
+    #[must_use]
+    pub fn since_j2000(&self, time_scale: TimeScale) -> Duration {
+        self.to_duration_since_j1900_in_time_scale(time_scale) - J2000_TO_J1900_DURATION
+    }
+
     /// Makes a copy of self and sets the duration and time scale appropriately given the new duration
     #[must_use]
     pub fn set(&self, new_duration: Duration) -> Self {
@@ -1738,6 +2801,23 @@ impl Epoch {
         self.duration_since_j1900_tai
     }
 
+    #[must_use]
+    /// Returns the number of TAI nanoseconds since J1900 of this epoch, as a canonical key of the instant.
+    ///
+    /// This does not depend on the time scale of the epoch, just like the equality of epochs, so it is the natural key
+    /// to group or deduplicate epochs initialized in different time scales, e.g. in a `BTreeMap`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale};
+    ///
+    /// let e = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// assert_eq!(e.instant_key(), e.with_time_scale(TimeScale::TDB).instant_key());
+    /// ```
+    pub fn instant_key(&self) -> i128 {
+        self.duration_since_j1900_tai.total_nanoseconds()
+    }
+
     #[must_use]
     /// Returns the epoch as a floating point value in the provided unit
     pub fn to_tai(&self, unit: Unit) -> f64 {
@@ -1750,6 +2830,29 @@ impl Epoch {
         self.duration_since_j1900_tai.to_parts()
     }
 
+    #[must_use]
+    /// Returns the centuries and nanoseconds of this epoch in the provided time scale, i.e. the parts of `to_duration_in_time_scale`,
+    /// and `to_parts_in_scale(TimeScale::TAI)` is `to_tai_parts`.
+    ///
+    /// Rebuilding the epoch from these parts with `Epoch::from_duration` returns the same epoch, except for:
+    /// 1. a UTC leap second, whose parts in UTC are those of the second before it, which is therefore what is rebuilt;
+    /// 2. ET and TDB, which are computed with floating point operations, so the rebuilt epoch is only within a few nanoseconds.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Duration, Epoch, TimeScale};
+    ///
+    /// let e = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// let (centuries, nanoseconds) = e.to_parts_in_scale(TimeScale::GPST);
+    /// assert_eq!(
+    ///     Epoch::from_duration(Duration::from_parts(centuries, nanoseconds), TimeScale::GPST),
+    ///     e
+    /// );
+    /// ```
+    pub fn to_parts_in_scale(&self, time_scale: TimeScale) -> (i16, u64) {
+        self.to_duration_in_time_scale(time_scale).to_parts()
+    }
+
     #[must_use]
     /// Returns the number of days since J1900 in TAI
     pub fn to_tai_days(&self) -> f64 {
@@ -1764,9 +2867,10 @@ impl Epoch {
 
     #[must_use]
     /// Returns this time in a Duration past J1900 counted in UTC
+    ///
+    /// Before 1960, UTC was not defined and this returns the TAI duration: use `try_to_utc_duration` to reject such epochs.
     pub fn to_utc_duration(&self) -> Duration {
-        // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
-        self.duration_since_j1900_tai - self.leap_seconds(true).unwrap_or(0.0) * Unit::Second
+        self.to_utc_duration_with(LatestLeapSeconds::default())
     }
 
     #[must_use]
@@ -1818,6 +2922,14 @@ impl Epoch {
         self.to_mjd_utc(Unit::Second)
     }
 
+    #[must_use]
+    /// Returns the Modified Julian Date in the provided time scale and unit, e.g. `to_mjd(TimeScale::UTC, Unit::Day)` is `to_mjd_utc_days()`.
+    /// The MJD is referenced to the duration since J1900 in that time scale, as returned by `to_duration_since_j1900_in_time_scale`.
+    pub fn to_mjd(&self, time_scale: TimeScale, unit: Unit) -> f64 {
+        (self.to_duration_since_j1900_in_time_scale(time_scale) + Unit::Day * J1900_OFFSET)
+            .to_unit(unit)
+    }
+
     #[must_use]
     /// Returns the Julian days from epoch 01 Jan -4713, 12:00 (noon)
     /// as explained in "Fundamentals of astrodynamics and applications", Vallado et al.
@@ -1862,6 +2974,15 @@ impl Epoch {
         self.to_jde_utc_duration().to_seconds()
     }
 
+    #[must_use]
+    /// Returns the Julian Days in the provided time scale and unit, e.g. `to_jde(TimeScale::ET, Unit::Day)` is `to_jde_et_days()`.
+    /// The JDE is referenced to the duration since J1900 in that time scale, as returned by `to_duration_since_j1900_in_time_scale`.
+    pub fn to_jde(&self, time_scale: TimeScale, unit: Unit) -> f64 {
+        (self.to_duration_since_j1900_in_time_scale(time_scale)
+            + Unit::Day * (J1900_OFFSET + MJD_OFFSET))
+            .to_unit(unit)
+    }
+
     #[must_use]
     /// Returns seconds past TAI epoch in Terrestrial Time (TT) (previously called Terrestrial Dynamical Time (TDT))
     pub fn to_tt_seconds(&self) -> f64 {
@@ -1903,6 +3024,26 @@ impl Epoch {
         self.to_tt_duration() + Unit::Day * (J1900_OFFSET + MJD_OFFSET)
     }
 
+    #[must_use]
+    /// Returns the Julian epoch of this epoch, i.e. the decimal year of 365.25 days in Terrestrial Time (TT), as used by astrometric catalogs (e.g. J2015.5 for Gaia DR2).
+    ///
+    /// This is computed as `J = 2000.0 + (JD_TT - 2451545.0) / 365.25`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// let j2015_5 = Epoch::from_julian_year(2015.5);
+    /// assert_eq!(j2015_5.to_jde_tt_days(), 2_457_206.375);
+    /// assert_eq!(j2015_5.to_julian_year(), 2015.5);
+    /// ```
+    pub fn to_julian_year(&self) -> f64 {
+        let days_since_j2000 = (self.to_jde_tt_duration()
+            - Unit::Day * (J2000_OFFSET + MJD_OFFSET))
+            .to_unit(Unit::Day);
+        2000.0 + days_since_j2000 / DAYS_PER_YEAR
+    }
+
     #[must_use]
     /// Returns days past Modified Julian epoch in Terrestrial Time (TT) (previously called Terrestrial Dynamical Time (TDT))
     pub fn to_mjd_tt_days(&self) -> f64 {
@@ -2022,6 +3163,38 @@ impl Epoch {
         self.to_unix(Unit::Day)
     }
 
+    #[must_use]
+    /// Returns the number of seconds since the UNIX epoch in a leap smeared UTC, as used by some cloud providers to avoid the 23:59:60 second.
+    ///
+    /// Within the smear `window` around a leap second, the extra second is linearly spread over the whole window such that the smeared
+    /// clock never repeats a second. Outside of the window, this is identical to `to_unix_seconds`.
+    /// If `centered` is set, the window is centered on the end of the leap second (e.g. 24 hours noon to noon),
+    /// otherwise the window ends with the leap second (i.e. it trails behind the leap second).
+    pub fn to_smeared_unix_seconds(&self, window: Duration, centered: bool) -> f64 {
+        let smear_bounds = |leap: Self| {
+            if centered {
+                (leap - window / 2, leap + window / 2)
+            } else {
+                (leap - window, leap)
+            }
+        };
+
+        for leap in [self.next_leap_second(), self.previous_leap_second()]
+            .into_iter()
+            .flatten()
+        {
+            let (start, end) = smear_bounds(leap);
+            if start <= *self && *self < end {
+                // Prior to the leap second, the UNIX time is one second ahead of the UNIX time after the leap second.
+                let unix_after_leap = leap.to_unix_seconds() + (*self - leap).to_seconds();
+                let smeared_fraction = (*self - start).to_seconds() / (end - start).to_seconds();
+                return unix_after_leap + 1.0 - smeared_fraction;
+            }
+        }
+
+        self.to_unix_seconds()
+    }
+
     #[must_use]
     /// Returns the Ephemeris Time seconds past 2000 JAN 01 midnight, matches NASA/NAIF SPICE.
     pub fn to_et_seconds(&self) -> f64 {
@@ -2045,21 +3218,44 @@ impl Epoch {
     ///
     /// In order to match SPICE, the as_et_duration() function will manually get rid of that difference.
     pub fn to_et_duration(&self) -> Duration {
-        // Run a Newton Raphston to convert find the correct value of the
-        let mut seconds = (self.duration_since_j1900_tai - J2000_TO_J1900_DURATION).to_seconds();
-        for _ in 0..5 {
-            seconds -= -NAIF_K
-                * (NAIF_M0 + NAIF_M1 * seconds + NAIF_EB * (NAIF_M0 + NAIF_M1 * seconds).sin())
-                    .sin();
-        }
+        self.to_et_duration_with_error().0
+    }
 
-        // At this point, we have a good estimate of the number of seconds of this epoch.
-        // Reverse the algorithm:
-        let delta_et_tai =
-            Self::delta_et_tai(seconds + (TT_OFFSET_MS * Unit::Millisecond).to_seconds());
+    #[must_use]
+    /// Returns the duration between J2000 and the current epoch as per NAIF SPICE, like `to_et_duration`, along with an estimate of its error.
+    ///
+    /// The difference between ET and TAI depends on the ET seconds themselves, so it is computed iteratively: the error estimate
+    /// is the magnitude of the last correction of that iteration, which is under a nanosecond once it has converged.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::prelude::*;
+    ///
+    /// let e = Epoch::from_gregorian_utc_at_noon(2023, 3, 14);
+    /// let (et, error) = e.to_et_duration_with_error();
+    /// assert_eq!(et, e.to_et_duration());
+    /// assert!(error < 10.nanoseconds());
+    /// ```
+    pub fn to_et_duration_with_error(&self) -> (Duration, Duration) {
+        // ET = TAI + delta_et_tai(ET), so iterate on that fixed point until it converges to within a nanosecond.
+        let tai_seconds = (self.duration_since_j1900_tai - J2000_TO_J1900_DURATION).to_seconds();
+        let mut delta_et_tai =
+            Self::delta_et_tai(tai_seconds + (TT_OFFSET_MS * Unit::Millisecond).to_seconds());
+        let mut step = f64::INFINITY;
+        for _ in 0..MAX_ITERATIONS {
+            let next = Self::delta_et_tai(tai_seconds + delta_et_tai);
+            step = (next - delta_et_tai).abs();
+            delta_et_tai = next;
+            if step < CONVERGENCE_S {
+                break;
+            }
+        }
 
         // Match SPICE by changing the UTC definition.
-        self.duration_since_j1900_tai + delta_et_tai * Unit::Second - J2000_TO_J1900_DURATION
+        (
+            self.duration_since_j1900_tai + delta_et_tai * Unit::Second - J2000_TO_J1900_DURATION,
+            step * Unit::Second,
+        )
     }
 
     #[must_use]
@@ -2069,31 +3265,25 @@ impl Epoch {
     /// Given the embedded sine functions in the equation to compute the difference between TDB and TAI from the number of TDB seconds
     /// past J2000, one cannot solve the revert the operation analytically. Instead, we iterate until the value no longer changes.
     ///
-    /// 1. Assume that the TAI duration is in fact the TDB seconds from J2000.
-    /// 2. Offset to J2000 because `Epoch` stores everything in the J1900 but the TDB duration is in J2000.
-    /// 3. Compute the offset `g` due to the TDB computation with the current value of the TDB seconds (defined in step 1).
-    /// 4. Subtract that offset to the latest TDB seconds and store this as a new candidate for the true TDB seconds value.
-    /// 5. Compute the difference between this candidate and the previous one. If the difference is less than one nanosecond, stop iteration.
-    /// 6. Set the new candidate as the TDB seconds since J2000 and loop until step 5 breaks the loop, or we've done five iterations.
-    /// 7. At this stage, we have a good approximation of the TDB seconds since J2000.
-    /// 8. Reverse the algorithm given that approximation: compute the `g` offset, compute the difference between TDB and TAI, add the TT offset (32.184 s), and offset by the difference between J1900 and J2000.
+    /// 1. Compute the TAI seconds since J2000, because `Epoch` stores everything in the J1900 but the TDB duration is in J2000.
+    /// 2. Assume that the TDB seconds are the TT seconds, i.e. the TAI seconds plus the TT offset (32.184 s), and compute the offset `g` at that time.
+    /// 3. Compute the offset `g` again at the TDB seconds corrected by the previous offset.
+    /// 4. If the offset changed by less than one nanosecond, stop iterating. Otherwise, loop back to step 3, up to ten times.
+    /// 5. Add the offset `g` and the TT offset to the TAI duration, and offset by the difference between J1900 and J2000.
     pub fn to_tdb_duration(&self) -> Duration {
-        // Iterate to convert find the correct value of the
-        let mut seconds = (self.duration_since_j1900_tai - J2000_TO_J1900_DURATION).to_seconds();
-        let mut delta = 1e8; // Arbitrary large number, greater than first step of Newton Raphson.
-        for _ in 0..5 {
-            let next = seconds - Self::inner_g(seconds);
-            let new_delta = (next - seconds).abs();
-            if (new_delta - delta).abs() < 1e-9 {
+        // TDB = TAI + TT offset + g(TDB), so iterate on that fixed point until it converges to within a nanosecond.
+        let tt_seconds = (self.duration_since_j1900_tai - J2000_TO_J1900_DURATION).to_seconds()
+            + (TT_OFFSET_MS * Unit::Millisecond).to_seconds();
+        let mut gamma = Self::inner_g(tt_seconds);
+        for _ in 0..MAX_ITERATIONS {
+            let next = Self::inner_g(tt_seconds + gamma);
+            let step = (next - gamma).abs();
+            gamma = next;
+            if step < CONVERGENCE_S {
                 break;
             }
-            seconds = next; // Loop
-            delta = new_delta;
         }
 
-        // At this point, we have a good estimate of the number of seconds of this epoch.
-        // Reverse the algorithm:
-        let gamma = Self::inner_g(seconds + (TT_OFFSET_MS * Unit::Millisecond).to_seconds());
         let delta_tdb_tai = gamma * Unit::Second + TT_OFFSET_MS * Unit::Millisecond;
 
         self.duration_since_j1900_tai + delta_tdb_tai - J2000_TO_J1900_DURATION
@@ -2187,8 +3377,19 @@ impl Epoch {
     /// #[cfg(feature = "std")]
     /// assert_eq!("2017-01-14T00:31:55 UTC", dt.as_gregorian_utc_str().to_owned());
     /// ```
+    ///
+    /// If this epoch falls within an inserted leap second, the returned second is 60.
+    /// Only the IERS leap seconds are accounted for, so UTC jumps by ten seconds at the start of 1972: the first nine
+    /// seconds of this step are all returned as 1971-12-31T23:59:60.000000000, and only its last second has subseconds.
     pub fn to_gregorian_utc(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
-        Self::compute_gregorian(self.to_utc_duration())
+        let (utc_duration, is_leap_second) =
+            self.utc_duration_and_leap_second_with(&LatestLeapSeconds::default());
+        let (y, mm, dd, hh, min, s, nanos) = Self::compute_gregorian(utc_duration);
+        if is_leap_second {
+            (y, mm, dd, hh, min, s + 1, nanos)
+        } else {
+            (y, mm, dd, hh, min, s, nanos)
+        }
     }
 
     #[must_use]
@@ -2211,6 +3412,41 @@ impl Epoch {
         Self::compute_gregorian(self.to_tai_duration())
     }
 
+    #[must_use]
+    /// Converts the Epoch to the Gregorian date and time of the GPS wall clock as (year, month, day, hour, minute, second, nanoseconds).
+    ///
+    /// This clock started at midnight UTC on 1980 January 06 and does not count leap seconds, so it is ahead of UTC by the leap
+    /// seconds since then (18 seconds as of 2017) and behind TAI by 19 seconds. This is the time found in GNSS logs.
+    /// Note that `to_gregorian(TimeScale::GPST)`, like the `Display` of an epoch in GPST, counts the GPST duration from the reference epoch in TAI instead.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    /// let dt = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// assert_eq!(dt.to_gregorian_gpst(), (2023, 1, 1, 0, 0, 18, 0));
+    /// ```
+    pub fn to_gregorian_gpst(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        Self::compute_gregorian(self.to_gpst_duration() + Unit::Day * days_since_j1900(1980, 1, 6))
+    }
+
+    #[must_use]
+    /// Converts the Epoch to the Gregorian date and time of the Galileo wall clock as (year, month, day, hour, minute, second, nanoseconds).
+    ///
+    /// Galileo System Time started at midnight of its own clock on 1999 August 22, which was 13 seconds before midnight UTC,
+    /// so that it is aligned with GPS Time: this is the same as `to_gregorian_gpst`, cf. its documentation.
+    pub fn to_gregorian_gst(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        Self::compute_gregorian(self.to_gst_duration() + Unit::Day * days_since_j1900(1999, 8, 22))
+    }
+
+    #[must_use]
+    /// Converts the Epoch to the Gregorian date and time of the BeiDou wall clock as (year, month, day, hour, minute, second, nanoseconds).
+    ///
+    /// This clock started at midnight UTC on 2006 January 01 and does not count leap seconds, so it is ahead of UTC by the leap
+    /// seconds since then (4 seconds as of 2017) and behind TAI by 33 seconds. Refer to `to_gregorian_gpst` for the difference with `to_gregorian`.
+    pub fn to_gregorian_bdt(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        Self::compute_gregorian(self.to_bdt_duration() + Unit::Day * days_since_j1900(2006, 1, 1))
+    }
+
     #[cfg(feature = "ut1")]
     #[must_use]
     /// Returns this time in a Duration past J1900 counted in UT1
@@ -2231,34 +3467,143 @@ impl Epoch {
     }
 
     #[must_use]
-    /// Floors this epoch to the closest provided duration
+    /// Returns the Greenwich Mean Sidereal Time (GMST) of this epoch as an angle in radians, in [0, 2π).
+    ///
+    /// This uses the IAU 1982 GMST model (as expressed in Meeus, _Astronomical Algorithms_, eq. 12.4), which is a function of UT1.
+    /// UT1 requires Earth orientation data, so this function uses UTC in lieu of UT1. As |UT1 - UTC| is kept under 0.9 seconds
+    /// by the IERS, the error of this approximation is at most 13.5 arcseconds. Use `gmst_ut1` if UT1 data is available.
     ///
     /// # Example
     /// ```
-    /// use hifitime::{Epoch, TimeUnits};
-    ///
-    /// let e = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43);
-    /// assert_eq!(
-    ///     e.floor(1.hours()),
-    ///     Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 0, 0)
-    /// );
+    /// use hifitime::Epoch;
     ///
-    /// let e = Epoch::from_gregorian_tai(2022, 10, 3, 17, 44, 29, 898032665);
-    /// assert_eq!(
-    ///     e.floor(3.minutes()),
-    ///     Epoch::from_gregorian_tai_hms(2022, 10, 3, 17, 42, 0)
-    /// );
+    /// // Vallado, Fundamentals of Astrodynamics and Applications, 4th ed., example 3-5
+    /// let e = Epoch::from_gregorian_utc_hms(1992, 8, 20, 12, 14, 0);
+    /// assert!((e.gmst().to_degrees() - 152.578_787_810).abs() < 1.0 / 3600.0);
     /// ```
-    pub fn floor(&self, duration: Duration) -> Self {
-        Self::from_duration(self.to_duration().floor(duration), self.time_scale)
+    pub fn gmst(&self) -> f64 {
+        gmst_from_duration_j2000(self.to_utc_duration() - J2000_TO_J1900_DURATION)
     }
 
+    #[cfg(feature = "ut1")]
     #[must_use]
-    /// Ceils this epoch to the closest provided duration in the TAI time scale
-    ///
-    /// # Example
-    /// ```
-    /// use hifitime::{Epoch, TimeUnits};
+    /// Returns the Greenwich Mean Sidereal Time (GMST) of this epoch as an angle in radians, in [0, 2π), using the UT1 data of the provider.
+    ///
+    /// Refer to `gmst` for the model used.
+    pub fn gmst_ut1(&self, provider: Ut1Provider) -> f64 {
+        gmst_from_duration_j2000(self.to_ut1_duration(provider) - J2000_TO_J1900_DURATION)
+    }
+
+    #[must_use]
+    /// Returns the equation of the equinoxes of this epoch in radians, i.e. the difference between the apparent and the mean sidereal times.
+    ///
+    /// This uses a truncated IAU 1980 nutation series (the four largest terms, as in Meeus, _Astronomical Algorithms_, chapter 22)
+    /// and the IAU 1994 complementary terms. The truncation is accurate to about 0.5 arcseconds.
+    pub fn equation_of_equinoxes(&self) -> f64 {
+        let centuries = self.to_tt_centuries_j2k();
+        let (nutation_longitude, obliquity) = nutation_iau1980_truncated(centuries);
+        // The complementary terms only depend on the ascending node of the Moon and are in arcseconds
+        let node = moon_ascending_node_deg(centuries).to_radians();
+        let complementary = 0.002_64 * node.sin() + 0.000_063 * (2.0 * node).sin();
+        nutation_longitude * obliquity.cos() + (complementary / 3600.0).to_radians()
+    }
+
+    #[must_use]
+    /// Returns the Greenwich Apparent Sidereal Time (GAST) of this epoch as an angle in radians, in [0, 2π).
+    ///
+    /// This is the GMST corrected by the equation of the equinoxes: refer to `gmst` and `equation_of_equinoxes` for the models and their accuracy.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// // Meeus, Astronomical Algorithms, example 12.b: 8h34m56.853s
+    /// let e = Epoch::from_gregorian_utc_hms(1987, 4, 10, 19, 21, 0);
+    /// let expected = (8.0 + 34.0 / 60.0 + 56.853 / 3600.0) * 15.0;
+    /// assert!((e.gast().to_degrees() - expected).abs() < 1.0 / 3600.0);
+    /// ```
+    pub fn gast(&self) -> f64 {
+        rem_euclid_f64(self.gmst() + self.equation_of_equinoxes(), TAU)
+    }
+
+    #[must_use]
+    /// Returns the equation of time of this epoch, i.e. the apparent solar time minus the mean solar time.
+    ///
+    /// This is positive when a sundial is ahead of the clock, and varies from about -14 minutes in mid February
+    /// to about +16 minutes in early November.
+    ///
+    /// This uses the low precision series of W. M. Smart (as expressed in Meeus, _Astronomical Algorithms_, eq. 28.3)
+    /// with the mean elements of the orbit of the Earth in TT centuries past J2000. Nutation and aberration are neglected,
+    /// so this is accurate to about 30 seconds.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale};
+    ///
+    /// // Meeus, Astronomical Algorithms, example 28.b: 13m42.7s
+    /// let e = Epoch::from_gregorian_at_midnight(1992, 10, 13, TimeScale::TT);
+    /// assert!((e.equation_of_time().to_seconds() - (13.0 * 60.0 + 42.7)).abs() < 0.5);
+    /// ```
+    pub fn equation_of_time(&self) -> Duration {
+        let centuries = self.to_tt_centuries_j2k();
+        // Geometric mean longitude and mean anomaly of the Sun, and eccentricity of the orbit of the Earth
+        let sun_longitude =
+            (280.466_46 + 36_000.769_83 * centuries + 0.000_303_2 * centuries.powi(2)).to_radians();
+        let sun_anomaly =
+            (357.529_11 + 35_999.050_29 * centuries - 0.000_153_7 * centuries.powi(2)).to_radians();
+        let eccentricity =
+            0.016_708_634 - 0.000_042_037 * centuries - 0.000_000_126_7 * centuries.powi(2);
+        let mean_obliquity = ((84_381.448 - 46.815_0 * centuries) / 3600.0).to_radians();
+        let y = (mean_obliquity / 2.0).tan().powi(2);
+
+        // In radians
+        let equation = y * (2.0 * sun_longitude).sin() - 2.0 * eccentricity * sun_anomaly.sin()
+            + 4.0 * eccentricity * y * sun_anomaly.sin() * (2.0 * sun_longitude).cos()
+            - 0.5 * y.powi(2) * (4.0 * sun_longitude).sin()
+            - 1.25 * eccentricity.powi(2) * (2.0 * sun_anomaly).sin();
+
+        // The Earth rotates by a full turn per day of solar time
+        equation / TAU * Unit::Day
+    }
+
+    #[cfg(feature = "ut1")]
+    #[must_use]
+    /// Returns the Greenwich Apparent Sidereal Time (GAST) of this epoch as an angle in radians, in [0, 2π), using the UT1 data of the provider.
+    ///
+    /// Refer to `gast` for the model used.
+    pub fn gast_ut1(&self, provider: Ut1Provider) -> f64 {
+        rem_euclid_f64(self.gmst_ut1(provider) + self.equation_of_equinoxes(), TAU)
+    }
+
+    #[must_use]
+    /// Floors this epoch to the closest provided duration
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let e = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43);
+    /// assert_eq!(
+    ///     e.floor(1.hours()),
+    ///     Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 0, 0)
+    /// );
+    ///
+    /// let e = Epoch::from_gregorian_tai(2022, 10, 3, 17, 44, 29, 898032665);
+    /// assert_eq!(
+    ///     e.floor(3.minutes()),
+    ///     Epoch::from_gregorian_tai_hms(2022, 10, 3, 17, 42, 0)
+    /// );
+    /// ```
+    pub fn floor(&self, duration: Duration) -> Self {
+        Self::from_duration(self.to_duration().floor(duration), self.time_scale)
+    }
+
+    #[must_use]
+    /// Ceils this epoch to the closest provided duration in the TAI time scale
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
     ///
     /// let e = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43);
     /// assert_eq!(
@@ -2295,8 +3640,147 @@ impl Epoch {
     }
 
     #[must_use]
-    /// Copies this epoch and sets it to the new time scale provided.
-    pub fn in_time_scale(&self, new_time_scale: TimeScale) -> Self {
+    /// Rounds this epoch to the closest multiple of the provided duration like `round`, but never lands inside a UTC leap second.
+    ///
+    /// In UTC, the rounding is computed on the civil time, which does not count the leap seconds, so the result is always a valid
+    /// civil instant. An epoch within a leap second (e.g. `23:59:60.4`) is rounded as if it were the following midnight, which is
+    /// the adjacent valid instant, less than a second later. In the other time scales, this is the same as `round`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let e = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 400_000_000);
+    /// assert_eq!(
+    ///     e.round_to_nearest_leap_safe(1.seconds()),
+    ///     Epoch::from_gregorian_utc_at_midnight(2017, 1, 1)
+    /// );
+    /// ```
+    pub fn round_to_nearest_leap_safe(&self, duration: Duration) -> Self {
+        if self.time_scale == TimeScale::UTC {
+            let (y, mm, dd, _, _, s, _) = self.to_gregorian_utc();
+            if s == 60 {
+                // Adding a day in UTC skips the leap second, so this is the following midnight.
+                let next_midnight = Self::from_gregorian_utc_at_midnight(y, mm, dd) + Unit::Day;
+                return next_midnight.round(duration);
+            }
+        }
+        self.round(duration)
+    }
+
+    #[must_use]
+    /// Floors this epoch to the closest multiple of the provided unit in the time scale this epoch was initialized in, i.e. `self.floor(unit * 1)`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, Unit};
+    ///
+    /// let e = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43);
+    /// assert_eq!(
+    ///     e.floor_to(Unit::Hour),
+    ///     Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 0, 0)
+    /// );
+    /// ```
+    pub fn floor_to(&self, unit: Unit) -> Self {
+        self.floor(unit * 1)
+    }
+
+    #[must_use]
+    /// Ceils this epoch to the closest multiple of the provided unit in the time scale this epoch was initialized in, i.e. `self.ceil(unit * 1)`.
+    pub fn ceil_to(&self, unit: Unit) -> Self {
+        self.ceil(unit * 1)
+    }
+
+    #[must_use]
+    /// Rounds this epoch to the closest multiple of the provided unit in the time scale this epoch was initialized in, i.e. `self.round(unit * 1)`.
+    pub fn round_to(&self, unit: Unit) -> Self {
+        self.round(unit * 1)
+    }
+
+    #[must_use]
+    /// Rounds this epoch to a multiple of the provided duration with the provided rounding mode, in the time scale this epoch was initialized in.
+    ///
+    /// The multiples are counted from the reference epoch of that time scale (J1900 for TAI, TT and UTC, J2000 for ET and TDB,
+    /// and the start of the constellation time for GPST, GST and BDT). Hence, `RoundMode::TowardZero` and `RoundMode::AwayFromZero`
+    /// round toward and away from that reference epoch, not toward the past and the future: before the reference epoch,
+    /// `TowardZero` rounds to the later multiple.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, RoundMode, TimeUnits};
+    ///
+    /// let e = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 30, 0);
+    /// assert_eq!(
+    ///     e.round_with(1.hours(), RoundMode::HalfDown),
+    ///     Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 0, 0)
+    /// );
+    /// assert_eq!(
+    ///     e.round_with(1.hours(), RoundMode::HalfUp),
+    ///     Epoch::from_gregorian_tai_hms(2022, 5, 20, 18, 0, 0)
+    /// );
+    ///
+    /// // Before J1900, the reference epoch of TAI, rounding toward zero moves the epoch forward
+    /// let e = Epoch::from_gregorian_tai_hms(1899, 12, 31, 12, 30, 0);
+    /// assert_eq!(
+    ///     e.round_with(1.hours(), RoundMode::TowardZero),
+    ///     Epoch::from_gregorian_tai_hms(1899, 12, 31, 13, 0, 0)
+    /// );
+    /// ```
+    pub fn round_with(&self, duration: Duration, mode: RoundMode) -> Self {
+        Self::from_duration(
+            self.to_duration().round_with(duration, mode),
+            self.time_scale,
+        )
+    }
+
+    #[must_use]
+    /// Returns the epoch halfway between this epoch and the other one, in the time scale of this epoch.
+    ///
+    /// The computation is done on the TAI durations, so the midpoint is exact to the nanosecond when the
+    /// span between both epochs is an even number of nanoseconds. Otherwise, it is rounded toward `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let start = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 0, 0);
+    /// let end = Epoch::from_gregorian_tai_hms(2022, 5, 20, 18, 0, 0);
+    /// assert_eq!(start.midpoint(end), start + 30.minutes());
+    /// assert_eq!(end.midpoint(start), start + 30.minutes());
+    /// ```
+    pub fn midpoint(&self, other: Self) -> Self {
+        Self::from_tai_duration(self.duration_since_j1900_tai + (other - *self) / 2)
+            .with_time_scale(self.time_scale)
+    }
+
+    #[must_use]
+    /// Linearly interpolates between this epoch (`t = 0.0`) and the other epoch (`t = 1.0`), in the time scale of this epoch.
+    ///
+    /// Like `midpoint`, this is computed on the TAI durations to preserve the nanosecond precision of both epochs.
+    /// Values of `t` outside of [0, 1] extrapolate beyond either epoch.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let start = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 0, 0);
+    /// let end = Epoch::from_gregorian_tai_hms(2022, 5, 20, 18, 0, 0);
+    /// assert_eq!(start.lerp(end, 0.0), start);
+    /// assert_eq!(start.lerp(end, 0.25), start + 15.minutes());
+    /// assert_eq!(start.lerp(end, 1.0), end);
+    /// ```
+    pub fn lerp(&self, other: Self, t: f64) -> Self {
+        Self::from_tai_duration(self.duration_since_j1900_tai + (other - *self) * t)
+            .with_time_scale(self.time_scale)
+    }
+
+    #[must_use]
+    /// Copies this epoch and relabels it with the new time scale provided.
+    ///
+    /// This does **not** change the instant represented by this epoch: the stored TAI duration is invariant under relabeling,
+    /// so the copy is equal to `self`. Only the time scale used by `to_duration`, `set`, and arithmetic operations changes.
+    /// To get the value of this instant in another time scale, use `duration_in`.
+    pub fn with_time_scale(&self, new_time_scale: TimeScale) -> Self {
         let mut me = *self;
         me.time_scale = new_time_scale;
         me
@@ -2317,6 +3801,59 @@ impl Epoch {
         (weeks as u32, nanoseconds as u64)
     }
 
+    #[must_use]
+    /// Returns the duration elapsed since the start of the current week of the provided time scale, i.e. the time of week (TOW) of GNSS.
+    ///
+    /// The weeks are counted from the reference epoch of that time scale, cf. `TimeScale::ref_epoch`: for example, GPS weeks
+    /// start on Sunday at midnight GPST. Before the reference epoch, this is still the duration since the start of the week.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::prelude::*;
+    ///
+    /// // 2023-01-01 was the first day of GPS week 2243, and GPST is ahead of UTC by 18 seconds.
+    /// let e = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// assert_eq!(e.week_number(TimeScale::GPST), 2243);
+    /// assert_eq!(e.time_of_week(TimeScale::GPST), 18.seconds());
+    /// ```
+    pub fn time_of_week(&self, time_scale: TimeScale) -> Duration {
+        let nanos = self
+            .to_duration_in_time_scale(time_scale)
+            .total_nanoseconds();
+        let nanos_per_week = i128::from(NANOSECONDS_PER_DAY) * Weekday::DAYS_PER_WEEK_I128;
+        Duration::from_total_nanoseconds(nanos.rem_euclid(nanos_per_week))
+    }
+
+    #[must_use]
+    /// Returns the number of whole weeks elapsed since the reference epoch of the provided time scale, cf. `time_of_week`.
+    /// This is zero before the reference epoch, and the week number is not rolled over, e.g. at 1024 weeks for the legacy GPS messages.
+    pub fn week_number(&self, time_scale: TimeScale) -> u32 {
+        let nanos_per_week = i128::from(NANOSECONDS_PER_DAY) * Weekday::DAYS_PER_WEEK_I128;
+        let weeks = self
+            .to_duration_in_time_scale(time_scale)
+            .total_nanoseconds()
+            .div_euclid(nanos_per_week);
+        weeks.clamp(0, i128::from(u32::MAX)) as u32
+    }
+
+    #[must_use]
+    /// Returns the number of rollovers of the 10 bit GPS week number since the GPST reference epoch, i.e. the number of
+    /// whole cycles of 1024 weeks, cf. `GPST_WEEK_ROLLOVER_1999` and the following rollovers.
+    ///
+    /// Receivers which report the week number modulo 1024 may reconstruct the full week number as `1024 * count + week`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::prelude::*;
+    ///
+    /// let e = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// assert_eq!(e.gps_rollover_count(), 2);
+    /// assert_eq!(1024 * e.gps_rollover_count() + 195, e.week_number(TimeScale::GPST));
+    /// ```
+    pub fn gps_rollover_count(&self) -> u32 {
+        self.week_number(TimeScale::GPST) / 1024
+    }
+
     #[must_use]
     /// Returns the weekday in provided time scale **ASSUMING** that the reference epoch of that time scale is a Monday.
     /// You _probably_ do not want to use this. You probably either want `weekday()` or `weekday_utc()`.
@@ -2376,95 +3913,343 @@ impl Epoch {
     }
 
     #[must_use]
-    pub fn next_weekday_at_noon(&self, weekday: Weekday) -> Self {
-        self.next(weekday).with_hms_strict(12, 0, 0)
+    pub fn next_weekday_at_noon(&self, weekday: Weekday) -> Self {
+        self.next(weekday).with_hms_strict(12, 0, 0)
+    }
+
+    #[must_use]
+    /// Returns the next weekday.
+    ///
+    /// ```
+    /// use hifitime::prelude::*;
+    ///
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(1988, 1, 2);
+    /// assert_eq!(epoch.previous(Weekday::Friday), Epoch::from_gregorian_utc_at_midnight(1988, 1, 1));
+    /// assert_eq!(epoch.previous(Weekday::Thursday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 31));
+    /// assert_eq!(epoch.previous(Weekday::Wednesday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 30));
+    /// assert_eq!(epoch.previous(Weekday::Tuesday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 29));
+    /// assert_eq!(epoch.previous(Weekday::Monday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 28));
+    /// assert_eq!(epoch.previous(Weekday::Sunday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 27));
+    /// assert_eq!(epoch.previous(Weekday::Saturday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 26));
+    /// ```
+    pub fn previous(&self, weekday: Weekday) -> Self {
+        let delta_days = weekday - self.weekday();
+        if delta_days == Duration::ZERO {
+            *self - 7 * Unit::Day
+        } else {
+            *self - delta_days
+        }
+    }
+
+    #[must_use]
+    pub fn previous_weekday_at_midnight(&self, weekday: Weekday) -> Self {
+        self.previous(weekday).with_hms_strict(0, 0, 0)
+    }
+
+    #[must_use]
+    pub fn previous_weekday_at_noon(&self, weekday: Weekday) -> Self {
+        self.previous(weekday).with_hms_strict(12, 0, 0)
+    }
+
+    #[must_use]
+    /// Returns the duration since the start of the year
+    pub fn duration_in_year(&self) -> Duration {
+        // Both the year and the start of the year are counted from J1900 in this time scale, like its Gregorian representation.
+        let year = self.year();
+        self.to_duration_since_j1900() - Unit::Day * days_since_j1900(year, 1, 1)
+    }
+
+    #[must_use]
+    /// Returns the number of days since the start of the year.
+    pub fn day_of_year(&self) -> f64 {
+        self.duration_in_year().to_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns the year and the days in the year so far (days of year).
+    pub fn year_days_of_year(&self) -> (i32, f64) {
+        (self.year(), self.day_of_year())
+    }
+
+    #[must_use]
+    /// Returns the quarter of the year of this epoch in the time scale it was initialized in, from 1 (January to March) to 4 (October to December).
+    pub fn quarter(&self) -> u8 {
+        (self.month() - 1) / 3 + 1
+    }
+
+    #[must_use]
+    /// Returns the civil year of this epoch as a decimal number, computed as `year + day_of_year / days_in_year`, where the
+    /// number of days in the year is 366 in leap years and 365 otherwise.
+    ///
+    /// Unlike `to_julian_year`, this is a fraction of the Gregorian calendar year, so the first of January at midnight is always a whole number.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// // Midway through 2021 (a common year), and through 2020 (a leap year)
+    /// assert_eq!(Epoch::from_gregorian_utc_hms(2021, 7, 2, 12, 0, 0).fractional_year(), 2021.5);
+    /// assert_eq!(Epoch::from_gregorian_utc_at_midnight(2020, 7, 2).fractional_year(), 2020.5);
+    /// ```
+    pub fn fractional_year(&self) -> f64 {
+        let (year, days) = self.year_days_of_year();
+        let days_in_year = if is_leap_year(year) { 366.0 } else { 365.0 };
+        f64::from(year) + days / days_in_year
+    }
+
+    #[must_use]
+    /// Returns the number of whole calendar months from this epoch until the other epoch, negative if the other epoch is before this one.
+    ///
+    /// Both epochs are compared by their Gregorian date and time in the time scale this epoch was initialized in. A month is complete
+    /// once the same day of the month and time of day is reached, so there is no whole month from January 31 to February 28 (or 29).
+    /// When that day does not exist in the final month, the month is only complete on the first of the following month: the
+    /// anniversary of February 29 in a common year is March 1.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// let jan31 = Epoch::from_gregorian_utc_at_midnight(2023, 1, 31);
+    /// assert_eq!(jan31.whole_months_until(Epoch::from_gregorian_utc_at_midnight(2023, 2, 28)), 0);
+    /// assert_eq!(jan31.whole_months_until(Epoch::from_gregorian_utc_at_midnight(2023, 3, 1)), 1);
+    /// assert_eq!(jan31.whole_months_until(Epoch::from_gregorian_utc_at_midnight(2022, 12, 31)), -1);
+    /// ```
+    pub fn whole_months_until(&self, other: Self) -> i32 {
+        if other < *self {
+            return -other
+                .with_time_scale(self.time_scale)
+                .whole_months_until(*self);
+        }
+
+        let (y0, m0, d0, hh0, min0, s0, ns0) = self.compute_gregorian_in(self.time_scale);
+        let (y1, m1, d1, hh1, min1, s1, ns1) = other.compute_gregorian_in(self.time_scale);
+
+        let months = (y1 - y0) * 12 + i32::from(m1) - i32::from(m0);
+        if (d1, hh1, min1, s1, ns1) < (d0, hh0, min0, s0, ns0) {
+            // The last month is not complete yet.
+            months - 1
+        } else {
+            months
+        }
+    }
+
+    #[must_use]
+    /// Returns the number of whole calendar years from this epoch until the other epoch, negative if the other epoch is before this one.
+    ///
+    /// This follows the same rules as `whole_months_until`: in particular, the anniversary of February 29 in a common year is March 1.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// let leap_day = Epoch::from_gregorian_utc_at_midnight(2020, 2, 29);
+    /// assert_eq!(leap_day.whole_years_until(Epoch::from_gregorian_utc_at_midnight(2021, 2, 28)), 0);
+    /// assert_eq!(leap_day.whole_years_until(Epoch::from_gregorian_utc_at_midnight(2021, 3, 1)), 1);
+    /// assert_eq!(leap_day.whole_years_until(Epoch::from_gregorian_utc_at_midnight(2024, 2, 29)), 4);
+    /// ```
+    pub fn whole_years_until(&self, other: Self) -> i32 {
+        self.whole_months_until(other) / 12
+    }
+
+    #[must_use]
+    /// Returns the number of seconds elapsed since midnight in the time scale this epoch was initialized in.
+    /// This is within 0 (inclusive) and 86400 (exclusive) seconds, except during an inserted leap second in UTC, where it may reach up to 86401 seconds.
+    pub fn seconds_of_day(&self) -> f64 {
+        let (_, _, _, hh, min, s, nanos) = self.compute_gregorian_in(self.time_scale);
+        f64::from(u32::from(hh) * 3600 + u32::from(min) * 60 + u32::from(s))
+            + f64::from(nanos) * 1e-9
+    }
+
+    #[must_use]
+    /// Returns the length of the civil day containing this epoch, in the time scale this epoch was initialized in.
+    ///
+    /// This is always one day, except in UTC where the leap seconds make the day last 86401 seconds (or 86399 seconds for a
+    /// negative leap second). Divide `seconds_of_day` by this duration to compute the fraction of a UTC day.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::prelude::*;
+    ///
+    /// let e = Epoch::from_gregorian_utc_at_noon(2016, 12, 31);
+    /// assert_eq!(e.duration_of_day(), 86_401.seconds());
+    /// assert_eq!(e.with_time_scale(TimeScale::TAI).duration_of_day(), 1.days());
+    /// ```
+    pub fn duration_of_day(&self) -> Duration {
+        if self.time_scale == TimeScale::UTC {
+            // Adding a day in UTC skips the leap seconds, whereas the difference of epochs is counted in TAI.
+            let midnight = self.at_midnight();
+            (midnight + Unit::Day) - midnight
+        } else {
+            Unit::Day * 1
+        }
+    }
+
+    #[must_use]
+    /// Returns a copy of self set to midnight of the same date, in the time scale this epoch was initialized in.
+    pub fn at_midnight(&self) -> Self {
+        let (y, mm, dd, _, _, _, _) = self.compute_gregorian_in(self.time_scale);
+        Self::from_gregorian(y, mm, dd, 0, 0, 0, 0, self.time_scale)
+    }
+
+    #[must_use]
+    /// Returns this epoch truncated to the start of its day in the time scale it was initialized in, i.e. `at_midnight`.
+    ///
+    /// The truncation is computed on the Gregorian representation, so a UTC day ending with a leap second,
+    /// which lasts 86401 seconds, is truncated to its own midnight.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale, TimeUnits};
+    ///
+    /// // Adding in TAI reaches the leap second at the end of 2016, which UTC arithmetic would skip.
+    /// let e = Epoch::from_tai_duration(Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 59).to_tai_duration() + 1.seconds())
+    ///     .with_time_scale(TimeScale::UTC);
+    /// assert_eq!(e.to_gregorian_utc(), (2016, 12, 31, 23, 59, 60, 0));
+    /// assert_eq!(e.truncate_to_day(), Epoch::from_gregorian_utc_at_midnight(2016, 12, 31));
+    /// ```
+    pub fn truncate_to_day(&self) -> Self {
+        self.at_midnight()
+    }
+
+    #[must_use]
+    /// Returns this epoch truncated to the start of its hour in the time scale it was initialized in.
+    /// As with `truncate_to_day`, an inserted UTC leap second belongs to the last hour of its day.
+    pub fn truncate_to_hour(&self) -> Self {
+        let (y, mm, dd, hh, _, _, _) = self.compute_gregorian_in(self.time_scale);
+        Self::from_gregorian(y, mm, dd, hh, 0, 0, 0, self.time_scale)
+    }
+
+    #[must_use]
+    /// Returns this epoch truncated to the start of its minute in the time scale it was initialized in.
+    /// As with `truncate_to_day`, an inserted UTC leap second belongs to the last minute of its day.
+    pub fn truncate_to_minute(&self) -> Self {
+        let (y, mm, dd, hh, min, _, _) = self.compute_gregorian_in(self.time_scale);
+        Self::from_gregorian(y, mm, dd, hh, min, 0, 0, self.time_scale)
+    }
+
+    #[must_use]
+    /// Returns whether this epoch and the other one fall within the same second of the Gregorian representation in the provided time scale,
+    /// i.e. whether they only differ by their nanoseconds. An inserted UTC leap second (23:59:60) is its own second.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale, TimeUnits};
+    ///
+    /// let e = Epoch::from_gregorian_utc(2023, 3, 14, 15, 9, 26, 100_000_000);
+    /// assert!(e.same_second_as(e + 500.milliseconds(), TimeScale::UTC));
+    /// assert!(!e.same_second_as(e + 900.milliseconds(), TimeScale::UTC));
+    /// ```
+    pub fn same_second_as(&self, other: Self, time_scale: TimeScale) -> bool {
+        let (y, mm, dd, hh, min, s, _) = self.compute_gregorian_in(time_scale);
+        let (o_y, o_mm, o_dd, o_hh, o_min, o_s, _) = other.compute_gregorian_in(time_scale);
+        (y, mm, dd, hh, min, s) == (o_y, o_mm, o_dd, o_hh, o_min, o_s)
+    }
+
+    #[must_use]
+    /// Returns whether this epoch and the other one fall on the same day of the Gregorian representation in the provided time scale.
+    /// An inserted UTC leap second (23:59:60) belongs to the day it ends.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale, TimeUnits};
+    ///
+    /// let e = Epoch::from_gregorian_utc_hms(2023, 3, 14, 23, 0, 0);
+    /// let later = e + 59.minutes() + 30.seconds();
+    /// assert!(e.same_day_as(later, TimeScale::UTC));
+    /// assert!(!e.same_day_as(e + 1.hours(), TimeScale::UTC));
+    /// // This is still the 14th in UTC, but already the 15th in TAI.
+    /// assert!(!e.same_day_as(later, TimeScale::TAI));
+    /// ```
+    pub fn same_day_as(&self, other: Self, time_scale: TimeScale) -> bool {
+        let (y, mm, dd, _, _, _, _) = self.compute_gregorian_in(time_scale);
+        let (o_y, o_mm, o_dd, _, _, _, _) = other.compute_gregorian_in(time_scale);
+        (y, mm, dd) == (o_y, o_mm, o_dd)
+    }
+
+    #[must_use]
+    /// Returns whether this epoch is strictly before the other one, i.e. `self < other`, whatever their time scales.
+    pub fn is_before(&self, other: Self) -> bool {
+        *self < other
+    }
+
+    #[must_use]
+    /// Returns whether this epoch is strictly after the other one, i.e. `self > other`, whatever their time scales.
+    pub fn is_after(&self, other: Self) -> bool {
+        *self > other
     }
 
     #[must_use]
-    /// Returns the next weekday.
+    /// Returns whether this epoch is within the half-open interval from the start (inclusive) to the end (exclusive),
+    /// i.e. `start <= self < end` like the range `start..end`. This is always false if the end is not after the start.
     ///
+    /// # Example
     /// ```
-    /// use hifitime::prelude::*;
+    /// use hifitime::{Epoch, TimeUnits};
     ///
-    /// let epoch = Epoch::from_gregorian_utc_at_midnight(1988, 1, 2);
-    /// assert_eq!(epoch.previous(Weekday::Friday), Epoch::from_gregorian_utc_at_midnight(1988, 1, 1));
-    /// assert_eq!(epoch.previous(Weekday::Thursday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 31));
-    /// assert_eq!(epoch.previous(Weekday::Wednesday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 30));
-    /// assert_eq!(epoch.previous(Weekday::Tuesday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 29));
-    /// assert_eq!(epoch.previous(Weekday::Monday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 28));
-    /// assert_eq!(epoch.previous(Weekday::Sunday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 27));
-    /// assert_eq!(epoch.previous(Weekday::Saturday), Epoch::from_gregorian_utc_at_midnight(1987, 12, 26));
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// let end = start + 1.days();
+    /// assert!(start.is_between(start, end));
+    /// assert!((start + 12.hours()).is_between(start, end));
+    /// assert!(!end.is_between(start, end));
     /// ```
-    pub fn previous(&self, weekday: Weekday) -> Self {
-        let delta_days = weekday - self.weekday();
-        if delta_days == Duration::ZERO {
-            *self - 7 * Unit::Day
-        } else {
-            *self - delta_days
-        }
-    }
-
-    #[must_use]
-    pub fn previous_weekday_at_midnight(&self, weekday: Weekday) -> Self {
-        self.previous(weekday).with_hms_strict(0, 0, 0)
+    pub fn is_between(&self, start: Self, end: Self) -> bool {
+        start <= *self && *self < end
     }
 
     #[must_use]
-    pub fn previous_weekday_at_noon(&self, weekday: Weekday) -> Self {
-        self.previous(weekday).with_hms_strict(12, 0, 0)
+    /// Returns the year of the Gregorian representation of this epoch in the time scale it was initialized in.
+    ///
+    /// Each of the Gregorian accessors computes the full Gregorian representation, so use `to_gregorian` to retrieve several of its fields at once.
+    pub fn year(&self) -> i32 {
+        self.compute_gregorian_in(self.time_scale).0
     }
 
     #[must_use]
-    /// Returns the duration since the start of the year
-    pub fn duration_in_year(&self) -> Duration {
-        let year = Self::compute_gregorian(self.to_duration()).0;
-        let start_of_year = Self::from_gregorian(year, 1, 1, 0, 0, 0, 0, self.time_scale);
-        self.to_duration() - start_of_year.to_duration()
+    /// Returns the month (from 1 to 12) of the Gregorian representation of this epoch in the time scale it was initialized in.
+    pub fn month(&self) -> u8 {
+        self.compute_gregorian_in(self.time_scale).1
     }
 
     #[must_use]
-    /// Returns the number of days since the start of the year.
-    pub fn day_of_year(&self) -> f64 {
-        self.duration_in_year().to_unit(Unit::Day)
+    /// Returns the day of the month (from 1 to 31) of the Gregorian representation of this epoch in the time scale it was initialized in.
+    pub fn day(&self) -> u8 {
+        self.compute_gregorian_in(self.time_scale).2
     }
 
     #[must_use]
-    /// Returns the year and the days in the year so far (days of year).
-    pub fn year_days_of_year(&self) -> (i32, f64) {
-        (
-            Self::compute_gregorian(self.to_duration()).0,
-            self.day_of_year(),
-        )
+    /// Returns the nanoseconds within the second (from 0 to 999_999_999) of the Gregorian representation of this epoch in the time scale it was initialized in.
+    pub fn nanoseconds_of_second(&self) -> u32 {
+        self.compute_gregorian_in(self.time_scale).6
     }
 
     /// Returns the hours of the Gregorian representation  of this epoch in the time scale it was initialized in.
     pub fn hours(&self) -> u64 {
-        self.to_duration().decompose().2
+        self.compute_gregorian_in(self.time_scale).3.into()
     }
 
     /// Returns the minutes of the Gregorian representation  of this epoch in the time scale it was initialized in.
     pub fn minutes(&self) -> u64 {
-        self.to_duration().decompose().3
+        self.compute_gregorian_in(self.time_scale).4.into()
     }
 
     /// Returns the seconds of the Gregorian representation  of this epoch in the time scale it was initialized in.
     pub fn seconds(&self) -> u64 {
-        self.to_duration().decompose().4
+        self.compute_gregorian_in(self.time_scale).5.into()
     }
 
     /// Returns the milliseconds of the Gregorian representation  of this epoch in the time scale it was initialized in.
     pub fn milliseconds(&self) -> u64 {
-        self.to_duration().decompose().5
+        u64::from(self.nanoseconds_of_second()) / 1_000_000
     }
 
     /// Returns the microseconds of the Gregorian representation  of this epoch in the time scale it was initialized in.
     pub fn microseconds(&self) -> u64 {
-        self.to_duration().decompose().6
+        u64::from(self.nanoseconds_of_second()) / 1_000 % 1_000
     }
 
     /// Returns the nanoseconds of the Gregorian representation  of this epoch in the time scale it was initialized in.
     pub fn nanoseconds(&self) -> u64 {
-        self.to_duration().decompose().7
+        u64::from(self.nanoseconds_of_second()) % 1_000
     }
 
     /// Returns a copy of self where the time is set to the provided hours, minutes, seconds
@@ -2488,6 +4273,106 @@ impl Epoch {
         )
     }
 
+    /// Returns a copy of self where the time of day is set to the provided hours, minutes, and seconds, keeping the same date,
+    /// and the subdivisions of seconds set to zero.
+    ///
+    /// Unlike `with_hms`, invalid times (e.g. 25 hours, or a second 60 on a day without a leap second) return an error instead of overflowing into their higher unit.
+    ///
+    /// ```
+    /// use hifitime::prelude::*;
+    ///
+    /// let epoch = Epoch::from_gregorian_utc(2022, 12, 01, 10, 11, 12, 13);
+    /// assert_eq!(
+    ///     epoch.try_with_hms(6, 0, 0).unwrap(),
+    ///     Epoch::from_gregorian_utc_hms(2022, 12, 01, 6, 0, 0)
+    /// );
+    /// assert_eq!(epoch.try_with_hms(24, 0, 0), Err(Errors::Carry));
+    /// ```
+    pub fn try_with_hms(&self, hours: u8, minutes: u8, seconds: u8) -> Result<Self, Errors> {
+        // The Gregorian validity check allows 24:00:00 as the end of the day, but that would change the date.
+        if hours > 23 {
+            return Err(Errors::Carry);
+        }
+        let (y, mm, dd, _, _, _, _) = self.compute_gregorian_in(self.time_scale);
+        Self::maybe_from_gregorian(y, mm, dd, hours, minutes, seconds, 0, self.time_scale)
+    }
+
+    /// Returns a copy of self where the time of day is set to the provided duration past midnight, keeping the same date.
+    ///
+    /// Returns an error if the duration is negative or does not fit within the day, accounting for the leap second in UTC if any.
+    ///
+    /// ```
+    /// use hifitime::prelude::*;
+    ///
+    /// let epoch = Epoch::from_gregorian_utc(2022, 12, 01, 10, 11, 12, 13);
+    /// assert_eq!(
+    ///     epoch.with_time_of_day(6.hours() + 30.minutes() + 1.5.seconds()).unwrap(),
+    ///     Epoch::from_gregorian_utc(2022, 12, 01, 6, 30, 1, 500_000_000)
+    /// );
+    /// assert!(epoch.with_time_of_day(1.days()).is_err());
+    /// ```
+    pub fn with_time_of_day(&self, time_of_day: Duration) -> Result<Self, Errors> {
+        if time_of_day.is_negative() {
+            return Err(Errors::Carry);
+        }
+        let (y, mm, dd, _, _, _, _) = self.compute_gregorian_in(self.time_scale);
+        let (_, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds) =
+            time_of_day.decompose();
+        let nanos = milliseconds * NANOSECONDS_PER_MILLISECOND
+            + microseconds * NANOSECONDS_PER_MICROSECOND
+            + nanoseconds;
+        let (hours, minutes, seconds) = match (days, hours, minutes, seconds) {
+            // Within the day
+            (0, _, _, _) => (hours, minutes, seconds),
+            // This may be the leap second, which is checked when building the new epoch
+            (1, 0, 0, 0) => (23, 59, 60),
+            _ => return Err(Errors::Carry),
+        };
+        Self::maybe_from_gregorian(
+            y,
+            mm,
+            dd,
+            hours as u8,
+            minutes as u8,
+            seconds as u8,
+            nanos as u32,
+            self.time_scale,
+        )
+    }
+
+    #[must_use]
+    /// Returns a copy of self where the sub-second portion is replaced by the provided nanoseconds, keeping the same date and
+    /// time down to the second, including a leap second in UTC.
+    ///
+    /// Nanoseconds of one second or more wrap into the following seconds, as if that duration were added to the start of the current second.
+    ///
+    /// ```
+    /// use hifitime::prelude::*;
+    ///
+    /// let epoch = Epoch::from_gregorian_utc(2022, 12, 01, 10, 11, 12, 13);
+    /// assert_eq!(
+    ///     epoch.with_nanoseconds(500_000_000),
+    ///     Epoch::from_gregorian_utc(2022, 12, 01, 10, 11, 12, 500_000_000)
+    /// );
+    /// assert_eq!(
+    ///     epoch.with_nanoseconds(1_500_000_000),
+    ///     Epoch::from_gregorian_utc(2022, 12, 01, 10, 11, 13, 500_000_000)
+    /// );
+    /// ```
+    pub fn with_nanoseconds(&self, nanos: u32) -> Self {
+        let (y, mm, dd, hh, min, s, _) = self.compute_gregorian_in(self.time_scale);
+        let (carry, nanos) = (
+            nanos / NANOSECONDS_PER_SECOND_U32,
+            nanos % NANOSECONDS_PER_SECOND_U32,
+        );
+        let epoch = Self::from_gregorian(y, mm, dd, hh, min, s, nanos, self.time_scale);
+        if carry == 0 {
+            epoch
+        } else {
+            epoch + i64::from(carry) * Unit::Second
+        }
+    }
+
     /// Returns a copy of self where the hours, minutes, seconds is set to the time of the provided epoch but the
     /// sub-second parts are kept from the current epoch.
     ///
@@ -2496,7 +4381,7 @@ impl Epoch {
     ///
     /// let epoch = Epoch::from_gregorian_utc(2022, 12, 01, 10, 11, 12, 13);
     /// let other_utc = Epoch::from_gregorian_utc(2024, 12, 01, 20, 21, 22, 23);
-    /// let other = other_utc.in_time_scale(TimeScale::TDB);
+    /// let other = other_utc.with_time_scale(TimeScale::TDB);
     ///
     /// assert_eq!(
     ///     epoch.with_hms_from(other),
@@ -2507,7 +4392,7 @@ impl Epoch {
         let (sign, days, _, _, _, milliseconds, microseconds, nanoseconds) =
             self.to_duration().decompose();
         // Shadow other with the provided other epoch but in the correct time scale.
-        let other = other.in_time_scale(self.time_scale);
+        let other = other.with_time_scale(self.time_scale);
         Self::from_duration(
             Duration::compose(
                 sign,
@@ -2531,7 +4416,7 @@ impl Epoch {
     /// let epoch = Epoch::from_gregorian_utc(2022, 12, 01, 10, 11, 12, 13);
     /// let other_utc = Epoch::from_gregorian_utc(2024, 12, 01, 20, 21, 22, 23);
     /// // If the other Epoch is in another time scale, it does not matter, it will be converted to the correct time scale.
-    /// let other = other_utc.in_time_scale(TimeScale::TDB);
+    /// let other = other_utc.with_time_scale(TimeScale::TDB);
     ///
     /// assert_eq!(
     ///     epoch.with_time_from(other),
@@ -2579,7 +4464,7 @@ impl Epoch {
     ///
     /// let epoch = Epoch::from_gregorian_utc(2022, 12, 01, 10, 11, 12, 13);
     /// let other_utc = Epoch::from_gregorian_utc(2024, 12, 01, 20, 21, 22, 23);
-    /// let other = other_utc.in_time_scale(TimeScale::TDB);
+    /// let other = other_utc.with_time_scale(TimeScale::TDB);
     ///
     /// assert_eq!(
     ///     epoch.with_hms_strict_from(other),
@@ -2588,7 +4473,7 @@ impl Epoch {
     /// ```
     pub fn with_hms_strict_from(&self, other: Self) -> Self {
         let (sign, days, _, _, _, _, _, _) = self.to_duration().decompose();
-        let other = other.in_time_scale(self.time_scale);
+        let other = other.with_time_scale(self.time_scale);
         Self::from_duration(
             Duration::compose(
                 sign,
@@ -2605,8 +4490,7 @@ impl Epoch {
     }
 
     pub fn month_name(&self) -> MonthName {
-        let month = Self::compute_gregorian(self.to_duration()).1;
-        month.into()
+        self.month().into()
     }
 
     // Python helpers
@@ -2642,8 +4526,14 @@ impl Epoch {
     }
 
     #[cfg(feature = "python")]
-    fn __sub__(&self, duration: Duration) -> Self {
-        *self - duration
+    /// Subtracts either a duration, returning an epoch, or another epoch, returning the duration between both.
+    fn __sub__(&self, py: Python, other: &PyAny) -> PyResult<PyObject> {
+        if let Ok(other) = other.extract::<Self>() {
+            Ok((*self - other).into_py(py))
+        } else {
+            let duration: Duration = other.extract()?;
+            Ok((*self - duration).into_py(py))
+        }
     }
 
     #[cfg(feature = "python")]
@@ -2651,6 +4541,58 @@ impl Epoch {
         *self - other
     }
 
+    #[cfg(feature = "python")]
+    /// Returns the Gregorian date and time in UTC as a tuple of (year, month, day, hour, minute, second, nanoseconds).
+    fn gregorian_utc(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        self.to_gregorian_utc()
+    }
+
+    #[cfg(feature = "python")]
+    /// Returns the Gregorian date and time in TAI as a tuple of (year, month, day, hour, minute, second, nanoseconds).
+    fn gregorian_tai(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        self.to_gregorian_tai()
+    }
+
+    #[cfg(feature = "python")]
+    #[allow(clippy::wrong_self_convention)]
+    /// Returns this epoch as a timezone aware Python `datetime` in UTC.
+    ///
+    /// Python's `datetime` only has a microsecond precision, so the nanoseconds are truncated.
+    /// It also does not support leap seconds, so an epoch during a leap second is returned as 23:59:59.999999.
+    fn to_datetime<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDateTime> {
+        let (y, mm, dd, hh, min, s, nanos) = self.to_gregorian_utc();
+        let (s, micros) = if s == 60 {
+            (59, 999_999)
+        } else {
+            (s, nanos / 1_000)
+        };
+        let utc = python_utc_tzinfo(py)?;
+        PyDateTime::new(py, y, mm, dd, hh, min, s, micros, Some(utc))
+    }
+
+    #[cfg(feature = "python")]
+    #[staticmethod]
+    /// Initializes a UTC Epoch from a Python `datetime`.
+    /// A timezone aware `datetime` is converted to UTC first, whereas a naive `datetime` is treated as UTC.
+    fn init_from_datetime(dt: &PyDateTime) -> PyResult<Self> {
+        let dt = if dt.get_tzinfo().is_some() {
+            let utc = python_utc_tzinfo(dt.py())?;
+            dt.call_method1("astimezone", (utc,))?
+                .downcast::<PyDateTime>()?
+        } else {
+            dt
+        };
+        Ok(Self::maybe_from_gregorian_utc(
+            dt.get_year(),
+            dt.get_month(),
+            dt.get_day(),
+            dt.get_hour(),
+            dt.get_minute(),
+            dt.get_second(),
+            dt.get_microsecond() * 1_000,
+        )?)
+    }
+
     #[cfg(feature = "python")]
     fn __richcmp__(&self, other: Self, op: CompareOp) -> bool {
         match op {
@@ -2663,6 +4605,38 @@ impl Epoch {
         }
     }
 
+    #[cfg(feature = "python")]
+    /// Same hash as the `Hash` implementation, so that it is consistent with the equality.
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[cfg(feature = "python")]
+    fn __getstate__(&self) -> (i16, u64, u8) {
+        let (centuries, nanoseconds) = self.duration_since_j1900_tai.to_parts();
+        (centuries, nanoseconds, self.time_scale.into())
+    }
+
+    #[cfg(feature = "python")]
+    fn __setstate__(&mut self, state: (i16, u64, u8)) {
+        *self = Self::from_tai_parts(state.0, state.1);
+        self.time_scale = state.2.into();
+    }
+
+    #[cfg(feature = "python")]
+    /// Pickles this epoch as its TAI parts, rebuilt with `Epoch.init_from_tai_parts`, and its time scale set from the state.
+    #[allow(clippy::type_complexity)]
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (i16, u64), (i16, u64, u8))> {
+        let init = py.get_type::<Self>().getattr("init_from_tai_parts")?;
+        Ok((
+            init.into(),
+            self.duration_since_j1900_tai.to_parts(),
+            self.__getstate__(),
+        ))
+    }
+
     #[deprecated(
         since = "3.8.0",
         note = "Prefer using `format!(\"{}\", epoch)` directly"
@@ -2689,16 +4663,16 @@ impl Epoch {
     #[must_use]
     /// Converts the Epoch to Gregorian in the provided time scale and in the ISO8601 format with the time scale appended to the string
     pub fn to_gregorian_str(&self, time_scale: TimeScale) -> String {
-        let (y, mm, dd, hh, min, s, nanos) = Self::compute_gregorian(match time_scale {
-            TimeScale::TT => self.to_tt_duration(),
-            TimeScale::TAI => self.to_tai_duration(),
-            TimeScale::ET => self.to_et_duration_since_j1900(),
-            TimeScale::TDB => self.to_tdb_duration_since_j1900(),
-            TimeScale::UTC => self.to_utc_duration(),
-            TimeScale::GPST => self.to_utc_duration(),
-            TimeScale::GST => self.to_utc_duration(),
-            TimeScale::BDT => self.to_utc_duration(),
-        });
+        let (y, mm, dd, hh, min, s, nanos) = match time_scale {
+            TimeScale::TT => Self::compute_gregorian(self.to_tt_duration()),
+            TimeScale::TAI => Self::compute_gregorian(self.to_tai_duration()),
+            TimeScale::ET => Self::compute_gregorian(self.to_et_duration_since_j1900()),
+            TimeScale::TDB => Self::compute_gregorian(self.to_tdb_duration_since_j1900()),
+            TimeScale::UTC => self.to_gregorian_utc(),
+            TimeScale::GPST => self.to_gregorian_utc(),
+            TimeScale::GST => self.to_gregorian_utc(),
+            TimeScale::BDT => self.to_gregorian_utc(),
+        };
 
         if nanos == 0 {
             format!(
@@ -2713,10 +4687,65 @@ impl Epoch {
         }
     }
 
+    #[cfg(feature = "std")]
+    /// Returns this epoch in the provided time scale in the expanded representation of ISO 8601, where the year always has a sign
+    /// and is padded to six digits, e.g. `+012023-01-14T00:31:55 UTC` or `-000300-03-01T00:00:00 TAI`.
+    ///
+    /// Unlike the default representation of four digits, this remains unambiguous for negative years and for years beyond 9999.
+    /// The output can be parsed back with `from_gregorian_str`.
+    pub fn to_iso8601_expanded(&self, time_scale: TimeScale) -> String {
+        let (y, mm, dd, hh, min, s, nanos) = self.compute_gregorian_in(time_scale);
+        let sign = if y < 0 { '-' } else { '+' };
+        let y = y.unsigned_abs();
+        if nanos == 0 {
+            format!(
+                "{}{:06}-{:02}-{:02}T{:02}:{:02}:{:02} {}",
+                sign, y, mm, dd, hh, min, s, time_scale
+            )
+        } else {
+            format!(
+                "{}{:06}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09} {}",
+                sign, y, mm, dd, hh, min, s, nanos, time_scale
+            )
+        }
+    }
+
+    #[cfg(feature = "std")]
+    /// Returns this epoch in the ordinal date representation of ISO 8601, where the month and day are replaced by the day of the
+    /// year from 001 to 366, e.g. `2023-045T00:31:55 UTC`. The epoch is represented in the time scale it was initialized in,
+    /// which is appended to the string, and the output can be parsed back with `from_gregorian_str`.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::Epoch;
+    ///
+    /// let e = Epoch::from_gregorian_utc_hms(2023, 2, 14, 0, 31, 55);
+    /// assert_eq!(e.to_iso8601_ordinal(), "2023-045T00:31:55 UTC");
+    /// assert_eq!(Epoch::from_gregorian_str("2023-045T00:31:55Z").unwrap(), e);
+    /// ```
+    pub fn to_iso8601_ordinal(&self) -> String {
+        let (y, mm, dd, hh, min, s, nanos) = self.compute_gregorian_in(self.time_scale);
+        let ordinal = MonthName::from(mm).days_since_year_start(y) + u16::from(dd);
+        // The magnitude of a negative year is padded after its sign, e.g. `-0500-060`, so that it parses back
+        let sign = if y < 0 { "-" } else { "" };
+        let y = y.unsigned_abs();
+        if nanos == 0 {
+            format!(
+                "{}{:04}-{:03}T{:02}:{:02}:{:02} {}",
+                sign, y, ordinal, hh, min, s, self.time_scale
+            )
+        } else {
+            format!(
+                "{}{:04}-{:03}T{:02}:{:02}:{:02}.{:09} {}",
+                sign, y, ordinal, hh, min, s, nanos, self.time_scale
+            )
+        }
+    }
+
     #[cfg(feature = "std")]
     /// Returns this epoch in UTC in the RFC3339 format
     pub fn to_rfc3339(&self) -> String {
-        let (y, mm, dd, hh, min, s, nanos) = Self::compute_gregorian(self.to_utc_duration());
+        let (y, mm, dd, hh, min, s, nanos) = self.to_gregorian_utc();
         if nanos == 0 {
             format!(
                 "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}+00:00",
@@ -2730,6 +4759,23 @@ impl Epoch {
         }
     }
 
+    #[cfg(feature = "std")]
+    /// Returns this epoch in UTC in the RFC2822 format, e.g. `Sat, 14 Jan 2017 00:31:55 +0000`, as used in email and HTTP headers.
+    /// The subseconds are truncated because RFC2822 does not support them.
+    pub fn to_rfc2822(&self) -> String {
+        let (y, mm, dd, hh, min, s, _) = self.to_gregorian_utc();
+        format!(
+            "{:x}, {} {:x} {:04} {:02}:{:02}:{:02} +0000",
+            self.weekday_utc(),
+            dd,
+            MonthName::from(mm),
+            y,
+            hh,
+            min,
+            s
+        )
+    }
+
     /// Returns the minimum of the two epochs.
     ///
     /// ```
@@ -2771,6 +4817,36 @@ impl Epoch {
             other
         }
     }
+
+    /// Returns the duration elapsed since the provided reference epoch, e.g. the time since a launch.
+    ///
+    /// This is strictly equivalent to `*self - reference` and is negative if `self` is before the reference.
+    ///
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let liftoff = Epoch::from_gregorian_utc_hms(2022, 11, 16, 6, 47, 44);
+    /// let e = liftoff + 12.minutes() + 34.seconds();
+    ///
+    /// assert_eq!(e.duration_since(liftoff), 12.minutes() + 34.seconds());
+    /// assert_eq!(liftoff.duration_since(e), -(12.minutes() + 34.seconds()));
+    /// ```
+    pub fn duration_since(&self, reference: Self) -> Duration {
+        *self - reference
+    }
+
+    /// Returns the number of seconds elapsed since the provided reference epoch, as an f64.
+    ///
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// let liftoff = Epoch::from_gregorian_utc_hms(2022, 11, 16, 6, 47, 44);
+    ///
+    /// assert_eq!((liftoff + 754.5.seconds()).seconds_since(liftoff), 754.5);
+    /// ```
+    pub fn seconds_since(&self, reference: Self) -> f64 {
+        self.duration_since(reference).to_seconds()
+    }
 }
 
 // This is in its separate impl far away from the Python feature because pyO3's staticmethod does not work with cfg_attr
@@ -2779,12 +4855,42 @@ impl Epoch {
     /// Initializes a new Epoch from `now`.
     /// WARNING: This assumes that the system time returns the time in UTC (which is the case on Linux)
     /// Uses [`std::time::SystemTime::now`](https://doc.rust-lang.org/std/time/struct.SystemTime.html#method.now) under the hood
+    #[cfg(not(all(target_arch = "wasm32", feature = "wasm")))]
     pub fn now() -> Result<Self, Errors> {
         match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
             Ok(std_duration) => Ok(Self::from_unix_seconds(std_duration.as_secs_f64())),
             Err(_) => Err(Errors::SystemTimeError),
         }
     }
+
+    /// Initializes a new Epoch from `now` in a WebAssembly environment, where `SystemTime::now` panics.
+    /// Uses [`Date.now()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/now) under the hood,
+    /// which returns the UTC wall clock time with a millisecond resolution at best: browsers may coarsen it further.
+    #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+    pub fn now() -> Result<Self, Errors> {
+        Ok(Self::from_unix_milliseconds(js_sys::Date::now()))
+    }
+}
+
+impl Epoch {
+    /// Returns the current epoch of the provided clock, e.g. a `SystemClock` in production and a `FixedClock` in tests.
+    pub fn now_with(clock: &impl Clock) -> Result<Self, Errors> {
+        clock.now()
+    }
+
+    /// Returns the duration elapsed from this epoch to the current epoch of the provided clock, negative if this epoch is in its future.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, FixedClock, TimeUnits};
+    ///
+    /// let start = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// let clock = FixedClock(start + 90.minutes());
+    /// assert_eq!(start.elapsed_with(&clock).unwrap(), 90.minutes());
+    /// ```
+    pub fn elapsed_with(&self, clock: &impl Clock) -> Result<Duration, Errors> {
+        Ok(clock.now()? - *self)
+    }
 }
 
 #[cfg(not(kani))]
@@ -2797,6 +4903,7 @@ impl FromStr for Epoch {
     ///  + JD: Julian days
     ///  + MJD: Modified Julian days
     ///  + SEC: Seconds past a given epoch (e.g. SEC 17.2 TAI is 17.2 seconds past TAI Epoch)
+    ///  + UNIX: UNIX timestamp in seconds, always in UTC (e.g. UNIX 1651487955.123), see `from_unix_str`
     /// # Example
     /// ```
     /// use hifitime::Epoch;
@@ -2805,13 +4912,20 @@ impl FromStr for Epoch {
     /// assert!(Epoch::from_str("JD 2452312.500372511 TDB").is_ok());
     /// assert!(Epoch::from_str("JD 2452312.500372511 ET").is_ok());
     /// assert!(Epoch::from_str("JD 2452312.500372511 TAI").is_ok());
+    /// assert!(Epoch::from_str("JD 2451545.0 TT").is_ok());
     /// assert!(Epoch::from_str("MJD 51544.5 TAI").is_ok());
+    /// assert!(Epoch::from_str("MJD 51544.5 TT").is_ok());
     /// assert!(Epoch::from_str("SEC 0.5 TAI").is_ok());
     /// assert!(Epoch::from_str("SEC 66312032.18493909 TDB").is_ok());
+    /// assert!(Epoch::from_str("UNIX 1651487955.123").is_ok());
     /// ```
     fn from_str(s_in: &str) -> Result<Self, Self::Err> {
         let s = s_in.trim();
 
+        if let Some(timestamp) = s.strip_prefix("UNIX ") {
+            return Self::from_unix_str(timestamp);
+        }
+
         if s.len() < 7 {
             // We need at least seven characters for a valid epoch
             Err(Errors::ParseError(ParsingErrors::UnknownFormat))
@@ -2828,52 +4942,120 @@ impl FromStr for Epoch {
             };
 
             // This is a valid numerical format.
-            // Parse the time scale from the last three characters (TS trims white spaces).
-            let ts = TimeScale::from_str(&s[s.len() - 3..])?;
+            // Parse the time scale from the last word, which may be up to four characters long (e.g. GPST).
+            let ts_str = s.rsplit(' ').next().unwrap_or_default();
+            let ts = TimeScale::from_str(ts_str)?;
             // Iterate through the string to figure out where the numeric data starts and ends.
             let start_idx = format.len();
-            let num_str = s[start_idx..s.len() - ts.formatted_len()].trim();
+            let num_str = s[start_idx..s.len() - ts_str.len()].trim();
             let value: f64 = match lexical_core::parse(num_str.as_bytes()) {
                 Ok(val) => val,
                 Err(_) => return Err(Errors::ParseError(ParsingErrors::ValueError)),
             };
 
-            match format {
-                "JD" => match ts {
-                    TimeScale::ET => Ok(Self::from_jde_et(value)),
-                    TimeScale::TAI => Ok(Self::from_jde_tai(value)),
-                    TimeScale::TDB => Ok(Self::from_jde_tdb(value)),
-                    TimeScale::UTC => Ok(Self::from_jde_utc(value)),
-                    _ => Err(Errors::ParseError(ParsingErrors::UnsupportedTimeSystem)),
-                },
-                "MJD" => match ts {
-                    TimeScale::TAI => Ok(Self::from_mjd_tai(value)),
-                    TimeScale::UTC | TimeScale::GPST | TimeScale::BDT | TimeScale::GST => {
-                        Ok(Self::from_mjd_in_time_scale(value, ts))
-                    }
-                    _ => Err(Errors::ParseError(ParsingErrors::UnsupportedTimeSystem)),
-                },
-                "SEC" => match ts {
-                    TimeScale::TAI => Ok(Self::from_tai_seconds(value)),
-                    TimeScale::ET => Ok(Self::from_et_seconds(value)),
-                    TimeScale::TDB => Ok(Self::from_tdb_seconds(value)),
-                    TimeScale::TT => Ok(Self::from_tt_seconds(value)),
-                    ts => {
-                        let secs = Duration::from_f64(value, Unit::Second);
-                        Ok(Self::from_duration(secs, ts))
-                    }
-                },
-                _ => Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
-            }
+            Self::from_numeric_format(format, value, ts)
+        }
+    }
+}
+
+impl Epoch {
+    /// Initializes an Epoch from the value of one of the numerical formats of `from_str`, i.e. `JD`, `MJD` or `SEC`.
+    fn from_numeric_format(format: &str, value: f64, ts: TimeScale) -> Result<Self, Errors> {
+        match format {
+            "JD" => match ts {
+                TimeScale::ET => Ok(Self::from_jde_et(value)),
+                TimeScale::TAI => Ok(Self::from_jde_tai(value)),
+                TimeScale::TDB => Ok(Self::from_jde_tdb(value)),
+                TimeScale::TT => Ok(Self::from_jde_tt(value)),
+                TimeScale::UTC | TimeScale::GPST | TimeScale::BDT | TimeScale::GST => {
+                    Ok(Self::from_jde_in_time_scale(value, ts))
+                }
+            },
+            "MJD" => match ts {
+                TimeScale::TAI => Ok(Self::from_mjd_tai(value)),
+                TimeScale::TT => Ok(Self::from_mjd_tt(value)),
+                TimeScale::UTC | TimeScale::GPST | TimeScale::BDT | TimeScale::GST => {
+                    Ok(Self::from_mjd_in_time_scale(value, ts))
+                }
+                _ => Err(Errors::ParseError(ParsingErrors::UnsupportedTimeSystem)),
+            },
+            "SEC" => match ts {
+                TimeScale::TAI => Ok(Self::from_tai_seconds(value)),
+                TimeScale::ET => Ok(Self::from_et_seconds(value)),
+                TimeScale::TDB => Ok(Self::from_tdb_seconds(value)),
+                TimeScale::TT => Ok(Self::from_tt_seconds(value)),
+                ts => {
+                    let secs = Duration::from_f64(value, Unit::Second);
+                    Ok(Self::from_duration(secs, ts))
+                }
+            },
+            _ => Err(Errors::ParseError(ParsingErrors::UnknownFormat)),
+        }
+    }
+
+    /// Permissively parses an epoch from "whatever the user types", e.g. in a command line interface.
+    ///
+    /// This tries, in order:
+    /// 1. the formats of `from_str`, i.e. a Gregorian date in ISO 8601 or RFC3339, or a number with one of the `JD`, `MJD`, `SEC`, or `UNIX` format identifiers;
+    /// 2. a bare number, e.g. `1651487955.123`, which is interpreted according to the provided convention instead of guessing it.
+    ///
+    /// A bare UNIX timestamp in decimal notation is converted exactly, like `from_unix_str`. Bare numbers may also use the
+    /// exponent notation, e.g. `1.6e9`, in which case they are parsed as an f64.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{BareNumber, Epoch, TimeScale};
+    ///
+    /// let e = Epoch::from_gregorian_utc_hms(2022, 5, 2, 10, 39, 15);
+    /// assert_eq!(Epoch::parse_any("2022-05-02T10:39:15Z", BareNumber::UnixSeconds).unwrap(), e);
+    /// assert_eq!(Epoch::parse_any("UNIX 1651487955", BareNumber::UnixSeconds).unwrap(), e);
+    /// assert_eq!(Epoch::parse_any("1651487955", BareNumber::UnixSeconds).unwrap(), e);
+    /// assert_eq!(
+    ///     Epoch::parse_any("2451545.0", BareNumber::JulianDays(TimeScale::TT)).unwrap(),
+    ///     Epoch::from_jde_tt(2451545.0)
+    /// );
+    /// ```
+    pub fn parse_any(s: &str, bare_number: BareNumber) -> Result<Self, Errors> {
+        let err = match Self::from_str(s) {
+            Ok(epoch) => return Ok(epoch),
+            Err(err) => err,
+        };
+
+        let s = s.trim();
+        let value: f64 = match lexical_core::parse(s.as_bytes()) {
+            Ok(value) => value,
+            // Not a number either, so report why it is not a valid format.
+            Err(_) => return Err(err),
+        };
+        if !value.is_finite() {
+            return Err(Errors::ParseError(ParsingErrors::ValueError));
+        }
+
+        match bare_number {
+            // The exponent notation is not supported by `from_unix_str`, which never goes through a float.
+            BareNumber::UnixSeconds if s.contains(['e', 'E']) => Ok(Self::from_unix_seconds(value)),
+            BareNumber::UnixSeconds => Self::from_unix_str(s),
+            BareNumber::JulianDays(ts) => Self::from_numeric_format("JD", value, ts),
+            BareNumber::ModifiedJulianDays(ts) => Self::from_numeric_format("MJD", value, ts),
         }
     }
 }
 
+/// The convention of a bare number, i.e. a number without any format identifier nor time scale, for `Epoch::parse_any`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BareNumber {
+    /// UNIX timestamp in seconds, always in UTC, cf. `Epoch::from_unix_str`
+    UnixSeconds,
+    /// Julian days in the provided time scale
+    JulianDays(TimeScale),
+    /// Modified Julian days in the provided time scale
+    ModifiedJulianDays(TimeScale),
+}
+
 impl fmt::Debug for Epoch {
     /// Print this epoch in Gregorian in the time scale used at initialization
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (y, mm, dd, hh, min, s, nanos) =
-            Self::compute_gregorian(self.to_duration_since_j1900());
+        let (y, mm, dd, hh, min, s, nanos) = self.compute_gregorian_in(self.time_scale);
         if nanos == 0 {
             write!(
                 f,
@@ -2894,7 +5076,7 @@ impl fmt::Display for Epoch {
     /// The default format of an epoch is in UTC
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let ts = TimeScale::UTC;
-        let (y, mm, dd, hh, min, s, nanos) = Self::compute_gregorian(self.to_utc_duration());
+        let (y, mm, dd, hh, min, s, nanos) = self.to_gregorian_utc();
         if nanos == 0 {
             write!(
                 f,
@@ -3011,8 +5193,21 @@ impl fmt::Octal for Epoch {
     }
 }
 
+/// Returns the UTC time zone of Python's `datetime` module.
+#[cfg(feature = "python")]
+fn python_utc_tzinfo(py: Python<'_>) -> PyResult<&PyTzInfo> {
+    Ok(py
+        .import("datetime")?
+        .getattr("timezone")?
+        .getattr("utc")?
+        .downcast::<PyTzInfo>()?)
+}
+
 #[must_use]
 /// Returns true if the provided Gregorian date is valid. Leap second days may have 60 seconds.
+///
+/// This function is `const`, so it only knows of the leap seconds of the built-in table and not of those registered at runtime
+/// with `register_leap_second`, which the Gregorian constructors and parsers of `Epoch` do account for.
 pub const fn is_gregorian_valid(
     year: i32,
     month: u8,
@@ -3022,11 +5217,12 @@ pub const fn is_gregorian_valid(
     second: u8,
     nanos: u32,
 ) -> bool {
-    let max_seconds = if (month == 12 || month == 6)
+    let max_seconds = if month >= 1
+        && month <= 12
         && day == usual_days_per_month(month - 1)
         && hour == 23
         && minute == 59
-        && ((month == 6 && july_years(year)) || (month == 12 && january_years(year + 1)))
+        && ends_with_leap_second(days_since_j1900(year, month, day))
     {
         60
     } else {
@@ -3051,22 +5247,106 @@ pub const fn is_gregorian_valid(
     true
 }
 
+/// Returns whether the provided Gregorian date and time is valid like `is_gregorian_valid`, but also accepts the 60th second
+/// of the days ending with a leap second registered at runtime with `register_leap_second`, which is why it is not `const`.
+pub(crate) fn is_gregorian_valid_with_registered(
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanos: u32,
+) -> bool {
+    is_gregorian_valid(year, month, day, hour, minute, second, nanos)
+        || (second == 60
+            && hour == 23
+            && minute == 59
+            && is_gregorian_valid(year, month, day, hour, minute, 59, nanos)
+            && ends_with_any_leap_second(days_since_j1900(year, month, day)))
+}
+
+/// Returns the month and day of the month of the provided day of the year, starting at 1 for January 01, or None if that year is shorter.
+pub(crate) const fn month_day_from_ordinal(year: i32, ordinal: u16) -> Option<(u8, u8)> {
+    let mut month = 12;
+    while month > 0 {
+        let month_name = match MonthName::from_u8(month) {
+            Some(month_name) => month_name,
+            None => return None,
+        };
+        let days_before = month_name.days_since_year_start(year);
+        if ordinal > days_before {
+            let day = ordinal - days_before;
+            return if day <= month_name.length(year) as u16 {
+                Some((month, day as u8))
+            } else {
+                None
+            };
+        }
+        month -= 1;
+    }
+    None
+}
+
+/// Returns the number of days from 1900 January 01 to the provided Gregorian date, which must be valid except for February 29.
+pub(crate) const fn days_since_j1900(year: i32, month: u8, day: u8) -> i64 {
+    // Number of leap years from year zero up to and including the provided year.
+    const fn leap_years_until(year: i64) -> i64 {
+        year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+    }
+    let year = year as i64;
+    let mut days = 365 * (year - 1900) + leap_years_until(year - 1) - leap_years_until(1899);
+    days += CUMULATIVE_DAYS_FOR_MONTH[(month - 1) as usize] as i64;
+    if month > 2 && is_leap_year(year as i32) {
+        days += 1;
+    }
+    days + day as i64 - 1
+}
+
 /// `is_leap_year` returns whether the provided year is a leap year or not.
 /// Tests for this function are part of the Datetime tests.
-const fn is_leap_year(year: i32) -> bool {
+pub(crate) const fn is_leap_year(year: i32) -> bool {
     (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
 }
 
-fn div_rem_f64(me: f64, rhs: f64) -> (i32, f64) {
-    ((div_euclid_f64(me, rhs) as i32), rem_euclid_f64(me, rhs))
+/// Computes the IAU 1982 GMST in radians from the UT1 duration past J2000 (noon).
+fn gmst_from_duration_j2000(ut1_j2k: Duration) -> f64 {
+    let days = ut1_j2k.to_unit(Unit::Day);
+    let centuries = days / DAYS_PER_CENTURY;
+    let degrees = 280.460_618_37 + 360.985_647_366_29 * days + 0.000_387_933 * centuries.powi(2)
+        - centuries.powi(3) / 38_710_000.0;
+    rem_euclid_f64(degrees, 360.0).to_radians()
 }
 
-fn div_euclid_f64(lhs: f64, rhs: f64) -> f64 {
-    let q = (lhs / rhs).trunc();
-    if lhs % rhs < 0.0 {
-        return if rhs > 0.0 { q - 1.0 } else { q + 1.0 };
-    }
-    q
+/// Mean longitude of the ascending node of the Moon in degrees, from the TT centuries past J2000.
+fn moon_ascending_node_deg(centuries: f64) -> f64 {
+    125.044_52 - 1_934.136_261 * centuries
+        + 0.002_070_8 * centuries.powi(2)
+        + centuries.powi(3) / 450_000.0
+}
+
+/// Computes the nutation in longitude and the true obliquity of the ecliptic, both in radians, from the TT centuries past J2000.
+/// Only the four largest terms of the IAU 1980 nutation series are used.
+fn nutation_iau1980_truncated(centuries: f64) -> (f64, f64) {
+    // Mean longitudes of the Sun and of the Moon
+    let sun = (280.4665 + 36_000.769_8 * centuries).to_radians();
+    let moon = (218.3165 + 481_267.881_3 * centuries).to_radians();
+    let node = moon_ascending_node_deg(centuries).to_radians();
+
+    // Both in arcseconds
+    let nutation_longitude =
+        -17.20 * node.sin() - 1.32 * (2.0 * sun).sin() - 0.23 * (2.0 * moon).sin()
+            + 0.21 * (2.0 * node).sin();
+    let nutation_obliquity =
+        9.20 * node.cos() + 0.57 * (2.0 * sun).cos() + 0.10 * (2.0 * moon).cos()
+            - 0.09 * (2.0 * node).cos();
+    let mean_obliquity = 84_381.448 - 46.815_0 * centuries - 0.000_59 * centuries.powi(2)
+        + 0.001_813 * centuries.powi(3);
+
+    (
+        (nutation_longitude / 3600.0).to_radians(),
+        ((mean_obliquity + nutation_obliquity) / 3600.0).to_radians(),
+    )
 }
 
 fn rem_euclid_f64(lhs: f64, rhs: f64) -> f64 {
@@ -3078,19 +5358,6 @@ fn rem_euclid_f64(lhs: f64, rhs: f64) -> f64 {
     }
 }
 
-#[test]
-fn div_rem_f64_test() {
-    assert_eq!(div_rem_f64(24.0, 6.0), (4, 0.0));
-    assert_eq!(div_rem_f64(25.0, 6.0), (4, 1.0));
-    assert_eq!(div_rem_f64(6.0, 6.0), (1, 0.0));
-    assert_eq!(div_rem_f64(5.0, 6.0), (0, 5.0));
-    assert_eq!(div_rem_f64(3540.0, 3600.0), (0, 3540.0));
-    assert_eq!(div_rem_f64(3540.0, 60.0), (59, 0.0));
-    assert_eq!(div_rem_f64(24.0, -6.0), (-4, 0.0));
-    assert_eq!(div_rem_f64(-24.0, 6.0), (-4, 0.0));
-    assert_eq!(div_rem_f64(-24.0, -6.0), (4, 0.0));
-}
-
 #[test]
 fn test_days_tdb_j2000() {
     let e = Epoch::from_tai_duration(Duration::from_parts(1, 723038437000000000));
@@ -24,7 +24,10 @@ use reqwest::StatusCode;
 use crate::Weekday;
 
 /// Errors handles all oddities which may occur in this library.
+///
+/// New variants may be added in minor releases, so matches on this enum must include a wildcard arm.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Errors {
     /// Carry is returned when a provided function does not support time carry. For example,
     /// if a call to `Datetime::new` receives 60 seconds and there are only 59 seconds in the provided
@@ -39,14 +42,27 @@ pub enum Errors {
     Overflow,
     /// Raised if the initialization from system time failed
     SystemTimeError,
+    /// Raised when strictly converting to or from UTC before 1960, when UTC was not yet defined
+    UtcUndefined,
+    /// Raised when registering a leap second which is not after all of the known leap seconds
+    LeapSecondOutOfOrder,
+    /// Raised when a floating point value is too large to be converted to the nanosecond, i.e. beyond 2^53 nanoseconds (about 104 days)
+    PrecisionLoss,
+    /// Raised when an epoch cannot be rebuilt from its own representation in its time scale, cf. `Epoch::debug_consistency`
+    InconsistentConversion,
 }
 
+/// The reasons why a string could not be parsed.
+///
+/// New variants may be added in minor releases, so matches on this enum must include a wildcard arm.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ParsingErrors {
     ParseIntError,
     ValueError,
     TimeSystem,
     ISO8601,
+    RFC2822,
     UnknownFormat,
     UnknownOrMissingUnit,
     UnsupportedTimeSystem,
@@ -62,6 +78,20 @@ pub enum ParsingErrors {
         found: Weekday,
         expected: Weekday,
     },
+    /// The month of a date is not between 1 and 12
+    MonthOutOfRange,
+    /// The day of a date is zero or after the end of its month, e.g. February 30
+    DayOutOfRange,
+    /// The hour of a time is not between 0 and 23
+    HourOutOfRange,
+    /// The minute of a time is not between 0 and 59
+    MinuteOutOfRange,
+    /// The second of a time is not between 0 and 59, or 60 at the end of a UTC day with a leap second
+    SecondOutOfRange,
+    /// The UTC offset after the time, e.g. `+05:30`, is not made of valid hours and minutes
+    InvalidTimeZone,
+    /// The character after a component of the date or time is not the expected separator
+    InvalidSeparator(char),
     #[cfg(feature = "std")]
     IOError(IOError),
     #[cfg(feature = "ut1")]
@@ -72,7 +102,7 @@ impl fmt::Display for Errors {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Self::Carry => write!(f, "a carry error (e.g. 61 seconds)"),
-            Self::ParseError(kind) => write!(f, "ParseError: {:?}", kind),
+            Self::ParseError(kind) => write!(f, "ParseError: {}", kind),
             Self::ConversionOverlapError(hi, lo) => {
                 write!(f, "hi and lo values overlap: {}, {}", hi, lo)
             }
@@ -81,6 +111,38 @@ impl fmt::Display for Errors {
                 "overflow occurred when trying to convert Duration information"
             ),
             Self::SystemTimeError => write!(f, "std::time::SystemTime returned an error"),
+            Self::UtcUndefined => write!(f, "UTC is not defined before 1960"),
+            Self::LeapSecondOutOfOrder => write!(
+                f,
+                "a registered leap second must be after all of the built-in leap seconds"
+            ),
+            Self::PrecisionLoss => write!(
+                f,
+                "the floating point value cannot be represented to the nanosecond"
+            ),
+            Self::InconsistentConversion => {
+                write!(f, "the epoch does not round trip through its time scale")
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParsingErrors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::MonthOutOfRange => write!(f, "the month must be between 1 and 12"),
+            Self::DayOutOfRange => write!(f, "the day must be within the month"),
+            Self::HourOutOfRange => write!(f, "the hour must be between 0 and 23"),
+            Self::MinuteOutOfRange => write!(f, "the minute must be between 0 and 59"),
+            Self::SecondOutOfRange => write!(
+                f,
+                "the second must be between 0 and 59, or 60 during a leap second"
+            ),
+            Self::InvalidTimeZone => {
+                write!(f, "the UTC offset must be formatted as +HH:MM or -HH:MM")
+            }
+            Self::InvalidSeparator(found) => write!(f, "unexpected separator '{found}'"),
+            _ => write!(f, "{self:?}"),
         }
     }
 }
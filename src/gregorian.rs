@@ -0,0 +1,117 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2022 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use crate::{Epoch, Errors, TimeScale};
+use core::convert::TryFrom;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde_derive::{Deserialize, Serialize};
+
+/// A Gregorian date and time in a given time scale, as returned by `Epoch::to_gregorian`.
+///
+/// Unlike the tuples returned by `to_gregorian_utc` and `to_gregorian_tai`, each field is named, which prevents mixing up the order of the fields.
+/// It can be converted back into an Epoch with `Epoch::try_from`, which fails if the date or time is invalid.
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use hifitime::{Epoch, TimeScale};
+///
+/// let e = Epoch::from_gregorian_utc(2022, 10, 3, 17, 44, 29, 898032665);
+/// let greg = e.to_gregorian(TimeScale::UTC);
+/// assert_eq!(greg.year, 2022);
+/// assert_eq!(greg.month, 10);
+/// assert_eq!(greg.nanos, 898032665);
+/// assert_eq!(format!("{greg}"), format!("{e}"));
+/// assert_eq!(Epoch::try_from(greg).unwrap(), e);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Gregorian {
+    pub year: i32,
+    /// Month of the year, starting at 1 for January
+    pub month: u8,
+    /// Day of the month, starting at 1
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    /// Second of the minute, which is 60 during a UTC leap second
+    pub second: u8,
+    pub nanos: u32,
+    pub time_scale: TimeScale,
+}
+
+impl Gregorian {
+    /// Converts this Gregorian date and time back into an Epoch, failing if the date or time is invalid, e.g. a leap second on a day without one.
+    ///
+    /// This is the inverse of `Epoch::to_gregorian`: for any epoch `e` and time scale `ts`, `e.to_gregorian(ts).to_epoch()` is `Ok(e)`,
+    /// to the nanosecond in all time scales but ET and TDB, whose conversions are computed with floating point values and match within a few nanoseconds.
+    ///
+    /// ```
+    /// use hifitime::{Epoch, TimeScale};
+    ///
+    /// let e = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 123_456_789);
+    /// for ts in [TimeScale::UTC, TimeScale::TAI, TimeScale::GPST] {
+    ///     assert_eq!(e.to_gregorian(ts).to_epoch(), Ok(e));
+    /// }
+    /// ```
+    pub fn to_epoch(&self) -> Result<Epoch, Errors> {
+        Epoch::try_from(*self)
+    }
+}
+
+impl TryFrom<Gregorian> for Epoch {
+    type Error = Errors;
+
+    fn try_from(greg: Gregorian) -> Result<Self, Self::Error> {
+        Self::maybe_from_gregorian(
+            greg.year,
+            greg.month,
+            greg.day,
+            greg.hour,
+            greg.minute,
+            greg.second,
+            greg.nanos,
+            greg.time_scale,
+        )
+    }
+}
+
+impl fmt::Display for Gregorian {
+    /// Formats this date as `YYYY-MM-DDTHH:MM:SS[.nnnnnnnnn] <time scale>`, like the Display of an Epoch.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.nanos == 0 {
+            write!(
+                f,
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02} {}",
+                self.year,
+                self.month,
+                self.day,
+                self.hour,
+                self.minute,
+                self.second,
+                self.time_scale
+            )
+        } else {
+            write!(
+                f,
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09} {}",
+                self.year,
+                self.month,
+                self.day,
+                self.hour,
+                self.minute,
+                self.second,
+                self.nanos,
+                self.time_scale
+            )
+        }
+    }
+}
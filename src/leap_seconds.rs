@@ -14,9 +14,55 @@ use pyo3::prelude::*;
 #[cfg(feature = "std")]
 pub use super::leap_seconds_file::LeapSecondsFile;
 
+use core::cell::Cell;
 use core::ops::Index;
 
-pub trait LeapSecondProvider: DoubleEndedIterator<Item = LeapSecond> + Index<usize> {}
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "std")]
+use std::sync::{PoisonError, RwLock};
+
+#[cfg(feature = "std")]
+use crate::Errors;
+
+use crate::epoch::days_since_j1900;
+use crate::{
+    Duration, Epoch, J1900_OFFSET, NANOSECONDS_PER_CENTURY, NANOSECONDS_PER_SECOND, SECONDS_PER_DAY,
+};
+
+/// A provider of the accumulated leap seconds (ΔAT) at a given instant.
+///
+/// The default methods of `Epoch` use the built-in `LatestLeapSeconds` table, but any type implementing this trait,
+/// e.g. a mission specific table or a table including a hypothetical future leap second, may be used instead via the `_with` methods.
+/// This trait is object safe and does not require any allocation, so it can be used in `no_std` environments.
+pub trait LeapSecondProvider {
+    /// Returns the accumulated number of leap seconds at the provided number of TAI seconds since J1900,
+    /// only accounting for the leap seconds announced by the IERS if `iers_only` is set.
+    /// Returns None if the provided time is before the first leap second of this provider.
+    fn leap_seconds_at(&self, tai_seconds: f64, iers_only: bool) -> Option<f64>;
+}
+
+impl<T: LeapSecondProvider + ?Sized> LeapSecondProvider for &T {
+    fn leap_seconds_at(&self, tai_seconds: f64, iers_only: bool) -> Option<f64> {
+        (**self).leap_seconds_at(tai_seconds, iers_only)
+    }
+}
+
+/// Returns the accumulated leap seconds from a list of leap seconds sorted in chronological order.
+pub(crate) fn leap_seconds_in(
+    data: &[LeapSecond],
+    tai_seconds: f64,
+    iers_only: bool,
+) -> Option<f64> {
+    for leap_second in data.iter().rev() {
+        if tai_seconds >= leap_second.timestamp_tai_s
+            && (!iers_only || leap_second.announced_by_iers)
+        {
+            return Some(leap_second.delta_at);
+        }
+    }
+    None
+}
 
 /// A structure representing a leap second
 #[repr(C)]
@@ -86,6 +132,128 @@ const LATEST_LEAP_SECONDS: [LeapSecond; 42] = [
     LeapSecond::new(3_692_217_600.0, 37.0, true),      // IERS: 01 Jan 2017
 ];
 
+/// Returns whether the UTC day starting the provided number of days after 1900 January 01 ends with a 23:59:60 leap second,
+/// i.e. whether the following midnight is the boundary of a built-in leap second announced by the IERS.
+pub(crate) const fn ends_with_leap_second(days_since_j1900: i64) -> bool {
+    let next_midnight_s = (days_since_j1900 + 1) * SECONDS_PER_DAY as i64;
+    let mut i = 0;
+    while i < LATEST_LEAP_SECONDS.len() {
+        let leap_second = LATEST_LEAP_SECONDS[i];
+        // All of the timestamps are whole seconds.
+        if leap_second.announced_by_iers && leap_second.timestamp_tai_s as i64 == next_midnight_s {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns whether the provided UTC day ends with a leap second announced by the IERS, either built-in or registered at runtime.
+/// Unlike `ends_with_leap_second`, this is not `const` since it reads the registered leap seconds.
+pub(crate) fn ends_with_any_leap_second(days_since_j1900: i64) -> bool {
+    ends_with_leap_second(days_since_j1900) || ends_with_registered_leap_second(days_since_j1900)
+}
+
+#[cfg(feature = "std")]
+fn ends_with_registered_leap_second(days_since_j1900: i64) -> bool {
+    if !HAS_REGISTERED_LEAP_SECONDS.load(Ordering::Acquire) {
+        return false;
+    }
+    let next_midnight_s = (days_since_j1900 + 1) * SECONDS_PER_DAY as i64;
+    REGISTERED_LEAP_SECONDS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .any(|leap_second| {
+            leap_second.announced_by_iers && leap_second.timestamp_tai_s as i64 == next_midnight_s
+        })
+}
+
+#[cfg(not(feature = "std"))]
+fn ends_with_registered_leap_second(_days_since_j1900: i64) -> bool {
+    false
+}
+
+/// Returns the accumulated number of leap seconds announced by the IERS as of January 01 of the provided year at midnight UTC,
+/// from the built-in table. This is zero before 1972, and agrees with `Epoch::leap_seconds(true)` at the start of that year.
+///
+/// This function is `const`, so it may be used to build static data at compile time. As such, it does not account
+/// for the leap seconds registered at runtime with `register_leap_second`.
+///
+/// # Example
+/// ```
+/// use hifitime::leap_seconds_for_year;
+///
+/// const DELTA_AT_2020: i32 = leap_seconds_for_year(2020);
+/// assert_eq!(DELTA_AT_2020, 37);
+/// assert_eq!(leap_seconds_for_year(1972), 10);
+/// assert_eq!(leap_seconds_for_year(1971), 0);
+/// ```
+pub const fn leap_seconds_for_year(year: i32) -> i32 {
+    let midnight_s = days_since_j1900(year, 1, 1) * SECONDS_PER_DAY as i64;
+    let mut i = LATEST_LEAP_SECONDS.len();
+    while i > 0 {
+        i -= 1;
+        let leap_second = LATEST_LEAP_SECONDS[i];
+        // All of the timestamps and offsets of the IERS are whole seconds.
+        if leap_second.announced_by_iers && leap_second.timestamp_tai_s as i64 <= midnight_s {
+            return leap_second.delta_at as i32;
+        }
+    }
+    0
+}
+
+/// The built-in leap seconds with their boundary as an Epoch in TAI, built at compile time.
+static LEAP_SECONDS_TABLE: [(Epoch, f64, bool); 42] = {
+    let mut table = [(Epoch::from_tai_duration(Duration::ZERO), 0.0, false); 42];
+    let mut i = 0;
+    while i < LATEST_LEAP_SECONDS.len() {
+        let leap_second = LATEST_LEAP_SECONDS[i];
+        // All of the timestamps are whole seconds.
+        let nanoseconds = leap_second.timestamp_tai_s as u64 * NANOSECONDS_PER_SECOND;
+        let boundary = Epoch::from_tai_duration(Duration {
+            centuries: (nanoseconds / NANOSECONDS_PER_CENTURY) as i16,
+            nanoseconds: nanoseconds % NANOSECONDS_PER_CENTURY,
+        });
+        table[i] = (
+            boundary,
+            leap_second.delta_at,
+            leap_second.announced_by_iers,
+        );
+        i += 1;
+    }
+    table
+};
+
+/// Returns the built-in leap seconds table in chronological order, as tuples of the boundary (from the stored TAI seconds),
+/// the accumulated offset ΔAT after that boundary, and whether this leap second was announced by the IERS.
+///
+/// The leap seconds which were not announced by the IERS are the offsets of UTC from 1960 to 1972, from dat.c in the SOFA library.
+///
+/// # Boundaries
+/// The boundary is the stored timestamp as is, i.e. the UTC midnight counted as if it were TAI seconds: this is the
+/// convention of the lookups of the table. The actual instant of that UTC midnight is later by ΔAT, and is the one returned
+/// by `Epoch::next_leap_second`, `Epoch::previous_leap_second` and `Epoch::leap_seconds_in_range`.
+///
+/// # Example
+/// ```
+/// use hifitime::leap_seconds::leap_seconds_table;
+/// use hifitime::{Epoch, TimeScale};
+///
+/// let (boundary, delta_at, announced) = *leap_seconds_table().last().unwrap();
+/// assert_eq!(boundary, Epoch::from_tai_seconds(3_692_217_600.0));
+/// assert_eq!(delta_at, 37.0);
+/// assert!(announced);
+///
+/// // The actual UTC midnight is later by ΔAT.
+/// let utc_midnight = Epoch::from_tai_seconds(boundary.to_tai_seconds() + delta_at).with_time_scale(TimeScale::UTC);
+/// assert_eq!(utc_midnight, Epoch::from_gregorian_utc_at_midnight(2017, 1, 1));
+/// assert_eq!(utc_midnight.previous_leap_second(), Some(utc_midnight));
+/// ```
+pub fn leap_seconds_table() -> &'static [(Epoch, f64, bool)] {
+    &LEAP_SECONDS_TABLE
+}
+
 /// List of leap seconds from https://www.ietf.org/timezones/data/leap-seconds.list .
 /// This list corresponds the number of seconds in TAI to the UTC offset and to whether it was an announced leap second or not.
 /// The unannoucned leap seconds come from dat.c in the SOFA library.
@@ -146,4 +314,190 @@ impl Index<usize> for LatestLeapSeconds {
     }
 }
 
-impl LeapSecondProvider for LatestLeapSeconds {}
+impl LeapSecondProvider for LatestLeapSeconds {
+    fn leap_seconds_at(&self, tai_seconds: f64, iers_only: bool) -> Option<f64> {
+        registered_leap_seconds_at(tai_seconds, iers_only)
+            .or_else(|| leap_seconds_in(&self.data, tai_seconds, iers_only))
+    }
+}
+
+/// The built-in leap seconds, looked up from the position of the previous lookup instead of from the end of the table.
+///
+/// When looking up nearby instants, e.g. when converting a sorted slice of epochs, the position only moves by a few
+/// entries between calls, so each lookup is amortized constant time. The results are identical to `LatestLeapSeconds`.
+pub(crate) struct SortedLeapSecondsCursor {
+    /// Number of built-in leap seconds at or before the instant of the previous lookup.
+    count: Cell<usize>,
+}
+
+impl SortedLeapSecondsCursor {
+    pub(crate) const fn new() -> Self {
+        Self {
+            count: Cell::new(0),
+        }
+    }
+}
+
+impl LeapSecondProvider for SortedLeapSecondsCursor {
+    fn leap_seconds_at(&self, tai_seconds: f64, iers_only: bool) -> Option<f64> {
+        if let Some(delta_at) = registered_leap_seconds_at(tai_seconds, iers_only) {
+            return Some(delta_at);
+        }
+
+        let mut count = self.count.get();
+        while count < LATEST_LEAP_SECONDS.len()
+            && LATEST_LEAP_SECONDS[count].timestamp_tai_s <= tai_seconds
+        {
+            count += 1;
+        }
+        while count > 0 && LATEST_LEAP_SECONDS[count - 1].timestamp_tai_s > tai_seconds {
+            count -= 1;
+        }
+        self.count.set(count);
+
+        leap_seconds_in(&LATEST_LEAP_SECONDS[..count], tai_seconds, iers_only)
+    }
+}
+
+/// Leap seconds registered at runtime, sorted in chronological order, all after the last built-in leap second.
+#[cfg(feature = "std")]
+static REGISTERED_LEAP_SECONDS: RwLock<Vec<LeapSecond>> = RwLock::new(Vec::new());
+
+/// Set once a leap second is registered, so that the conversions do not take the lock until then.
+#[cfg(feature = "std")]
+static HAS_REGISTERED_LEAP_SECONDS: AtomicBool = AtomicBool::new(false);
+
+/// Registers a leap second which is not yet part of the built-in table, e.g. one which the IERS just announced
+/// in Bulletin C, so that it is accounted for without waiting for a new release of hifitime.
+///
+/// The `boundary` is the first instant with the new `total_offset` (ΔAT, TAI - UTC, in seconds), i.e. the midnight
+/// following the 23:59:60 leap second. It may be provided in UTC or in TAI, e.g. both
+/// `Epoch::from_gregorian_utc_at_midnight(2025, 7, 1)` and `Epoch::from_gregorian_tai_at_midnight(2025, 7, 1)`
+/// refer to the leap second at the end of 2025 June 30.
+/// Registering another leap second at the same boundary replaces it.
+/// The `total_offset` may be lower than the previous one, for a negative leap second which removes the 23:59:59 second.
+///
+/// The registered leap seconds are consulted by `LatestLeapSeconds`, and therefore by all of the default UTC
+/// conversions of `Epoch`, but are not returned by its iterator nor by `leap_seconds_table`.
+///
+/// # Global state
+/// The registered leap seconds are shared by the whole process and cannot be unregistered.
+/// Registration is thread safe, but any UTC conversion running concurrently may or may not account for the new leap second,
+/// so register it before computing any UTC time after its boundary.
+///
+/// # Errors
+/// `Errors::LeapSecondOutOfOrder` if the boundary is not after the last built-in leap second.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, TimeUnits};
+///
+/// // A hypothetical leap second at the end of 2098.
+/// let boundary = Epoch::from_gregorian_utc_at_midnight(2099, 1, 1);
+/// hifitime::register_leap_second(boundary, 38.0, true).unwrap();
+///
+/// let before = Epoch::from_gregorian_utc_at_midnight(2098, 12, 31);
+/// let after = Epoch::from_gregorian_utc_at_midnight(2099, 1, 2);
+/// assert_eq!(before.leap_seconds_iers(), 37);
+/// assert_eq!(after.leap_seconds_iers(), 38);
+/// assert_eq!(after - before, 2.days() + 1.seconds());
+///
+/// // Leap seconds may only be appended to the built-in table.
+/// assert!(hifitime::register_leap_second(Epoch::from_gregorian_utc_at_midnight(2017, 1, 1), 37.0, true).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn register_leap_second(
+    boundary: Epoch,
+    total_offset: f64,
+    announced: bool,
+) -> Result<(), Errors> {
+    // Both a UTC and a TAI midnight boundary map to the midnight timestamp used by the built-in table.
+    let timestamp_tai_s = boundary
+        .to_duration_since_j1900_in_time_scale(boundary.time_scale)
+        .to_seconds();
+
+    if timestamp_tai_s <= LATEST_LEAP_SECONDS[LATEST_LEAP_SECONDS.len() - 1].timestamp_tai_s {
+        return Err(Errors::LeapSecondOutOfOrder);
+    }
+
+    let leap_second = LeapSecond::new(timestamp_tai_s, total_offset, announced);
+
+    let mut registered = REGISTERED_LEAP_SECONDS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    match registered.binary_search_by(|known| known.timestamp_tai_s.total_cmp(&timestamp_tai_s)) {
+        Ok(idx) => registered[idx] = leap_second,
+        Err(idx) => registered.insert(idx, leap_second),
+    }
+    HAS_REGISTERED_LEAP_SECONDS.store(true, Ordering::Release);
+
+    Ok(())
+}
+
+/// Returns the accumulated leap seconds from the leap seconds registered at runtime, if any applies.
+#[cfg(feature = "std")]
+fn registered_leap_seconds_at(tai_seconds: f64, iers_only: bool) -> Option<f64> {
+    if !HAS_REGISTERED_LEAP_SECONDS.load(Ordering::Acquire) {
+        return None;
+    }
+    let registered = REGISTERED_LEAP_SECONDS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    leap_seconds_in(&registered, tai_seconds, iers_only)
+}
+
+#[cfg(not(feature = "std"))]
+fn registered_leap_seconds_at(_tai_seconds: f64, _iers_only: bool) -> Option<f64> {
+    None
+}
+
+/// Reference dates (MJD) and drift rates (seconds per day) of UTC prior to 1972, from dat.c in the SOFA library.
+/// Each entry applies to the pre-1972 leap second with the same index in the built-in table.
+const SOFA_DRIFT_RATES: [(f64, f64); 14] = [
+    (37_300.0, 0.001_296),   // 01 Jan 1960
+    (37_300.0, 0.001_296),   // 01 Jan 1961
+    (37_300.0, 0.001_296),   // 01 Aug 1961
+    (37_665.0, 0.001_123_2), // 01 Jan 1962
+    (37_665.0, 0.001_123_2), // 01 Nov 1963
+    (38_761.0, 0.001_296),   // 01 Jan 1964
+    (38_761.0, 0.001_296),   // 01 Apr 1964
+    (38_761.0, 0.001_296),   // 01 Sep 1964
+    (38_761.0, 0.001_296),   // 01 Jan 1965
+    (38_761.0, 0.001_296),   // 01 Mar 1965
+    (38_761.0, 0.001_296),   // 01 Jul 1965
+    (38_761.0, 0.001_296),   // 01 Sep 1965
+    (39_126.0, 0.002_592),   // 01 Jan 1966
+    (39_126.0, 0.002_592),   // 01 Feb 1968
+];
+
+/// The leap seconds as computed by the `iauDat` function of SOFA, i.e. including the fractional offsets of UTC from 1960 to 1972
+/// **and** their linear drift (e.g. 3.85397 seconds on 1965 June 15 at midnight UTC).
+///
+/// Use this provider with the `_with` methods of `Epoch` to reproduce SOFA's UTC between 1960 and 1972, for example
+/// `Epoch::from_utc_duration_with(duration, SofaLeapSeconds)`. Since the offsets before 1972 are precisely what this provider is for,
+/// they are always included, even when only the IERS leap seconds are requested.
+/// From 1972 onward, this provider is identical to the built-in IERS leap seconds.
+///
+/// As with the other providers, the drift is evaluated at the provided time, which the conversions treat as UTC: the error due to
+/// the difference between UTC and TAI is below one nanosecond.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SofaLeapSeconds;
+
+impl LeapSecondProvider for SofaLeapSeconds {
+    fn leap_seconds_at(&self, tai_seconds: f64, _iers_only: bool) -> Option<f64> {
+        if let Some(delta_at) = registered_leap_seconds_at(tai_seconds, false) {
+            return Some(delta_at);
+        }
+        for (idx, leap_second) in LATEST_LEAP_SECONDS.iter().enumerate().rev() {
+            if tai_seconds >= leap_second.timestamp_tai_s {
+                if leap_second.announced_by_iers {
+                    return Some(leap_second.delta_at);
+                }
+                let (reference_mjd, rate_s_per_day) = SOFA_DRIFT_RATES[idx];
+                let mjd = tai_seconds / SECONDS_PER_DAY + J1900_OFFSET;
+                return Some(leap_second.delta_at + (mjd - reference_mjd) * rate_s_per_day);
+            }
+        }
+        None
+    }
+}
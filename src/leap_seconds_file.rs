@@ -13,10 +13,12 @@ use pyo3::prelude::*;
 
 use std::{fs::File, io::Read};
 
+use crate::Epoch;
+
 use core::ops::Index;
 
 use crate::{
-    leap_seconds::{LeapSecond, LeapSecondProvider},
+    leap_seconds::{leap_seconds_in, LeapSecond, LeapSecondProvider},
     Errors, ParsingErrors,
 };
 
@@ -27,25 +29,40 @@ use crate::{
 pub struct LeapSecondsFile {
     data: Vec<LeapSecond>,
     iter_pos: usize,
+    expiration: Option<Epoch>,
 }
 
 impl LeapSecondsFile {
     /// Builds a leap second provider from the provided Leap Seconds file in IERS format as found on <https://www.ietf.org/timezones/data/leap-seconds.list> .
     pub fn from_path(path: &str) -> Result<Self, Errors> {
-        let mut f = match File::open(path) {
-            Ok(f) => f,
-            Err(e) => return Err(Errors::ParseError(ParsingErrors::IOError(e.kind()))),
-        };
+        match File::open(path) {
+            Ok(f) => Self::from_reader(f),
+            Err(e) => Err(Errors::ParseError(ParsingErrors::IOError(e.kind()))),
+        }
+    }
 
+    /// Builds a leap second provider from any reader providing the contents of a Leap Seconds file in IERS format.
+    ///
+    /// This allows loading an updated `leap-seconds.list` at runtime, e.g. from a buffer downloaded by the application,
+    /// without waiting for a new release of hifitime.
+    /// The expiration date of the file (the `#@` line) is also parsed and available via `expiration`.
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, Errors> {
         let mut contents = String::new();
-        if let Err(e) = f.read_to_string(&mut contents) {
+        if let Err(e) = reader.read_to_string(&mut contents) {
             return Err(Errors::ParseError(ParsingErrors::IOError(e.kind())));
         }
 
         let mut me = Self::default();
 
         for line in contents.lines() {
-            if let Some(first_char) = line.chars().next() {
+            if let Some(expiration) = line.strip_prefix("#@") {
+                // The expiration is stored as the number of NTP seconds, i.e. UTC seconds since 1900 January 01 at midnight.
+                let ntp_seconds: u64 = match lexical_core::parse(expiration.trim().as_bytes()) {
+                    Ok(val) => val,
+                    Err(_) => return Err(Errors::ParseError(ParsingErrors::ValueError)),
+                };
+                me.expiration = Some(Epoch::from_utc_seconds(ntp_seconds as f64));
+            } else if let Some(first_char) = line.chars().next() {
                 if first_char == '#' {
                     continue;
                 } else {
@@ -76,6 +93,21 @@ impl LeapSecondsFile {
 
         Ok(me)
     }
+
+    /// Returns the expiration date of this leap seconds file, if it was specified in the file.
+    /// After this date, a new leap second may have been announced by the IERS and the file should be updated.
+    pub fn expiration(&self) -> Option<Epoch> {
+        self.expiration
+    }
+
+    /// Returns whether this leap seconds file has expired at the provided epoch.
+    /// Files without an expiration date never expire.
+    pub fn is_expired_at(&self, epoch: Epoch) -> bool {
+        match self.expiration {
+            Some(expiration) => epoch >= expiration,
+            None => false,
+        }
+    }
 }
 
 #[cfg(feature = "python")]
@@ -119,4 +151,8 @@ impl Index<usize> for LeapSecondsFile {
     }
 }
 
-impl LeapSecondProvider for LeapSecondsFile {}
+impl LeapSecondProvider for LeapSecondsFile {
+    fn leap_seconds_at(&self, tai_seconds: f64, iers_only: bool) -> Option<f64> {
+        leap_seconds_in(&self.data, tai_seconds, iers_only)
+    }
+}
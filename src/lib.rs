@@ -84,6 +84,36 @@ pub const J2000_REF_EPOCH_TDB: Epoch = Epoch {
     time_scale: TimeScale::ET,
 };
 
+/// Implements a binary operator for all of the reference combinations of its operands by delegating to the by-value implementation,
+/// e.g. `&a - &b`, `a - &b` and `&a - b` for `a - b`.
+macro_rules! forward_ref_binop {
+    (impl $imp:ident, $method:ident for $t:ty, $u:ty) => {
+        impl<'a> $imp<$u> for &'a $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, other: $u) -> Self::Output {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl<'b> $imp<&'b $u> for $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, other: &'b $u) -> Self::Output {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl<'a, 'b> $imp<&'b $u> for &'a $t {
+            type Output = <$t as $imp<$u>>::Output;
+
+            fn $method(self, other: &'b $u) -> Self::Output {
+                $imp::$method(*self, *other)
+            }
+        }
+    };
+}
+
 // Epoch formatting module is called `efmt` to avoid collision with `std::fmt` and `core::fmt`.
 pub mod efmt;
 mod parser;
@@ -113,22 +143,52 @@ pub use weekday::*;
 mod month;
 pub use month::*;
 
+mod gregorian;
+pub use gregorian::*;
+
+mod localtime;
+pub use localtime::*;
+
 pub mod leap_seconds;
+pub use leap_seconds::leap_seconds_for_year;
+#[cfg(feature = "std")]
+pub use leap_seconds::register_leap_second;
 
 #[cfg(feature = "std")]
 mod leap_seconds_file;
 
+mod clock;
+pub use clock::*;
+
+#[cfg(feature = "std")]
+mod stopwatch;
+#[cfg(feature = "std")]
+pub use stopwatch::*;
+
 #[cfg(feature = "ut1")]
 pub mod ut1;
 
 /// This module defines all of the deprecated methods.
 mod deprecated;
 
+/// Re-exports the commonly needed types and traits, so that a single glob import is enough for most uses.
+///
+/// This includes the `TimeUnits` and `Frequencies` traits, which provide the arithmetic on numbers, e.g. `1.days()` or `2.Hz()`.
+///
+/// ```
+/// use hifitime::prelude::*;
+///
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(2023, 1, 31);
+/// assert_eq!(epoch + 1.days() + 12 * Unit::Hour, Epoch::from_gregorian_utc_at_noon(2023, 2, 1));
+/// assert_eq!(epoch.month_name(), MonthName::January);
+/// assert_eq!(2.Hz(), 500.milliseconds());
+/// ```
 #[allow(deprecated)]
 pub mod prelude {
-    pub use crate::efmt::{Format, Formatter};
+    pub use crate::efmt::{EpochFormatter, Format, Formatter};
     pub use crate::{
-        deprecated::TimeSystem, Duration, Epoch, Errors, Freq, Frequencies, TimeScale, TimeSeries,
+        deprecated::TimeSystem, Duration, Epoch, Errors, Freq, Frequencies, Gregorian, LocalTime,
+        Month, MonthName, MonthlySeries, ParsingErrors, RoundMode, TimeScale, TimeSeries,
         TimeUnits, Unit, Weekday,
     };
 }
@@ -136,6 +196,11 @@ pub mod prelude {
 #[cfg(feature = "asn1der")]
 pub mod asn1der;
 
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rand")]
+pub use random::*;
+
 #[cfg(feature = "python")]
 pub mod python;
 
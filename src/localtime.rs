@@ -0,0 +1,90 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2022 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use crate::leap_seconds::LatestLeapSeconds;
+use crate::{Duration, Epoch};
+use core::fmt;
+
+/// An epoch associated with a fixed offset from UTC, e.g. UTC-05:00, as found in logs and in RFC3339 strings.
+///
+/// This only supports fixed offsets: there is no support for time zone databases or daylight saving time.
+/// The offset is positive east of Greenwich, i.e. the local time is the UTC time plus the offset.
+///
+/// ```
+/// use hifitime::{Epoch, TimeUnits};
+///
+/// let e = Epoch::from_gregorian_utc_hms(2017, 1, 14, 13, 15, 30);
+/// let local = e.to_local(-5.hours());
+/// assert_eq!(format!("{local}"), "2017-01-14T08:15:30-05:00");
+/// assert_eq!(local.to_epoch(), e);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LocalTime {
+    /// The instant of this local time
+    pub epoch: Epoch,
+    /// The offset of the local time with respect to UTC
+    pub offset: Duration,
+}
+
+impl LocalTime {
+    /// Creates a new local time from an epoch and a fixed offset from UTC.
+    pub const fn new(epoch: Epoch, offset: Duration) -> Self {
+        Self { epoch, offset }
+    }
+
+    /// Returns the epoch of this local time, which does not depend on the offset.
+    pub const fn to_epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Returns the local Gregorian date and time as (year, month, day, hour, minute, second, nanoseconds).
+    ///
+    /// If the epoch falls within an inserted leap second, the returned second is 60, as in `Epoch::to_gregorian_utc`.
+    pub fn to_gregorian(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
+        let (utc_duration, is_leap_second) = self
+            .epoch
+            .utc_duration_and_leap_second_with(&LatestLeapSeconds::default());
+        let (y, mm, dd, hh, min, s, nanos) = Epoch::compute_gregorian(utc_duration + self.offset);
+        if is_leap_second {
+            (y, mm, dd, hh, min, s + 1, nanos)
+        } else {
+            (y, mm, dd, hh, min, s, nanos)
+        }
+    }
+}
+
+impl fmt::Display for LocalTime {
+    /// Formats this local time in the RFC3339 format, e.g. `2017-01-14T08:15:30-05:00`.
+    /// If the offset is not a whole number of minutes, the offset seconds are also printed.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (y, mm, dd, hh, min, s, nanos) = self.to_gregorian();
+        if nanos == 0 {
+            write!(
+                f,
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                y, mm, dd, hh, min, s
+            )?;
+        } else {
+            write!(
+                f,
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}",
+                y, mm, dd, hh, min, s, nanos
+            )?;
+        }
+
+        let (sign, days, hours, minutes, seconds, _, _, _) = self.offset.decompose();
+        let sign = if sign < 0 { '-' } else { '+' };
+        write!(f, "{}{:02}:{:02}", sign, days * 24 + hours, minutes)?;
+        if seconds != 0 {
+            write!(f, ":{:02}", seconds)?;
+        }
+        Ok(())
+    }
+}
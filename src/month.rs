@@ -8,6 +8,7 @@
  * Documentation: https://nyxspace.com/
  */
 
+use crate::epoch::{is_leap_year, usual_days_per_month, CUMULATIVE_DAYS_FOR_MONTH};
 use crate::ParsingErrors;
 use core::fmt;
 use core::str::FromStr;
@@ -43,8 +44,71 @@ impl Default for MonthName {
     }
 }
 
+/// Alias of `MonthName`, which is the month of the year in the Gregorian calendar.
+pub type Month = MonthName;
+
 impl MonthName {
     const MAX: u8 = 12;
+
+    /// Returns the month from its number in the year, starting at 1 for January, or None if the number is not within 1 and 12.
+    pub const fn from_u8(month: u8) -> Option<Self> {
+        match month {
+            1 => Some(Self::January),
+            2 => Some(Self::February),
+            3 => Some(Self::March),
+            4 => Some(Self::April),
+            5 => Some(Self::May),
+            6 => Some(Self::June),
+            7 => Some(Self::July),
+            8 => Some(Self::August),
+            9 => Some(Self::September),
+            10 => Some(Self::October),
+            11 => Some(Self::November),
+            12 => Some(Self::December),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of this month in the year, starting at 1 for January.
+    pub const fn as_u8(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Returns the full English name of this month.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::January => "January",
+            Self::February => "February",
+            Self::March => "March",
+            Self::April => "April",
+            Self::May => "May",
+            Self::June => "June",
+            Self::July => "July",
+            Self::August => "August",
+            Self::September => "September",
+            Self::October => "October",
+            Self::November => "November",
+            Self::December => "December",
+        }
+    }
+
+    /// Returns the number of days in this month for the provided year, accounting for leap years.
+    pub const fn length(self, year: i32) -> u8 {
+        match self {
+            Self::February if is_leap_year(year) => 29,
+            _ => usual_days_per_month(self as u8),
+        }
+    }
+
+    /// Returns the number of days in the provided year before the first day of this month, accounting for leap years.
+    pub const fn days_since_year_start(self, year: i32) -> u16 {
+        let days = CUMULATIVE_DAYS_FOR_MONTH[self as usize];
+        if self as u8 > Self::February as u8 && is_leap_year(year) {
+            days + 1
+        } else {
+            days
+        }
+    }
 }
 
 impl FromStr for MonthName {
@@ -69,25 +133,21 @@ impl FromStr for MonthName {
 }
 
 impl From<u8> for MonthName {
+    /// Converts the month number, starting at 1 for January, into a month. Values greater than 12 wrap around.
     fn from(u: u8) -> Self {
-        match u.rem_euclid(Self::MAX) {
-            1 => Self::January,
-            2 => Self::February,
-            3 => Self::March,
-            4 => Self::April,
-            5 => Self::May,
-            6 => Self::June,
-            7 => Self::July,
-            8 => Self::August,
-            9 => Self::September,
-            10 => Self::October,
-            11 => Self::November,
-            12 => Self::December,
-            _ => Self::default(), // Defaults back to default for other values.
+        match u {
+            0 => Self::default(), // Defaults back to default for zero.
+            _ => Self::from_u8((u - 1).rem_euclid(Self::MAX) + 1).unwrap_or_default(),
         }
     }
 }
 
+impl From<MonthName> for u8 {
+    fn from(month: MonthName) -> Self {
+        month.as_u8()
+    }
+}
+
 impl fmt::Display for MonthName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{self:?}")
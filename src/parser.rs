@@ -43,36 +43,50 @@ impl Token {
         match &self {
             Self::Year => Ok(()), // No validation
             Self::Month => {
-                if !(0..=13).contains(&val) {
-                    Err(Errors::ParseError(ParsingErrors::ValueError))
+                if !(1..=12).contains(&val) {
+                    Err(Errors::ParseError(ParsingErrors::MonthOutOfRange))
                 } else {
                     Ok(())
                 }
             }
             Self::Day => {
-                if !(0..=31).contains(&val) {
-                    Err(Errors::ParseError(ParsingErrors::ValueError))
+                if !(1..=31).contains(&val) {
+                    Err(Errors::ParseError(ParsingErrors::DayOutOfRange))
                 } else {
                     Ok(())
                 }
             }
-            Self::Hour | Self::OffsetHours => {
+            Self::Hour => {
                 if !(0..=23).contains(&val) {
-                    Err(Errors::ParseError(ParsingErrors::ValueError))
+                    Err(Errors::ParseError(ParsingErrors::HourOutOfRange))
                 } else {
                     Ok(())
                 }
             }
-            Self::Minute | Self::OffsetMinutes => {
+            Self::Minute => {
                 if !(0..=59).contains(&val) {
-                    Err(Errors::ParseError(ParsingErrors::ValueError))
+                    Err(Errors::ParseError(ParsingErrors::MinuteOutOfRange))
                 } else {
                     Ok(())
                 }
             }
             Self::Second => {
                 if !(0..=60).contains(&val) {
-                    Err(Errors::ParseError(ParsingErrors::ValueError))
+                    Err(Errors::ParseError(ParsingErrors::SecondOutOfRange))
+                } else {
+                    Ok(())
+                }
+            }
+            Self::OffsetHours => {
+                if !(0..=23).contains(&val) {
+                    Err(Errors::ParseError(ParsingErrors::InvalidTimeZone))
+                } else {
+                    Ok(())
+                }
+            }
+            Self::OffsetMinutes => {
+                if !(0..=59).contains(&val) {
+                    Err(Errors::ParseError(ParsingErrors::InvalidTimeZone))
                 } else {
                     Ok(())
                 }
@@ -131,7 +145,9 @@ impl Token {
                     *self = Token::Month;
                     Ok(())
                 } else {
-                    Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+                    Err(Errors::ParseError(ParsingErrors::InvalidSeparator(
+                        ending_char,
+                    )))
                 }
             }
             Token::Month => {
@@ -139,7 +155,9 @@ impl Token {
                     *self = Token::Day;
                     Ok(())
                 } else {
-                    Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+                    Err(Errors::ParseError(ParsingErrors::InvalidSeparator(
+                        ending_char,
+                    )))
                 }
             }
             Token::Day => {
@@ -147,7 +165,9 @@ impl Token {
                     *self = Token::Hour;
                     Ok(())
                 } else {
-                    Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+                    Err(Errors::ParseError(ParsingErrors::InvalidSeparator(
+                        ending_char,
+                    )))
                 }
             }
             Token::Hour => {
@@ -155,7 +175,9 @@ impl Token {
                     *self = Token::Minute;
                     Ok(())
                 } else {
-                    Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+                    Err(Errors::ParseError(ParsingErrors::InvalidSeparator(
+                        ending_char,
+                    )))
                 }
             }
             Token::Minute => {
@@ -163,11 +185,14 @@ impl Token {
                     *self = Token::Second;
                     Ok(())
                 } else {
-                    Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+                    Err(Errors::ParseError(ParsingErrors::InvalidSeparator(
+                        ending_char,
+                    )))
                 }
             }
             Token::Second => {
-                if ending_char == '.' {
+                // ISO 8601 allows both the period and the comma as the decimal separator
+                if ending_char == '.' || ending_char == ',' {
                     *self = Token::Subsecond;
                 } else if ending_char == ' ' || ending_char == 'Z' {
                     // There are no subseconds here, only room for a time scale
@@ -176,7 +201,9 @@ impl Token {
                     // There are no subseconds here, but we're seeing the start of an offset
                     *self = Token::OffsetHours;
                 } else {
-                    return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+                    return Err(Errors::ParseError(ParsingErrors::InvalidSeparator(
+                        ending_char,
+                    )));
                 }
                 Ok(())
             }
@@ -188,7 +215,9 @@ impl Token {
                     // There are no subseconds here, but we're seeing the start of an offset
                     *self = Token::OffsetHours;
                 } else {
-                    return Err(Errors::ParseError(ParsingErrors::UnknownFormat));
+                    return Err(Errors::ParseError(ParsingErrors::InvalidSeparator(
+                        ending_char,
+                    )));
                 }
                 Ok(())
             }
@@ -197,7 +226,7 @@ impl Token {
                     *self = Token::OffsetMinutes;
                     Ok(())
                 } else {
-                    Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+                    Err(Errors::ParseError(ParsingErrors::InvalidTimeZone))
                 }
             }
             Token::OffsetMinutes => {
@@ -206,7 +235,7 @@ impl Token {
                     *self = Token::Timescale;
                     Ok(())
                 } else {
-                    Err(Errors::ParseError(ParsingErrors::UnknownFormat))
+                    Err(Errors::ParseError(ParsingErrors::InvalidTimeZone))
                 }
             }
             _ => Ok(()),
@@ -16,6 +16,8 @@ use crate::leap_seconds::{LatestLeapSeconds, LeapSecondsFile};
 
 use crate::ut1::Ut1Provider;
 
+use crate::MonthName;
+
 impl std::convert::From<Errors> for PyErr {
     fn from(err: Errors) -> PyErr {
         PyException::new_err(err.to_string())
@@ -29,6 +31,9 @@ fn hifitime(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<TimeSeries>()?;
     m.add_class::<Duration>()?;
     m.add_class::<Unit>()?;
+    m.add_class::<RoundMode>()?;
+    m.add_class::<Weekday>()?;
+    m.add_class::<MonthName>()?;
     m.add_class::<LatestLeapSeconds>()?;
     m.add_class::<LeapSecondsFile>()?;
     m.add_class::<Ut1Provider>()?;
@@ -0,0 +1,79 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2022 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::ops::Range;
+
+use rand::distributions::{Distribution, Uniform};
+use rand::Rng;
+
+use crate::{Duration, Epoch};
+
+/// A uniform distribution of epochs in the half-open interval `[start, end)`, with nanosecond resolution.
+///
+/// The sampled epochs are in the time scale of the start of the range.
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, TimeUnits, UniformEpoch};
+/// use rand::distributions::Distribution;
+/// use rand::rngs::mock::StepRng;
+///
+/// let start = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+/// let end = start + 1.days();
+/// let dist = UniformEpoch::new(start..end);
+///
+/// let epoch = dist.sample(&mut StepRng::new(0, 1));
+/// assert!(epoch >= start && epoch < end);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UniformEpoch {
+    start: Epoch,
+    start_ns: i128,
+    offset_ns: Uniform<i128>,
+}
+
+impl UniformEpoch {
+    /// Builds a new uniform distribution over the provided range of epochs.
+    ///
+    /// # Panics
+    /// Like `rand`'s own `Uniform`, this panics if the range is empty or reversed.
+    pub fn new(range: Range<Epoch>) -> Self {
+        // Work on the total nanoseconds directly: the difference of the extreme epochs does not fit in a Duration,
+        // but it always fits in an i128.
+        let start_ns = range.start.to_tai_duration().total_nanoseconds();
+        let end_ns = range.end.to_tai_duration().total_nanoseconds();
+        assert!(
+            start_ns < end_ns,
+            "UniformEpoch::new called with an empty or reversed range"
+        );
+
+        Self {
+            start: range.start,
+            start_ns,
+            offset_ns: Uniform::new(0, end_ns - start_ns),
+        }
+    }
+}
+
+impl From<Range<Epoch>> for UniformEpoch {
+    fn from(range: Range<Epoch>) -> Self {
+        Self::new(range)
+    }
+}
+
+impl Distribution<Epoch> for UniformEpoch {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Epoch {
+        let offset_ns = self.offset_ns.sample(rng);
+        Epoch {
+            duration_since_j1900_tai: Duration::from_total_nanoseconds(self.start_ns + offset_ns),
+            time_scale: self.start.time_scale,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2022 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use std::time::Instant;
+
+use crate::Duration;
+
+/// Measures elapsed time using the monotonic clock of the operating system.
+///
+/// Unlike `Epoch::now()`, which relies on the system wall clock and may jump backward (e.g. on NTP adjustments),
+/// a stop watch never goes backward. Use it to measure intervals, not to timestamp events.
+///
+/// # Example
+/// ```
+/// use hifitime::{Duration, StopWatch};
+///
+/// let sw = StopWatch::start();
+/// // ... some work ...
+/// let elapsed: Duration = sw.elapsed();
+/// assert!(elapsed >= Duration::ZERO);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StopWatch {
+    start: Instant,
+}
+
+impl StopWatch {
+    /// Starts a new stop watch.
+    #[must_use]
+    pub fn start() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns the duration elapsed since this stop watch was started.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed().into()
+    }
+
+    /// Returns the duration elapsed since this stop watch was started, and restarts it.
+    pub fn restart(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.start);
+        self.start = now;
+        elapsed.into()
+    }
+}
+
+impl Default for StopWatch {
+    fn default() -> Self {
+        Self::start()
+    }
+}
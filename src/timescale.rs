@@ -40,6 +40,24 @@ pub const SECONDS_GPS_TAI_OFFSET: f64 = 2_524_953_619.0;
 pub const SECONDS_GPS_TAI_OFFSET_I64: i64 = 2_524_953_619;
 pub const DAYS_GPS_TAI_OFFSET: f64 = SECONDS_GPS_TAI_OFFSET / SECONDS_PER_DAY;
 
+/// First rollover of the 10 bit GPS week number, at the start of GPS week 1024: 1999 August 22 at midnight GPST, i.e. 13 seconds before midnight UTC.
+pub const GPST_WEEK_ROLLOVER_1999: Epoch = Epoch::from_tai_duration(Duration {
+    centuries: 0,
+    nanoseconds: 3_144_268_819_000_000_000,
+});
+
+/// Second rollover of the 10 bit GPS week number, at the start of GPS week 2048: 2019 April 07 at midnight GPST, i.e. 18 seconds before midnight UTC.
+pub const GPST_WEEK_ROLLOVER_2019: Epoch = Epoch::from_tai_duration(Duration {
+    centuries: 1,
+    nanoseconds: 607_824_019_000_000_000,
+});
+
+/// Third rollover of the 10 bit GPS week number, at the start of GPS week 3072: 2038 November 21 at midnight GPST.
+pub const GPST_WEEK_ROLLOVER_2038: Epoch = Epoch::from_tai_duration(Duration {
+    centuries: 1,
+    nanoseconds: 1_227_139_219_000_000_000,
+});
+
 /// GST (Galileo) reference epoch is 13 seconds before 1999 August 21 UTC at midnight.
 pub const GST_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
     centuries: 0,
@@ -96,6 +114,19 @@ impl Arbitrary for TimeScale {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for TimeScale {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let ts_u8: u8 = u.int_in_range(0..=7)?;
+
+        Ok(Self::from(ts_u8))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u8 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
 impl Default for TimeScale {
     /// Builds default TAI time scale
     fn default() -> Self {
@@ -109,17 +140,22 @@ impl Default for TimeScale {
 }
 
 impl TimeScale {
-    pub(crate) const fn formatted_len(&self) -> usize {
-        match &self {
-            Self::GPST => 4,
-            Self::TAI | Self::TDB | Self::UTC | Self::GST | Self::BDT => 3,
-            Self::ET | Self::TT => 2,
+    /// Returns true if Self is based off a GNSS constellation
+    pub const fn is_gnss(&self) -> bool {
+        // Exhaustive on purpose so that new time scales must be classified.
+        match self {
+            Self::GPST | Self::GST | Self::BDT => true,
+            Self::TAI | Self::TT | Self::ET | Self::TDB | Self::UTC => false,
         }
     }
 
-    /// Returns true if Self is based off a GNSS constellation
-    pub const fn is_gnss(&self) -> bool {
-        matches!(self, Self::GPST | Self::GST | Self::BDT)
+    /// Returns true if Self is a dynamical time scale, i.e. Terrestrial Time or one of the barycentric time scales (TDB and ET).
+    pub const fn is_dynamical(&self) -> bool {
+        // Exhaustive on purpose so that new time scales must be classified.
+        match self {
+            Self::TT | Self::ET | Self::TDB => true,
+            Self::TAI | Self::UTC | Self::GPST | Self::GST | Self::BDT => false,
+        }
     }
 
     /// Returns Reference Epoch (t(0)) for given timescale
@@ -169,7 +205,13 @@ impl fmt::LowerHex for TimeScale {
 impl TimeScale {
     /// Returns true if self takes leap seconds into account
     pub const fn uses_leap_seconds(&self) -> bool {
-        matches!(self, Self::UTC)
+        // Exhaustive on purpose so that new time scales must be classified.
+        match self {
+            Self::UTC => true,
+            Self::TAI | Self::TT | Self::ET | Self::TDB | Self::GPST | Self::GST | Self::BDT => {
+                false
+            }
+        }
     }
 }
 
@@ -8,7 +8,8 @@
  * Documentation: https://nyxspace.com/
  */
 
-use super::{Duration, Epoch};
+use super::{Duration, Epoch, MonthName};
+use crate::epoch::is_gregorian_valid_with_registered;
 
 use core::fmt;
 
@@ -290,6 +291,81 @@ where
     }
 }
 
+/// An iterator of epochs stepping by calendar months, as returned by `Epoch::monthly`.
+///
+/// Each item is on the same day of the month and at the same time of day as the start, in the time scale of the start.
+/// When that day does not exist in a month, it is clamped to the last day of that month, but the day of the start is
+/// remembered: a series starting on January 31 continues on February 28 (or 29), March 31, April 30, and so on.
+/// Likewise, a start on a leap second is clamped to the previous second on the days without one.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MonthlySeries {
+    start: Epoch,
+    front: usize,
+    back: usize,
+}
+
+impl MonthlySeries {
+    /// Returns an iterator of `count` epochs, starting at `start` and stepping by one calendar month.
+    #[must_use]
+    pub const fn new(start: Epoch, count: usize) -> Self {
+        Self {
+            start,
+            front: 0,
+            back: count,
+        }
+    }
+
+    /// Returns the epoch the provided number of months after the start.
+    fn nth_month(&self, months: usize) -> Epoch {
+        let ts = self.start.time_scale;
+        let (y, mm, dd, hh, min, s, ns) = self.start.compute_gregorian_in(ts);
+        let months = i64::from(mm - 1) + months as i64;
+        let year = y + (months / 12) as i32;
+        let month = (months % 12) as u8 + 1;
+        let day = dd.min(MonthName::from(month).length(year));
+        let s = if is_gregorian_valid_with_registered(year, month, day, hh, min, s, ns) {
+            s
+        } else {
+            59
+        };
+        Epoch::from_gregorian(year, month, day, hh, min, s, ns, ts)
+    }
+}
+
+impl Iterator for MonthlySeries {
+    type Item = Epoch;
+
+    fn next(&mut self) -> Option<Epoch> {
+        if self.front >= self.back {
+            None
+        } else {
+            self.front += 1;
+            Some(self.nth_month(self.front - 1))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl DoubleEndedIterator for MonthlySeries {
+    fn next_back(&mut self) -> Option<Epoch> {
+        if self.front >= self.back {
+            None
+        } else {
+            self.back -= 1;
+            Some(self.nth_month(self.back))
+        }
+    }
+}
+
+impl ExactSizeIterator for MonthlySeries {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Epoch, TimeSeries, Unit};
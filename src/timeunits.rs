@@ -33,6 +33,8 @@ pub enum Unit {
     Minute,
     Hour,
     Day,
+    /// 7 days
+    Week,
     /// 36525 days, is the number of days per century in the Julian calendar
     Century,
 }
@@ -67,6 +69,9 @@ pub trait TimeUnits: Copy + Mul<Unit, Output = Duration> {
     fn centuries(self) -> Duration {
         self * Unit::Century
     }
+    fn weeks(self) -> Duration {
+        self * Unit::Week
+    }
     fn days(self) -> Duration {
         self * Unit::Day
     }
@@ -158,6 +163,7 @@ impl Unit {
     pub fn in_seconds(&self) -> f64 {
         match self {
             Unit::Century => DAYS_PER_CENTURY * SECONDS_PER_DAY,
+            Unit::Week => 7.0 * SECONDS_PER_DAY,
             Unit::Day => SECONDS_PER_DAY,
             Unit::Hour => SECONDS_PER_HOUR,
             Unit::Minute => SECONDS_PER_MINUTE,
@@ -190,7 +196,7 @@ impl Unit {
 }
 
 /// Allows conversion of a Unit into a u8 with the following mapping.
-/// 0: Second; 1: Nanosecond; 2: Microsecond; 3: Millisecond; 4: Minute; 5: Hour; 6: Day; 7: Century
+/// 0: Second; 1: Nanosecond; 2: Microsecond; 3: Millisecond; 4: Minute; 5: Hour; 6: Day; 7: Century; 8: Week
 impl From<Unit> for u8 {
     fn from(unit: Unit) -> Self {
         match unit {
@@ -201,6 +207,7 @@ impl From<Unit> for u8 {
             Unit::Hour => 5,
             Unit::Day => 6,
             Unit::Century => 7,
+            Unit::Week => 8,
             Unit::Second => 0,
         }
     }
@@ -223,6 +230,7 @@ impl From<u8> for Unit {
             5 => Unit::Hour,
             6 => Unit::Day,
             7 => Unit::Century,
+            8 => Unit::Week,
             _ => Unit::Second,
         }
     }
@@ -236,6 +244,7 @@ impl Mul<i64> for Unit {
     fn mul(self, q: i64) -> Duration {
         let factor = match self {
             Unit::Century => NANOSECONDS_PER_CENTURY as i64,
+            Unit::Week => 7 * NANOSECONDS_PER_DAY as i64,
             Unit::Day => NANOSECONDS_PER_DAY as i64,
             Unit::Hour => NANOSECONDS_PER_HOUR as i64,
             Unit::Minute => NANOSECONDS_PER_MINUTE as i64,
@@ -245,21 +254,12 @@ impl Mul<i64> for Unit {
             Unit::Nanosecond => 1,
         };
 
-        match q.checked_mul(factor) {
-            Some(total_ns) => {
-                if total_ns.abs() < i64::MAX {
-                    Duration::from_truncated_nanoseconds(total_ns)
-                } else {
-                    Duration::from_total_nanoseconds(i128::from(total_ns))
-                }
-            }
-            None => {
-                if q.is_negative() {
-                    Duration::MIN
-                } else {
-                    Duration::MAX
-                }
-            }
+        // The product of two i64 always fits on an i128, and `from_total_nanoseconds` saturates if needed.
+        let total_ns = i128::from(q) * i128::from(factor);
+        if total_ns.abs() < i128::from(i64::MAX) {
+            Duration::from_truncated_nanoseconds(total_ns as i64)
+        } else {
+            Duration::from_total_nanoseconds(total_ns)
         }
     }
 }
@@ -276,6 +276,7 @@ impl Mul<f64> for Unit {
     fn mul(self, q: f64) -> Duration {
         let factor = match self {
             Unit::Century => NANOSECONDS_PER_CENTURY as f64,
+            Unit::Week => (7 * NANOSECONDS_PER_DAY) as f64,
             Unit::Day => NANOSECONDS_PER_DAY as f64,
             Unit::Hour => NANOSECONDS_PER_HOUR as f64,
             Unit::Minute => NANOSECONDS_PER_MINUTE as f64,
@@ -306,8 +307,8 @@ fn test_unit_conversion() {
     for unit_u8 in 0..u8::MAX {
         let unit = Unit::from(unit_u8);
         let unit_u8_back: u8 = unit.into();
-        // If the u8 is greater than 8, it isn't valid and necessarily encoded as Second.
-        if unit_u8 < 8 {
+        // If the u8 is greater than 9, it isn't valid and necessarily encoded as Second.
+        if unit_u8 < 9 {
             assert_eq!(unit_u8_back, unit_u8, "got {unit_u8_back} want {unit_u8}");
         } else {
             assert_eq!(unit, Unit::Second);
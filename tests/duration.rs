@@ -29,6 +29,14 @@ fn time_unit() {
     assert_eq!(4.0 * Unit::Millisecond, Unit::Millisecond * 4);
     assert_eq!(5.0 * Unit::Nanosecond, Unit::Nanosecond * 5);
 
+    // Weeks
+    assert_eq!(Unit::Week * 1, Unit::Day * 7);
+    assert_eq!(Unit::Week * 1.5, Unit::Day * 10.5);
+    assert_eq!(3.weeks(), 21.days());
+    assert_eq!(-2 * Unit::Week, Unit::Day * -14);
+    assert!((Unit::Week.in_seconds() - 604_800.0).abs() < EPSILON);
+    assert!((3.weeks().to_unit(Unit::Week) - 3.0).abs() < EPSILON);
+
     let d: Duration = 1.0 * Unit::Hour / 3 - 20 * Unit::Minute;
     assert!(d.abs() < Unit::Nanosecond);
     assert_eq!(3 * (20 * Unit::Minute), Unit::Hour);
@@ -301,6 +309,34 @@ fn test_extremes() {
 
     let past_min = Duration::from_total_nanoseconds(i128::MIN);
     assert_eq!(past_min, Duration::MIN);
+    assert_eq!(
+        past_min.total_nanoseconds(),
+        Duration::MIN.total_nanoseconds()
+    );
+    assert_eq!(
+        Duration::from_total_nanoseconds(i128::MAX).total_nanoseconds(),
+        Duration::MAX.total_nanoseconds()
+    );
+
+    // The exact i64 minimum fits in a duration and round trips
+    let d = Duration::from_truncated_nanoseconds(i64::MIN);
+    assert_eq!(d.total_nanoseconds(), i128::from(i64::MIN));
+    assert_eq!(d.try_truncated_nanoseconds(), Ok(i64::MIN));
+    assert_eq!(d.truncated_nanoseconds(), i64::MIN);
+    let (sign, days, _, _, _, _, _, nanos) = d.decompose();
+    assert_eq!(sign, -1);
+    assert_eq!(days, 106_751);
+    assert_eq!(nanos, 808);
+    // Minus two centuries and a bit also round trips
+    for ns in [-2 * NANOSECONDS_PER_CENTURY as i64 + 5, i64::MIN + 1] {
+        assert_eq!(
+            Duration::from_truncated_nanoseconds(ns).try_truncated_nanoseconds(),
+            Ok(ns)
+        );
+    }
+    assert!(Duration::from_total_nanoseconds(i128::from(i64::MIN) - 1)
+        .try_truncated_nanoseconds()
+        .is_err());
 }
 
 #[test]
@@ -384,9 +420,10 @@ fn duration_floor_ceil_round() {
     assert_eq!(d.floor(1.seconds()), 4.minutes() + 13.seconds());
     assert_eq!(d.floor(3.seconds()), 4.minutes() + 12.seconds());
     assert_eq!(d.floor(9.minutes()), 0.minutes());
+    // This is already a multiple of ten seconds
     assert_eq!(
         (Duration::MIN + 10.seconds()).floor(10.seconds()),
-        Duration::MIN
+        Duration::MIN + 10.seconds()
     );
 
     // Ceil
@@ -403,6 +440,88 @@ fn duration_floor_ceil_round() {
     assert_eq!(d.round(1.seconds()), 4.minutes() + 14.seconds());
 }
 
+#[test]
+fn duration_floor_ceil_round_to_unit() {
+    let two_hours_three_min = 2.hours() + 3.minutes();
+    assert_eq!(two_hours_three_min.floor_to(Unit::Hour), 2.hours());
+    assert_eq!(two_hours_three_min.ceil_to(Unit::Hour), 3.hours());
+    assert_eq!(two_hours_three_min.round_to(Unit::Hour), 2.hours());
+    assert_eq!(two_hours_three_min.floor_to(Unit::Day), 0.days());
+    assert_eq!(two_hours_three_min.ceil_to(Unit::Day), 1.days());
+    assert_eq!(two_hours_three_min.round_to(Unit::Week), 0.days());
+    assert_eq!(
+        two_hours_three_min.floor_to(Unit::Second),
+        two_hours_three_min
+    );
+
+    for d in [
+        two_hours_three_min,
+        2.days() + 17.5.minutes(),
+        -(7.hours() + 59.minutes()),
+    ] {
+        for unit in [Unit::Second, Unit::Minute, Unit::Hour, Unit::Day] {
+            assert_eq!(d.floor_to(unit), d.floor(unit * 1));
+            assert_eq!(d.ceil_to(unit), d.ceil(unit * 1));
+            assert_eq!(d.round_to(unit), d.round(unit * 1));
+        }
+    }
+}
+
+#[test]
+fn duration_round_with_mode() {
+    use hifitime::RoundMode;
+
+    let hour = 1.hours();
+    // (duration, HalfUp, HalfDown, HalfEven, TowardZero, AwayFromZero), all in minutes
+    let cases: [(i64, [i64; 5]); 8] = [
+        (30, [60, 0, 0, 0, 60]),
+        (90, [120, 60, 120, 60, 120]),
+        (150, [180, 120, 120, 120, 180]),
+        (61, [60, 60, 60, 60, 120]),
+        (119, [120, 120, 120, 60, 120]),
+        (-30, [-60, 0, 0, 0, -60]),
+        (-90, [-120, -60, -120, -60, -120]),
+        (-61, [-60, -60, -60, -60, -120]),
+    ];
+    let modes = [
+        RoundMode::HalfUp,
+        RoundMode::HalfDown,
+        RoundMode::HalfEven,
+        RoundMode::TowardZero,
+        RoundMode::AwayFromZero,
+    ];
+    for (minutes, expected) in cases {
+        let d = minutes.minutes();
+        for (mode, want) in modes.iter().zip(expected) {
+            // Compare the total nanoseconds to check the sign as well
+            assert_eq!(
+                d.round_with(hour, *mode).total_nanoseconds(),
+                want.minutes().total_nanoseconds(),
+                "{d} with {mode:?}"
+            );
+            // The sign of the step is ignored
+            assert_eq!(
+                d.round_with(-hour, *mode).total_nanoseconds(),
+                want.minutes().total_nanoseconds(),
+            );
+        }
+    }
+
+    // Exact multiples and zero steps are unchanged
+    assert_eq!(
+        2.hours().round_with(hour, RoundMode::AwayFromZero),
+        2.hours()
+    );
+    assert_eq!(
+        17.5.minutes()
+            .round_with(Duration::ZERO, RoundMode::HalfEven),
+        17.5.minutes()
+    );
+    // HalfUp matches the default rounding for positive durations
+    let d = 2.hours() + 30.minutes();
+    assert_eq!(d.round_with(hour, RoundMode::HalfUp), d.round(hour));
+}
+
 #[test]
 fn duration_from_str() {
     use core::str::FromStr;
@@ -587,3 +706,712 @@ fn test_min_max() {
     assert_eq!(d1, d1.max(d0));
     assert_eq!(d1, d0.max(d1));
 }
+
+#[test]
+fn test_duration_components() {
+    let d = 1.hours() + 2.minutes() + 3.seconds() + 456.milliseconds() + 789.nanoseconds();
+    assert_eq!(d.whole_seconds(), 3723);
+    assert_eq!(d.subsec_nanoseconds(), 456_000_789);
+    assert_eq!(d.subsec_millis(), 456);
+
+    // Negative durations are truncated toward zero, and the sub-second part has the same sign
+    let neg = -d;
+    assert_eq!(neg.whole_seconds(), -3723);
+    assert_eq!(neg.subsec_nanoseconds(), -456_000_789);
+    assert_eq!(neg.subsec_millis(), -456);
+
+    // Round trip
+    for d in [
+        d,
+        neg,
+        Duration::ZERO,
+        Duration::EPSILON,
+        Duration::MIN_NEGATIVE,
+        -0.5.seconds(),
+        36_525.days() * 3 + 1.nanoseconds(),
+        Duration::MAX,
+        Duration::MIN,
+    ] {
+        assert_eq!(
+            Duration::from_total_nanoseconds(
+                i128::from(d.whole_seconds()) * 1_000_000_000 + i128::from(d.subsec_nanoseconds())
+            ),
+            d
+        );
+        assert!(d.subsec_nanoseconds().abs() < 1_000_000_000);
+    }
+
+    let d = 36.hours();
+    assert!((d.total_days() - 1.5).abs() < EPSILON);
+    assert!((d.total_hours() - 36.0).abs() < EPSILON);
+    assert!((d.total_minutes() - 2160.0).abs() < EPSILON);
+    assert!(((-d).total_days() + 1.5).abs() < EPSILON);
+}
+
+#[test]
+fn test_num_traits() {
+    use num_traits::{Bounded, Zero};
+
+    assert_eq!(num_traits::zero::<Duration>(), Duration::ZERO);
+    assert!(Duration::zero().is_zero());
+    assert!(!1.nanoseconds().is_zero());
+    assert!(!(-1).nanoseconds().is_zero());
+    // Generic accumulation
+    let total = [1.hours(), 30.minutes(), -15.minutes()]
+        .into_iter()
+        .fold(Duration::zero(), |acc, d| acc + d);
+    assert_eq!(total, 1.hours() + 15.minutes());
+
+    assert_eq!(Duration::min_value(), Duration::MIN);
+    assert_eq!(Duration::max_value(), Duration::MAX);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_stopwatch() {
+    use hifitime::StopWatch;
+
+    let mut sw = StopWatch::start();
+    let first = sw.elapsed();
+    assert!(first >= Duration::ZERO);
+    std::thread::sleep(std::time::Duration::from_millis(5));
+    let second = sw.elapsed();
+    assert!(second > first);
+    assert!(second >= 5.milliseconds());
+
+    let lap = sw.restart();
+    assert!(lap >= second);
+    assert!(sw.elapsed() < lap);
+}
+
+#[test]
+fn test_mul_f64_precision() {
+    use core::f64::consts::PI;
+
+    // Multipliers which do not have a finite decimal representation must terminate
+    assert_eq!(1.0 / 3.0 * Unit::Hour, 20.minutes());
+    assert_eq!(1.hours() * (1.0 / 3.0), 20.minutes());
+    assert_eq!(2.hours() * (2.0 / 3.0), 80.minutes());
+    assert_eq!(
+        (-1).hours() * (1.0 / 3.0),
+        -20.minutes(),
+        "negative durations"
+    );
+    assert_eq!(
+        ((-1).hours() * (1.0 / 3.0)).total_nanoseconds(),
+        -1_200_000_000_000
+    );
+    assert_eq!(
+        (1.seconds() * PI).total_nanoseconds(),
+        3_141_592_654,
+        "rounded to the nearest nanosecond"
+    );
+    assert_eq!((PI * Unit::Second).total_nanoseconds(), 3_141_592_653);
+    // Very small and very large multipliers
+    assert_eq!(Duration::MAX * 1e-300, Duration::ZERO);
+    assert_eq!((1.centuries() * 1e-15).total_nanoseconds(), 3_156);
+    assert_eq!(Duration::MAX * 1e300, Duration::MAX);
+    assert_eq!(Duration::MAX * -1e300, Duration::MIN);
+    assert_eq!(
+        (Duration::MAX * (1.0 / 3.0)).total_nanoseconds(),
+        Duration::MAX.total_nanoseconds() / 3
+    );
+    // Exact multipliers are unchanged
+    assert_eq!(1.hours() * 0.25, 15.minutes());
+    assert_eq!(1.days() * 1.5, 36.hours());
+
+    // Non-finite multipliers are deterministic
+    assert_eq!(1.hours() * f64::NAN, Duration::ZERO);
+    assert_eq!(1.hours() * f64::INFINITY, Duration::MAX);
+    assert_eq!(1.hours() * f64::NEG_INFINITY, Duration::MIN);
+    assert_eq!((-1).hours() * f64::INFINITY, Duration::MIN);
+    assert_eq!(Duration::ZERO * f64::INFINITY, Duration::ZERO);
+}
+
+#[test]
+fn test_from_time_of_day_str() {
+    assert_eq!(
+        Duration::from_time_of_day_str("00:31:55").unwrap(),
+        31.minutes() + 55.seconds()
+    );
+    assert_eq!(
+        Duration::from_time_of_day_str("12:00:00.5").unwrap(),
+        12.hours() + 500.milliseconds()
+    );
+    assert_eq!(
+        Duration::from_time_of_day_str("23:59:59,123456789").unwrap(),
+        1.days() - 1.seconds() + 123_456_789.nanoseconds()
+    );
+    // Truncation past the nanosecond
+    assert_eq!(
+        Duration::from_time_of_day_str("00:00:00.000000001999").unwrap(),
+        1.nanoseconds()
+    );
+    // Leap second
+    assert_eq!(
+        Duration::from_time_of_day_str("23:59:60").unwrap(),
+        1.days()
+    );
+    assert_eq!(
+        Duration::from_time_of_day_str("23:59:60.25").unwrap(),
+        1.days() + 250.milliseconds()
+    );
+
+    for invalid in [
+        "",
+        "12:00",
+        "24:00:00",
+        "12:60:00",
+        "12:00:61",
+        "1:00:00",
+        "12:00:00.",
+        "12:00:00.5a",
+        "-12:00:00",
+        "12:00:00:00",
+    ] {
+        assert_eq!(
+            Duration::from_time_of_day_str(invalid),
+            Err(Errors::ParseError(ParsingErrors::ISO8601)),
+            "{invalid}"
+        );
+    }
+}
+
+#[test]
+fn test_to_weeks_days_hours() {
+    assert_eq!(14.days().to_weeks(), 2.0);
+    assert_eq!(1.weeks().to_days(), 7.0);
+    assert_eq!(1.5.days().to_hours(), 36.0);
+    assert_eq!((-90).minutes().to_hours(), -1.5);
+    assert_eq!(Duration::ZERO.to_weeks(), 0.0);
+
+    let d = 3.weeks() + 2.days() + 5.hours();
+    assert_eq!(d.to_weeks(), d.to_unit(Unit::Week));
+    assert_eq!(d.to_days(), d.to_unit(Unit::Day));
+    assert_eq!(d.to_hours(), d.to_unit(Unit::Hour));
+}
+
+#[test]
+fn test_from_hms() {
+    assert_eq!(Duration::from_hms(1, 30, 0), 90.minutes());
+    assert_eq!(Duration::from_hms(0, 0, 0), Duration::ZERO);
+    assert_eq!(
+        Duration::from_hms(25, 61, 61),
+        1.days() + 2.hours() + 2.minutes() + 1.seconds()
+    );
+    assert_eq!(
+        Duration::from_hmsn(12, 34, 56, 789),
+        12.hours() + 34.minutes() + 56.seconds() + 789.nanoseconds()
+    );
+    assert_eq!(
+        Duration::from_dhms(2, 3, 4, 5),
+        2.days() + 3.hours() + 4.minutes() + 5.seconds()
+    );
+
+    // The sign of the leading non-zero component applies to the whole duration.
+    assert_eq!(
+        Duration::from_hms(-1, 30, 0).total_nanoseconds(),
+        (-90).minutes().total_nanoseconds()
+    );
+    assert_eq!(
+        Duration::from_hms(0, -30, 15).total_nanoseconds(),
+        (-(30.minutes() + 15.seconds())).total_nanoseconds()
+    );
+    assert_eq!(
+        Duration::from_hmsn(0, 0, 0, -1).total_nanoseconds(),
+        Duration::MIN_NEGATIVE.total_nanoseconds()
+    );
+    assert_eq!(
+        Duration::from_dhms(-1, 0, 0, 1).total_nanoseconds(),
+        (-(1.days() + 1.seconds())).total_nanoseconds()
+    );
+    assert_eq!(
+        Duration::from_hms(-1, -30, 0).total_nanoseconds(),
+        Duration::from_hms(-1, 30, 0).total_nanoseconds()
+    );
+
+    // Inverse of decompose, for durations of at least one day so that the sign is carried by the days.
+    for duration in [
+        Duration::from_hmsn(49, 59, 59, 999_999_999),
+        -Duration::from_hmsn(49, 59, 59, 999_999_999),
+        Duration::from_dhms(-1, 0, 0, 0),
+    ] {
+        let (sign, days, hours, minutes, seconds, ms, us, ns) = duration.decompose();
+        let sign = if sign < 0 { -1 } else { 1 };
+        let rebuilt = Duration::from_dhms(
+            sign * days as i64,
+            hours as i64,
+            minutes as i64,
+            seconds as i64,
+        ) + sign * ((ms * 1_000_000 + us * 1_000 + ns) as i64) * Unit::Nanosecond;
+        assert_eq!(rebuilt.total_nanoseconds(), duration.total_nanoseconds());
+    }
+}
+
+#[test]
+fn test_floor_ceil_round_negative() {
+    // Floor is toward negative infinity and ceil toward positive infinity, like f64.
+    let neg = -90.minutes();
+    assert_eq!(
+        neg.floor(1.hours()).total_nanoseconds(),
+        (-2.hours()).total_nanoseconds()
+    );
+    assert_eq!(
+        neg.ceil(1.hours()).total_nanoseconds(),
+        (-1.hours()).total_nanoseconds()
+    );
+    // Ties are broken toward positive infinity.
+    assert_eq!(
+        neg.round(1.hours()).total_nanoseconds(),
+        (-1.hours()).total_nanoseconds()
+    );
+    assert_eq!(
+        (-91).minutes().round(1.hours()).total_nanoseconds(),
+        (-2.hours()).total_nanoseconds()
+    );
+
+    let neg = -(2.hours() + 3.minutes() + 1.nanoseconds());
+    assert_eq!(
+        neg.floor(30.minutes()).total_nanoseconds(),
+        (-150.minutes()).total_nanoseconds()
+    );
+    assert_eq!(
+        neg.ceil(30.minutes()).total_nanoseconds(),
+        (-2.hours()).total_nanoseconds()
+    );
+    assert_eq!(
+        neg.floor(1.seconds()).total_nanoseconds(),
+        (-(2.hours() + 3.minutes() + 1.seconds())).total_nanoseconds()
+    );
+    // Flooring a small negative duration does not give zero.
+    assert_eq!(
+        (-1).nanoseconds().floor(1.days()).total_nanoseconds(),
+        (-1.days()).total_nanoseconds()
+    );
+    assert_eq!((-1).nanoseconds().ceil(1.days()), Duration::ZERO);
+
+    // Multiples are unchanged, whatever their sign and the sign of the step.
+    for d in [-2.hours(), Duration::ZERO, 3.hours()] {
+        assert_eq!(d.floor(1.hours()), d);
+        assert_eq!(d.ceil(1.hours()), d);
+        assert_eq!(d.round(1.hours()), d);
+        assert_eq!(d.floor(-1.hours()), d);
+    }
+    assert_eq!(90.minutes().floor(-1.hours()), 1.hours());
+    assert_eq!(90.minutes().ceil(-1.hours()), 2.hours());
+
+    // A step of zero returns the duration unchanged.
+    assert_eq!(neg.floor(Duration::ZERO), neg);
+    assert_eq!(neg.ceil(Duration::ZERO), neg);
+
+    // The results saturate instead of overflowing.
+    assert_eq!(Duration::MAX.ceil(7.days()), Duration::MAX);
+    assert_eq!(Duration::MIN.floor(7.days()), Duration::MIN);
+    for d in [Duration::MIN, Duration::MAX] {
+        let floored = d.floor(7.days()).total_nanoseconds();
+        let ceiled = d.ceil(7.days()).total_nanoseconds();
+        assert!(floored <= d.total_nanoseconds() && d.total_nanoseconds() <= ceiled);
+    }
+}
+
+#[test]
+fn test_saturating_add_mul() {
+    // Right at the century overflow boundary
+    assert_eq!(Duration::MAX.saturating_add(1.nanoseconds()), Duration::MAX);
+    assert_eq!(
+        Duration::from_parts(i16::MAX, 0).saturating_add(Duration::from_parts(1, 0)),
+        Duration::MAX
+    );
+    assert_eq!(
+        Duration::from_parts(i16::MAX - 1, NANOSECONDS_PER_CENTURY - 1)
+            .saturating_add(1.nanoseconds()),
+        Duration::from_parts(i16::MAX, 0)
+    );
+    assert_eq!(
+        Duration::MIN.saturating_add(-1.nanoseconds()),
+        Duration::MIN
+    );
+    assert_eq!(
+        Duration::from_parts(i16::MIN, 0).saturating_add(Duration::from_parts(-1, 0)),
+        Duration::MIN
+    );
+    assert_eq!(
+        Duration::MAX.saturating_add(Duration::MIN),
+        Duration::MAX + Duration::MIN
+    );
+    assert_eq!(1.days().saturating_add(-2.days()), -1.days());
+
+    assert_eq!(Duration::MAX.saturating_mul(2), Duration::MAX);
+    assert_eq!(Duration::MAX.saturating_mul(-2), Duration::MIN);
+    assert_eq!(Duration::MIN.saturating_mul(2), Duration::MIN);
+    assert_eq!(Duration::MIN.saturating_mul(-2), Duration::MAX);
+    assert_eq!(Duration::MAX.saturating_mul(1), Duration::MAX);
+    assert_eq!(
+        Duration::from_parts(1, 0).saturating_mul(i64::from(i16::MAX) + 1),
+        Duration::MAX
+    );
+    assert_eq!(
+        Duration::from_parts(1, 0).saturating_mul(i64::from(i16::MAX)),
+        Duration::from_parts(i16::MAX, 0)
+    );
+    assert_eq!(12.hours().saturating_mul(-3), -36.hours());
+    assert_eq!(Duration::MAX.saturating_mul(0), Duration::ZERO);
+}
+
+#[test]
+fn test_cmp_integer_seconds() {
+    // Positive
+    assert!(1.nanoseconds() > 0);
+    assert!(1.nanoseconds() >= 0);
+    assert!(1.nanoseconds() != 0);
+    assert!(1.5.seconds() > 1);
+    assert!(1.5.seconds() < 2);
+    assert!(2.seconds() == 2);
+    assert!(2.seconds() != -2);
+    assert!(1.days() == 86_400);
+    // Zero
+    assert!(Duration::ZERO == 0);
+    assert!(Duration::ZERO >= 0);
+    assert!(Duration::ZERO <= 0);
+    assert_eq!(
+        Duration::ZERO.partial_cmp(&0),
+        Some(core::cmp::Ordering::Equal)
+    );
+    // Negative
+    assert!((-1).nanoseconds() < 0);
+    assert!((-1).nanoseconds() != 0);
+    assert!((-2).seconds() == -2);
+    assert!((-2).seconds() != 2);
+    assert!((-1.5).seconds() < -1);
+    assert!((-1.5).seconds() > -2);
+    // Extremes
+    assert!(Duration::MAX > i32::MAX);
+    assert!(Duration::MIN < i32::MIN);
+}
+
+#[test]
+fn test_try_from_f64() {
+    // Within the safe range, this matches the infallible initialization.
+    for (value, unit) in [
+        (0.0, Unit::Second),
+        (1.5, Unit::Day),
+        (-1.5, Unit::Day),
+        (123.456_789_012, Unit::Second),
+        (104.0, Unit::Day),
+        (-104.0, Unit::Day),
+        (1e15, Unit::Nanosecond),
+    ] {
+        assert_eq!(
+            Duration::try_from_f64(value, unit),
+            Ok(Duration::from_f64(value, unit))
+        );
+    }
+    // The nanoseconds are exact up to 2^53 ns.
+    let max_exact = 2_f64.powi(53);
+    assert_eq!(
+        Duration::try_from_f64(max_exact, Unit::Nanosecond)
+            .unwrap()
+            .total_nanoseconds(),
+        1 << 53
+    );
+    // Beyond the safe range, i.e. about 104.25 days
+    for (value, unit) in [
+        (max_exact + 2.0, Unit::Nanosecond),
+        (-max_exact - 2.0, Unit::Nanosecond),
+        (105.0, Unit::Day),
+        (-105.0, Unit::Day),
+        (1.0, Unit::Century),
+        (1e300, Unit::Second),
+    ] {
+        assert_eq!(
+            Duration::try_from_f64(value, unit),
+            Err(Errors::PrecisionLoss),
+            "{value} {unit:?}"
+        );
+    }
+    // Non finite values
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(
+            Duration::try_from_f64(value, Unit::Second),
+            Err(Errors::Overflow)
+        );
+    }
+}
+
+#[test]
+fn test_from_integer_seconds() {
+    assert_eq!(Duration::from_integer_seconds(0), Duration::ZERO);
+    assert_eq!(Duration::from_integer_seconds(86_400), Unit::Day * 1);
+    assert_eq!(Duration::from_integer_seconds(86_400), 1.days());
+    assert_eq!(
+        Duration::from_integer_seconds(-86_400).total_nanoseconds(),
+        (-1.days()).total_nanoseconds()
+    );
+    // Exact for large integer second counts, where an f64 of nanoseconds is no longer exact
+    let seconds = 3_155_760_000_123; // About one thousand centuries
+    assert_eq!(
+        Duration::from_integer_seconds(seconds).total_nanoseconds(),
+        i128::from(seconds) * 1_000_000_000
+    );
+    assert_eq!(
+        Duration::from_integer_seconds(seconds),
+        Unit::Second * seconds
+    );
+    let seconds = 100_000_000_000_001; // Close to the largest durations
+    assert_eq!(
+        Duration::from_integer_seconds(-seconds).total_nanoseconds(),
+        -i128::from(seconds) * 1_000_000_000
+    );
+    // Saturates beyond the representable durations
+    assert_eq!(Duration::from_integer_seconds(i64::MAX), Duration::MAX);
+    assert_eq!(Duration::from_integer_seconds(i64::MIN), Duration::MIN);
+}
+
+#[test]
+fn test_is_normalized() {
+    for duration in [
+        Duration::ZERO,
+        Duration::MAX,
+        Duration::MIN,
+        Duration::MIN_POSITIVE,
+        Duration::MIN_NEGATIVE,
+        1.5.days(),
+        -1.5.days(),
+        Duration::from_total_nanoseconds(i128::MAX),
+    ] {
+        assert!(duration.is_normalized(), "{duration:?}");
+    }
+
+    // Building from unnormalized parts normalizes them.
+    for (centuries, nanoseconds, expected) in [
+        (0, NANOSECONDS_PER_CENTURY, (1, 0)),
+        (0, 2 * NANOSECONDS_PER_CENTURY + 5, (2, 5)),
+        (-1, NANOSECONDS_PER_CENTURY + 1, (0, 1)),
+        (-3, u64::MAX, (2, u64::MAX % NANOSECONDS_PER_CENTURY)),
+        (
+            i16::MAX,
+            2 * NANOSECONDS_PER_CENTURY,
+            Duration::MAX.to_parts(),
+        ),
+    ] {
+        let duration = Duration::from_parts(centuries, nanoseconds);
+        assert!(duration.is_normalized(), "{centuries} {nanoseconds}");
+        assert_eq!(duration.to_parts(), expected, "{centuries} {nanoseconds}");
+        // The parts round trip
+        let (centuries, nanoseconds) = duration.to_parts();
+        assert_eq!(Duration::from_parts(centuries, nanoseconds), duration);
+    }
+}
+
+#[test]
+fn test_scientific() {
+    // Multi-century propagation span
+    let span = 3.centuries() + 12.days();
+    assert_eq!(
+        format!("{:.6}", span.scientific(Unit::Century)),
+        "3.000329e0 centuries"
+    );
+    assert_eq!(
+        format!("{:.3e}", span.scientific(Unit::Day)),
+        "1.096e5 days"
+    );
+    assert_eq!(format!("{:.2}", span.scientific(Unit::Second)), "9.47e9 s");
+    assert_eq!(
+        format!("{:.3}", (-span).scientific(Unit::Day)),
+        "-1.096e5 days"
+    );
+    assert_eq!(
+        format!("{:.1}", Duration::MAX.scientific(Unit::Nanosecond)),
+        "1.0e23 ns"
+    );
+
+    // Sub-nanosecond inputs are rounded to the nanosecond when building the duration.
+    let tiny = Duration::from_f64(1.4e-9, Unit::Second);
+    assert_eq!(format!("{}", tiny.scientific(Unit::Second)), "1e-9 s");
+    assert_eq!(
+        format!("{:.3}", tiny.scientific(Unit::Nanosecond)),
+        "1.000e0 ns"
+    );
+    let zero = Duration::from_f64(0.4, Unit::Nanosecond);
+    assert_eq!(format!("{}", zero.scientific(Unit::Millisecond)), "0e0 ms");
+
+    // The default Display is unchanged
+    assert_eq!(format!("{span}"), "109587 days");
+}
+
+#[test]
+fn test_mul_rational() {
+    let third = 1.hours().mul_rational(1, 3);
+    assert_eq!(third.total_nanoseconds(), 1_200_000_000_000);
+    assert_eq!(third, 20.minutes());
+    assert_eq!(third * 3, 1.hours());
+
+    // Sevenths of a day, rounded to the nearest nanosecond
+    let seventh = 1.days().mul_rational(1, 7);
+    assert_eq!(seventh.total_nanoseconds(), 12_342_857_142_857);
+    assert_eq!(1.weeks().mul_rational(1, 7), 1.days());
+
+    // Rounding to the nearest, ties away from zero, with any signs
+    assert_eq!(5.nanoseconds().mul_rational(1, 2).total_nanoseconds(), 3);
+    assert_eq!(
+        (-5).nanoseconds().mul_rational(1, 2).total_nanoseconds(),
+        -3
+    );
+    assert_eq!(5.nanoseconds().mul_rational(-1, 2).total_nanoseconds(), -3);
+    assert_eq!(5.nanoseconds().mul_rational(1, -2).total_nanoseconds(), -3);
+    assert_eq!(
+        (-5).nanoseconds().mul_rational(-1, -2).total_nanoseconds(),
+        -3
+    );
+    assert_eq!(4.nanoseconds().mul_rational(1, 3).total_nanoseconds(), 1);
+    assert_eq!(5.nanoseconds().mul_rational(1, 3).total_nanoseconds(), 2);
+
+    // Saturates, including with extreme numerators and denominators
+    assert_eq!(Duration::MAX.mul_rational(3, 2), Duration::MAX);
+    assert_eq!(Duration::MAX.mul_rational(i64::MAX, 1), Duration::MAX);
+    assert_eq!(Duration::MAX.mul_rational(i64::MIN, 1), Duration::MIN);
+    assert_eq!(
+        Duration::MAX
+            .mul_rational(i64::MAX, i64::MAX)
+            .total_nanoseconds(),
+        Duration::MAX.total_nanoseconds()
+    );
+    assert_eq!(1.seconds().mul_rational(1, i64::MIN), Duration::ZERO);
+    assert_eq!(Duration::ZERO.mul_rational(i64::MIN, 7), Duration::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "zero denominator")]
+fn test_mul_rational_zero_den() {
+    let _ = 1.hours().mul_rational(1, 0);
+}
+
+#[test]
+fn test_split_at_unit() {
+    let d = 1.hours() + 23.minutes() + 45.seconds();
+    let (hours, rem) = d.split_at_unit(Unit::Hour);
+    assert_eq!(hours, 1.hours());
+    assert_eq!(rem, 23.minutes() + 45.seconds());
+
+    let (minutes, rem) = d.split_at_unit(Unit::Minute);
+    assert_eq!(minutes, 83.minutes());
+    assert_eq!(rem, 45.seconds());
+
+    // Exact in nanoseconds, and the parts always sum back to the original duration
+    let d = 3.centuries() + 12.days() + 1.nanoseconds();
+    for unit in [
+        Unit::Century,
+        Unit::Week,
+        Unit::Day,
+        Unit::Hour,
+        Unit::Minute,
+        Unit::Second,
+        Unit::Millisecond,
+        Unit::Microsecond,
+        Unit::Nanosecond,
+    ] {
+        for d in [d, -d, Duration::ZERO, 999.milliseconds()] {
+            let (floored, rem) = d.split_at_unit(unit);
+            assert_eq!(
+                (floored + rem).total_nanoseconds(),
+                d.total_nanoseconds(),
+                "{d} {unit:?}"
+            );
+            assert_eq!(floored, d.floor_to(unit));
+            assert!(rem >= Duration::ZERO && rem < unit * 1);
+        }
+    }
+    assert_eq!(
+        d.split_at_unit(Unit::Second),
+        (3.centuries() + 12.days(), 1.nanoseconds())
+    );
+
+    // Negative durations are floored away from zero, with a positive remainder
+    assert_eq!(
+        (-90).minutes().split_at_unit(Unit::Hour),
+        (-2.hours(), 30.minutes())
+    );
+}
+
+#[test]
+fn test_total_nanoseconds_negative_centuries() {
+    // The nanoseconds are counted forward from the start of the century, even below minus one century.
+    let npc = i128::from(NANOSECONDS_PER_CENTURY);
+    assert_eq!(Duration::from_parts(-1, 1).total_nanoseconds(), -npc + 1);
+    assert_eq!(
+        Duration::from_parts(-2, 1).total_nanoseconds(),
+        -2 * npc + 1
+    );
+    assert_eq!(
+        Duration::from_parts(-5, NANOSECONDS_PER_CENTURY / 2).total_nanoseconds(),
+        -9 * npc / 2
+    );
+    assert_eq!(
+        Duration::MIN.total_nanoseconds(),
+        i128::from(i16::MIN) * npc
+    );
+    for total_ns in [
+        -npc - 1,
+        -3 * npc / 2,
+        -7 * npc + 123,
+        i128::from(i16::MIN) * npc + 1,
+    ] {
+        assert_eq!(
+            Duration::from_total_nanoseconds(total_ns).total_nanoseconds(),
+            total_ns
+        );
+    }
+    // Which keeps the arithmetic consistent below minus one century
+    assert_eq!(
+        (Duration::from_parts(-2, 0) + 1.nanoseconds()).total_nanoseconds(),
+        -2 * npc + 1
+    );
+    assert_eq!(
+        (Duration::MIN + 10.seconds()).floor(10.seconds()),
+        Duration::MIN + 10.seconds()
+    );
+}
+
+#[test]
+fn test_unit_mul_large_i64() {
+    // The product overflows an i64 of nanoseconds, but fits in a Duration.
+    let days: i64 = 200_000;
+    let expected = i128::from(days) * 86_400_000_000_000;
+    assert!(expected > i128::from(i64::MAX));
+    assert_eq!((Unit::Day * days).total_nanoseconds(), expected);
+    assert_eq!((days * Unit::Day).total_nanoseconds(), expected);
+    assert_eq!(days.days().total_nanoseconds(), expected);
+    assert_eq!((Unit::Day * -days).total_nanoseconds(), -expected);
+    assert_eq!(
+        (Unit::Century * 1_000).total_nanoseconds(),
+        1_000 * i128::from(NANOSECONDS_PER_CENTURY)
+    );
+
+    // Only the products which do not fit in a Duration saturate.
+    assert_eq!(Unit::Century * i64::MAX, Duration::MAX);
+    assert_eq!(Unit::Century * i64::MIN, Duration::MIN);
+    assert_eq!(Unit::Second * i64::MAX, Duration::MAX);
+    assert_eq!(
+        Unit::Nanosecond * i64::MIN,
+        Duration::from_truncated_nanoseconds(i64::MIN)
+    );
+}
+
+#[test]
+fn test_hash_zero_crossing() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let hash = |duration: Duration| {
+        let mut hasher = DefaultHasher::new();
+        duration.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    // Equal durations must have the same hash, including opposite durations around the zero crossing.
+    for duration in [1.nanoseconds(), 15.minutes(), 36.hours()] {
+        assert_eq!(-duration, duration);
+        assert_eq!(hash(-duration), hash(duration));
+    }
+    assert_eq!(hash(Duration::MIN_NEGATIVE), hash(Duration::MIN_POSITIVE));
+}
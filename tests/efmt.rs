@@ -86,3 +86,247 @@ fn epoch_format_rfc2822() {
         "Sat, 07 Feb 2015 11:22:32"
     );
 }
+
+#[test]
+fn epoch_formatter_builder() {
+    let e = Epoch::from_gregorian_utc(2023, 3, 14, 15, 9, 26, 535_897_932);
+
+    // By default, this matches the Display of the epoch.
+    assert_eq!(
+        format!("{}", EpochFormatter::new(TimeScale::UTC).format(e)),
+        format!("{e}")
+    );
+    assert_eq!(
+        format!("{}", EpochFormatter::new(TimeScale::TAI).format(e)),
+        format!("{e:x}")
+    );
+
+    let fmt = EpochFormatter::new(TimeScale::UTC);
+    assert_eq!(
+        format!("{}", fmt.decimals(0).format(e)),
+        "2023-03-14T15:09:27 UTC"
+    );
+    assert_eq!(
+        format!("{}", fmt.decimals(3).format(e)),
+        "2023-03-14T15:09:26.536 UTC"
+    );
+    assert_eq!(
+        format!("{}", fmt.decimals(9).format(e)),
+        "2023-03-14T15:09:26.535897932 UTC"
+    );
+    // More than nine decimals are printed as nine
+    assert_eq!(
+        format!("{}", fmt.decimals(12).format(e)),
+        "2023-03-14T15:09:26.535897932 UTC"
+    );
+    // The decimals are always printed once requested
+    let whole = Epoch::from_gregorian_utc_hms(2023, 3, 14, 15, 9, 26);
+    assert_eq!(
+        format!("{}", fmt.decimals(3).format(whole)),
+        "2023-03-14T15:09:26.000 UTC"
+    );
+    assert_eq!(
+        format!("{}", fmt.decimals(0).format(whole)),
+        "2023-03-14T15:09:26 UTC"
+    );
+
+    // Other options
+    let custom = fmt.decimals(3).date_sep('/').append_scale(false);
+    assert_eq!(format!("{}", custom.format(e)), "2023/03/14T15:09:26.536");
+    // In other time scales, this matches the Debug representation of the epoch in that time scale.
+    let gpst = Epoch::from_gregorian_utc(2023, 3, 14, 15, 9, 26, 536_000_000)
+        .with_time_scale(TimeScale::GPST);
+    assert_eq!(
+        format!("{}", EpochFormatter::new(TimeScale::GPST).format(gpst)),
+        format!("{gpst:?}")
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            EpochFormatter::new(TimeScale::GPST)
+                .decimals(3)
+                .format(gpst)
+        ),
+        format!("{} GPST", &format!("{gpst:?}")[..23])
+    );
+
+    // Rounding up carries over to the next second, up to the next year.
+    let end_of_year = Epoch::from_gregorian_utc(2023, 12, 31, 23, 59, 59, 999_600_000);
+    assert_eq!(
+        format!("{}", fmt.decimals(3).format(end_of_year)),
+        "2024-01-01T00:00:00.000 UTC"
+    );
+    assert_eq!(
+        format!("{}", fmt.decimals(4).format(end_of_year)),
+        "2023-12-31T23:59:59.9996 UTC"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            fmt.decimals(0).format(end_of_year - 100.milliseconds())
+        ),
+        "2024-01-01T00:00:00 UTC"
+    );
+    let feb = Epoch::from_gregorian_utc(2024, 2, 28, 23, 59, 59, 999_999_999);
+    assert_eq!(
+        format!("{}", fmt.decimals(0).format(feb)),
+        "2024-02-29T00:00:00 UTC"
+    );
+
+    // Or to the leap second, in UTC only.
+    let before_leap = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 999_999_000);
+    assert_eq!(
+        format!("{}", fmt.decimals(3).format(before_leap)),
+        "2016-12-31T23:59:60.000 UTC"
+    );
+    let in_leap = Epoch::from_tai_duration(before_leap.to_tai_duration() + 1.seconds());
+    assert_eq!(
+        format!("{}", fmt.decimals(9).format(in_leap)),
+        "2016-12-31T23:59:60.999999000 UTC"
+    );
+    assert_eq!(
+        format!("{}", fmt.decimals(3).format(in_leap)),
+        "2017-01-01T00:00:00.000 UTC"
+    );
+    let tai = Epoch::from_gregorian_tai(2016, 12, 31, 23, 59, 59, 999_999_000);
+    assert_eq!(
+        format!(
+            "{}",
+            EpochFormatter::new(TimeScale::TAI).decimals(3).format(tai)
+        ),
+        "2017-01-01T00:00:00.000 TAI"
+    );
+}
+
+#[test]
+fn epoch_formatter_trim_zeros() {
+    let e = Epoch::from_gregorian_utc(2023, 1, 14, 0, 31, 55, 811_000_000);
+    let fmt = EpochFormatter::new(TimeScale::UTC);
+    let trimmed = fmt.trim_zeros(true);
+
+    assert_eq!(
+        format!("{}", fmt.format(e)),
+        "2023-01-14T00:31:55.811000000 UTC"
+    );
+    assert_eq!(
+        format!("{}", trimmed.format(e)),
+        "2023-01-14T00:31:55.811 UTC"
+    );
+    // This round trips through the parser
+    assert_eq!(
+        format!("{}", trimmed.format(e)).parse::<Epoch>().unwrap(),
+        e
+    );
+
+    // Trimming applies after rounding, and removes the fractional seconds if they are zero.
+    assert_eq!(
+        format!("{}", trimmed.decimals(2).format(e)),
+        "2023-01-14T00:31:55.81 UTC"
+    );
+    assert_eq!(
+        format!("{}", trimmed.decimals(1).format(e)),
+        "2023-01-14T00:31:55.8 UTC"
+    );
+    let whole = Epoch::from_gregorian_utc_hms(2023, 1, 14, 0, 31, 55);
+    assert_eq!(
+        format!("{}", trimmed.decimals(3).format(whole)),
+        "2023-01-14T00:31:55 UTC"
+    );
+    assert_eq!(
+        format!("{}", trimmed.decimals(2).format(whole + 996.milliseconds())),
+        "2023-01-14T00:31:56 UTC"
+    );
+    let odd = Epoch::from_gregorian_utc(2023, 1, 14, 0, 31, 55, 1);
+    assert_eq!(
+        format!("{}", trimmed.format(odd)),
+        "2023-01-14T00:31:55.000000001 UTC"
+    );
+}
+
+#[test]
+fn epoch_parse_with_offset() {
+    use core::str::FromStr;
+
+    // Offsets must shift the parsed time to UTC, for both the Format parser and from_gregorian_str.
+    for (input, expected) in [
+        (
+            "2023-01-01T12:00:00+10:00",
+            Epoch::from_gregorian_utc_hms(2023, 1, 1, 2, 0, 0),
+        ),
+        (
+            "2023-01-01T12:00:00-05:00",
+            Epoch::from_gregorian_utc_hms(2023, 1, 1, 17, 0, 0),
+        ),
+        (
+            "2023-01-01T12:00:00+01:30",
+            Epoch::from_gregorian_utc_hms(2023, 1, 1, 10, 30, 0),
+        ),
+        (
+            "2023-01-01T00:15:00-00:30",
+            Epoch::from_gregorian_utc_hms(2023, 1, 1, 0, 45, 0),
+        ),
+        (
+            "2023-01-01T01:00:00+02:00",
+            Epoch::from_gregorian_utc_hms(2022, 12, 31, 23, 0, 0),
+        ),
+    ] {
+        assert_eq!(
+            Format::from_str("%Y-%m-%dT%H:%M:%S%z")
+                .unwrap()
+                .parse(input)
+                .unwrap(),
+            expected,
+            "%z {input}"
+        );
+        assert_eq!(
+            Epoch::from_gregorian_str(input).unwrap(),
+            expected,
+            "from_gregorian_str {input}"
+        );
+        assert_eq!(
+            Epoch::from_str(input).unwrap(),
+            expected,
+            "from_str {input}"
+        );
+
+        // The RFC3339 formats expect the subseconds before the offset
+        let input = input.replace(":00+", ":00.5+").replace(":00-", ":00.5-");
+        let expected = expected + Unit::Millisecond * 500;
+        assert_eq!(RFC3339.parse(&input).unwrap(), expected, "RFC3339 {input}");
+        assert_eq!(
+            RFC3339_FLEX.parse(&input).unwrap(),
+            expected,
+            "RFC3339_FLEX {input}"
+        );
+        assert_eq!(
+            Epoch::from_gregorian_str(&input).unwrap(),
+            expected,
+            "from_gregorian_str {input}"
+        );
+    }
+
+    // Offsets without the colon separator, or without minutes
+    let fmt = Format::from_str("%Y-%m-%dT%H:%M:%S%z").unwrap();
+    assert_eq!(
+        fmt.parse("2023-01-01T12:00:00+0130").unwrap(),
+        Epoch::from_gregorian_utc_hms(2023, 1, 1, 10, 30, 0)
+    );
+    assert_eq!(
+        fmt.parse("2023-01-01T12:00:00-03").unwrap(),
+        Epoch::from_gregorian_utc_hms(2023, 1, 1, 15, 0, 0)
+    );
+
+    // Invalid offsets are rejected instead of being ignored
+    for input in [
+        "2023-01-01T12:00:00+24:00",
+        "2023-01-01T12:00:00+10:60",
+        "2023-01-01T12:00:00+1",
+        "2023-01-01T12:00:00+",
+    ] {
+        assert_eq!(
+            fmt.parse(input),
+            Err(Errors::ParseError(ParsingErrors::InvalidTimeZone)),
+            "{input}"
+        );
+    }
+}
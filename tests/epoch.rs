@@ -2,10 +2,11 @@
 extern crate core;
 
 use hifitime::{
-    is_gregorian_valid, Duration, Epoch, Errors, ParsingErrors, TimeScale, TimeUnits, Unit,
-    Weekday, BDT_REF_EPOCH, DAYS_GPS_TAI_OFFSET, GPST_REF_EPOCH, GST_REF_EPOCH, J1900_OFFSET,
-    J1900_REF_EPOCH, J2000_OFFSET, MJD_OFFSET, SECONDS_BDT_TAI_OFFSET, SECONDS_GPS_TAI_OFFSET,
-    SECONDS_GST_TAI_OFFSET, SECONDS_PER_DAY,
+    is_gregorian_valid, Clock, Duration, Epoch, Errors, FixedClock, ParsingErrors, TimeScale,
+    TimeUnits, Unit, Weekday, BDT_REF_EPOCH, DAYS_GPS_TAI_OFFSET, GPST_REF_EPOCH,
+    GPST_WEEK_ROLLOVER_1999, GPST_WEEK_ROLLOVER_2019, GPST_WEEK_ROLLOVER_2038, GST_REF_EPOCH,
+    J1900_OFFSET, J1900_REF_EPOCH, J2000_OFFSET, MJD_OFFSET, SECONDS_BDT_TAI_OFFSET,
+    SECONDS_GPS_TAI_OFFSET, SECONDS_GST_TAI_OFFSET, SECONDS_PER_DAY,
 };
 
 use hifitime::efmt::{Format, Formatter};
@@ -330,6 +331,54 @@ fn datetime_invalid_dates() {
     assert!(!is_gregorian_valid(2015, 6, 30, 23, 59, 61, 0));
 }
 
+#[test]
+fn datetime_leap_second_validity() {
+    use hifitime::leap_seconds::leap_seconds_table;
+
+    // Only the days ending with a leap second announced by the IERS may have a 60th second.
+    let mut leap_second_days = 0;
+    for (boundary, _, announced) in leap_seconds_table() {
+        let (y, m, d, _, _, _, _) = (*boundary - 1.days()).to_gregorian_tai();
+        assert_eq!(
+            is_gregorian_valid(y, m, d, 23, 59, 60, 0),
+            *announced,
+            "{y}-{m:02}-{d:02}"
+        );
+        assert!(!is_gregorian_valid(y, m, d, 23, 58, 60, 0));
+        assert!(!is_gregorian_valid(y, m, d, 22, 59, 60, 0));
+        assert!(!is_gregorian_valid(y, m, d, 23, 59, 61, 0));
+        if *announced {
+            leap_second_days += 1;
+            assert!(Epoch::maybe_from_gregorian_utc(y, m, d, 23, 59, 60, 0).is_ok());
+        }
+    }
+    assert_eq!(leap_second_days, 28);
+
+    // Any other end of month or end of year must not have a 60th second.
+    for year in 1960..2030 {
+        for month in 1..=12 {
+            let day = if month == 2 && year % 4 == 0 {
+                29
+            } else {
+                [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31][month as usize - 1]
+            };
+            let next_midnight = if month == 12 {
+                Epoch::from_gregorian_tai_at_midnight(year + 1, 1, 1)
+            } else {
+                Epoch::from_gregorian_tai_at_midnight(year, month + 1, 1)
+            };
+            let expected = leap_seconds_table()
+                .iter()
+                .any(|(boundary, _, announced)| *announced && *boundary == next_midnight);
+            assert_eq!(
+                is_gregorian_valid(year, month, day, 23, 59, 60, 0),
+                expected,
+                "{year}-{month:02}-{day:02}"
+            );
+        }
+    }
+}
+
 #[test]
 fn gpst() {
     let ref_gps = Epoch::from_gregorian_utc_at_midnight(1980, 01, 06);
@@ -522,11 +571,11 @@ fn unix() {
     let unix_epoch = Epoch::from_gregorian_utc_at_midnight(1970, 1, 1);
 
     assert_eq!(
-        format!("{}", unix_epoch.in_time_scale(TimeScale::UTC)),
+        format!("{}", unix_epoch.with_time_scale(TimeScale::UTC)),
         "1970-01-01T00:00:00 UTC"
     );
     assert_eq!(
-        format!("{:x}", unix_epoch.in_time_scale(TimeScale::TAI)),
+        format!("{:x}", unix_epoch.with_time_scale(TimeScale::TAI)),
         "1970-01-01T00:00:00 TAI"
     );
     // Print as UNIX seconds
@@ -822,12 +871,9 @@ fn test_from_str() {
     let greg = "2020-01-31T00:00:00 TDB";
     assert_eq!(greg, format!("{:e}", Epoch::from_str(greg).unwrap()));
 
-    // Newton Raphson of ET leads to an 11 nanosecond error in this case.
+    // The ET iteration converges, so there is no longer an 11 nanosecond error in this case.
     let greg = "2020-01-31T00:00:00 ET";
-    assert_eq!(
-        "2020-01-31T00:00:00.000000011 ET",
-        format!("{:E}", Epoch::from_str(greg).unwrap())
-    );
+    assert_eq!(greg, format!("{:E}", Epoch::from_str(greg).unwrap()));
 
     // Regression test for #90
     assert_eq!(
@@ -849,6 +895,46 @@ fn test_from_str() {
     );
 }
 
+#[test]
+fn test_from_str_jd_mjd_all_time_scales() {
+    use core::str::FromStr;
+
+    // J2000 is JD 2451545.0 TT, i.e. MJD 51544.5 TT
+    let j2000_tt = Epoch::from_gregorian(2000, 1, 1, 12, 0, 0, 0, TimeScale::TT);
+    assert_eq!(Epoch::from_jde_tt(2_451_545.0), j2000_tt);
+    assert_eq!(Epoch::from_mjd_tt(51_544.5), j2000_tt);
+    assert_eq!(Epoch::from_str("JD 2451545.0 TT").unwrap(), j2000_tt);
+    assert_eq!(Epoch::from_str("MJD 51544.5 TT").unwrap(), j2000_tt);
+    assert_eq!(j2000_tt.to_jde_tt_days(), 2_451_545.0);
+    assert_eq!(j2000_tt.to_mjd_tt_days(), 51_544.5);
+
+    // GNSS time scales in JD, which match their dedicated initializers
+    assert_eq!(
+        Epoch::from_str("JD 2459580.5 GPST").unwrap(),
+        Epoch::from_jde_gpst(2_459_580.5)
+    );
+    assert_eq!(
+        Epoch::from_str("JD 2459580.5 GST").unwrap(),
+        Epoch::from_jde_gst(2_459_580.5)
+    );
+    assert_eq!(
+        Epoch::from_str("JD 2459580.5 BDT").unwrap(),
+        Epoch::from_jde_bdt(2_459_580.5)
+    );
+    assert_eq!(
+        Epoch::from_str("JD 2459580.5 GPST").unwrap().time_scale,
+        TimeScale::GPST
+    );
+
+    // MJD remains unsupported in the dynamical time scales
+    for s in ["MJD 51544.5 TDB", "MJD 51544.5 ET"] {
+        assert_eq!(
+            Epoch::from_str(s),
+            Err(Errors::ParseError(ParsingErrors::UnsupportedTimeSystem))
+        );
+    }
+}
+
 #[test]
 fn test_from_str_tdb() {
     use core::str::FromStr;
@@ -1013,6 +1099,32 @@ fn ops() {
     assert!((sp_ex.to_tdb_seconds() - expected_et_s).abs() < 2.6e-6);
 }
 
+#[test]
+fn ops_f64_seconds() {
+    let expected_et_s = 381_885_819.184_935_87;
+    let start = Epoch::from_gregorian_utc_hms(2012, 2, 7, 11, 22, 33);
+
+    let mut sp_ex = start;
+    sp_ex += 1.0;
+    assert_eq!(sp_ex, start + 1.0);
+    assert!((sp_ex.to_tdb_seconds() - expected_et_s - 1.0).abs() < 2.6e-6);
+    sp_ex -= 1.0;
+    assert_eq!(sp_ex, start);
+    assert!((sp_ex.to_tdb_seconds() - expected_et_s).abs() < 2.6e-6);
+
+    // Subtraction mirrors the addition, including with fractional and negative seconds.
+    assert_eq!(start - 1.5, start - Unit::Second * 1.5);
+    assert_eq!(start - 0.5, start + (-0.5));
+    assert_eq!(start - (-2.0), start + 2.0);
+
+    // The seconds are added and subtracted in the time scale of the epoch.
+    let utc = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    assert_eq!(
+        utc - 1.0,
+        Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 0)
+    );
+}
+
 #[test]
 fn test_range() {
     let start = Epoch::from_gregorian_utc_hms(2012, 2, 7, 11, 22, 33);
@@ -1089,6 +1201,124 @@ fn test_floor_ceil_round() {
     );
 }
 
+#[test]
+fn test_round_with_mode() {
+    use hifitime::{RoundMode, TimeUnits};
+
+    let e = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 30, 0);
+    let five = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 0, 0);
+    let six = Epoch::from_gregorian_tai_hms(2022, 5, 20, 18, 0, 0);
+    assert_eq!(e.round_with(1.hours(), RoundMode::HalfUp), six);
+    assert_eq!(e.round_with(1.hours(), RoundMode::HalfDown), five);
+    assert_eq!(e.round_with(1.hours(), RoundMode::TowardZero), five);
+    assert_eq!(e.round_with(1.hours(), RoundMode::AwayFromZero), six);
+    // Hours since J1900 are even at midnight, so 17:00 is the odd multiple here
+    assert_eq!(e.round_with(1.hours(), RoundMode::HalfEven), six);
+    assert_eq!(
+        (e + 1.hours()).round_with(1.hours(), RoundMode::HalfEven),
+        six
+    );
+    assert_eq!(
+        e.round_with(1.hours(), RoundMode::HalfUp),
+        e.round(1.hours())
+    );
+
+    // Rounding happens in the time scale of the epoch
+    let e = Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 30, 0);
+    assert_eq!(
+        e.round_with(1.hours(), RoundMode::HalfDown),
+        Epoch::from_gregorian_utc_hms(2022, 5, 20, 17, 0, 0)
+    );
+}
+
+#[test]
+fn test_midpoint_lerp() {
+    use hifitime::TimeUnits;
+
+    let start = Epoch::from_gregorian_utc(2022, 10, 3, 17, 44, 29, 898032665);
+    let end = start + 2.days() + 3.hours();
+    let mid = start + 1.days() + 90.minutes();
+    assert_eq!(start.midpoint(end), mid);
+    assert_eq!(end.midpoint(start), mid);
+    assert_eq!(start.midpoint(start), start);
+    // The time scale of the epoch is preserved
+    assert_eq!(start.midpoint(end).time_scale, TimeScale::UTC);
+
+    // Span of an odd number of nanoseconds: rounded toward self
+    let end = start + 3.nanoseconds();
+    assert_eq!(start.midpoint(end), start + 1.nanoseconds());
+    assert_eq!(end.midpoint(start), start + 2.nanoseconds());
+    let end = start + 1.nanoseconds();
+    assert_eq!(start.midpoint(end), start);
+
+    // Midpoint across a leap second, which is counted as any other second
+    let start = Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 59);
+    let end = Epoch::from_gregorian_utc_hms(2017, 1, 1, 0, 0, 0);
+    assert_eq!(end - start, 2.seconds());
+    assert_eq!(
+        start.midpoint(end),
+        Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0)
+    );
+
+    // Linear interpolation
+    let start = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 0, 0);
+    let end = Epoch::from_gregorian_tai_hms(2022, 5, 20, 18, 0, 0);
+    assert_eq!(start.lerp(end, 0.0), start);
+    assert_eq!(start.lerp(end, 0.5), start.midpoint(end));
+    assert_eq!(start.lerp(end, 0.1), start + 6.minutes());
+    assert_eq!(start.lerp(end, 1.0), end);
+    assert_eq!(end.lerp(start, 0.75), start + 15.minutes());
+    assert_eq!(start.lerp(end, 2.0), end + 1.hours());
+}
+
+#[test]
+fn test_floor_ceil_round_to_unit() {
+    use hifitime::TimeUnits;
+
+    let e = Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 57, 43);
+    assert_eq!(
+        e.ceil_to(Unit::Hour),
+        Epoch::from_gregorian_tai_hms(2022, 5, 20, 18, 0, 0)
+    );
+    assert_eq!(
+        e.floor_to(Unit::Hour),
+        Epoch::from_gregorian_tai_hms(2022, 5, 20, 17, 0, 0)
+    );
+    assert_eq!(
+        e.round_to(Unit::Hour),
+        Epoch::from_gregorian_tai_hms(2022, 5, 20, 18, 0, 0)
+    );
+
+    // Matches the duration based versions in every time scale
+    let e = Epoch::from_gregorian_utc(2022, 10, 3, 17, 44, 29, 898032665);
+    for ts in [
+        TimeScale::TAI,
+        TimeScale::UTC,
+        TimeScale::TDB,
+        TimeScale::GPST,
+    ] {
+        let e = e.with_time_scale(ts);
+        for unit in [Unit::Second, Unit::Minute, Unit::Hour, Unit::Day] {
+            assert_eq!(e.floor_to(unit), e.floor(unit * 1));
+            assert_eq!(e.ceil_to(unit), e.ceil(unit * 1));
+            assert_eq!(e.round_to(unit), e.round(unit * 1));
+        }
+    }
+    assert_eq!(
+        e.floor_to(Unit::Minute),
+        Epoch::from_gregorian_utc_hms(2022, 10, 3, 17, 44, 0)
+    );
+    assert_eq!(
+        e.ceil_to(Unit::Second),
+        Epoch::from_gregorian_utc_hms(2022, 10, 3, 17, 44, 30)
+    );
+    assert_eq!(
+        e.round_to(Unit::Millisecond),
+        Epoch::from_gregorian_utc(2022, 10, 3, 17, 44, 29, 898_000_000)
+    );
+    assert_eq!(e.floor_to(Unit::Nanosecond), e.floor(1.nanoseconds()));
+}
+
 #[test]
 fn test_ord() {
     let epoch1 = Epoch::maybe_from_gregorian(2020, 1, 8, 16, 1, 17, 100, TimeScale::TAI).unwrap();
@@ -1283,7 +1513,7 @@ fn test_add_durations_over_leap_seconds() {
     // Noon UTC after the first leap second is in fact ten seconds _after_ noon TAI.
     // Hence, there are as many TAI seconds since Epoch between UTC Noon and TAI Noon + 10s.
     let pre_ls_utc = Epoch::from_gregorian_utc_at_noon(1971, 12, 31);
-    let pre_ls_tai = pre_ls_utc.in_time_scale(TimeScale::TAI);
+    let pre_ls_tai = pre_ls_utc.with_time_scale(TimeScale::TAI);
 
     // Before the first leap second, there is no time difference between both epochs (because only IERS announced leap seconds are accounted for by default).
     assert_eq!(pre_ls_utc - pre_ls_tai, Duration::ZERO);
@@ -1384,7 +1614,7 @@ fn test_weekday() {
             TimeScale::TT,
             TimeScale::UTC,
         ] {
-            let e_ts = e.in_time_scale(new_time_scale);
+            let e_ts = e.with_time_scale(new_time_scale);
             assert_eq!(e_ts.weekday(), expect, "error with {new_time_scale}");
         }
     };
@@ -1464,7 +1694,7 @@ fn test_get_time() {
 
     let epoch = Epoch::from_gregorian_utc(2022, 12, 01, 10, 11, 12, 13);
     let other_utc = Epoch::from_gregorian_utc(2024, 12, 01, 20, 21, 22, 23);
-    let other = other_utc.in_time_scale(TimeScale::TDB);
+    let other = other_utc.with_time_scale(TimeScale::TDB);
 
     assert_eq!(
         epoch.with_hms_from(other),
@@ -1522,7 +1752,7 @@ fn test_time_of_week() {
     assert_eq!(epoch.to_time_of_week(), (0, 10 * 1_000_000_000 + 10));
 
     // TAI<=>UTC
-    let epoch_utc = epoch.in_time_scale(TimeScale::UTC);
+    let epoch_utc = epoch.with_time_scale(TimeScale::UTC);
     let (week, tow) = epoch_utc.to_time_of_week();
     assert_eq!(
         Epoch::from_time_of_week(week, tow, TimeScale::UTC),
@@ -1542,7 +1772,7 @@ fn test_time_of_week() {
     assert_eq!(epoch.to_gregorian_utc(), (2022, 12, 01, 00, 00, 00, 00));
     assert_eq!(epoch.to_time_of_week(), (2238, 345_618_000_000_000));
 
-    let epoch_utc = epoch.in_time_scale(TimeScale::UTC);
+    let epoch_utc = epoch.with_time_scale(TimeScale::UTC);
     let (utc_wk, utc_tow) = epoch_utc.to_time_of_week();
     assert_eq!(
         Epoch::from_time_of_week(utc_wk, utc_tow, TimeScale::UTC),
@@ -1554,7 +1784,7 @@ fn test_time_of_week() {
     assert_eq!(epoch.to_gregorian_utc(), (1980, 01, 06, 01, 00, 0 + 18, 00));
     assert_eq!(epoch.to_time_of_week(), (0, 3_618_000_000_000));
 
-    let epoch_utc = epoch.in_time_scale(TimeScale::UTC);
+    let epoch_utc = epoch.with_time_scale(TimeScale::UTC);
     let (utc_wk, utc_tow) = epoch_utc.to_time_of_week();
     assert_eq!(
         Epoch::from_time_of_week(utc_wk, utc_tow, TimeScale::UTC),
@@ -1575,7 +1805,7 @@ fn test_time_of_week() {
     assert_eq!(epoch.to_time_of_week(), (24, 306_457_000_000_000));
 
     // <=>UTC
-    let epoch_utc = epoch.in_time_scale(TimeScale::UTC);
+    let epoch_utc = epoch.with_time_scale(TimeScale::UTC);
     let (week, tow) = epoch_utc.to_time_of_week();
     assert_eq!(
         Epoch::from_time_of_week(week, tow, TimeScale::UTC),
@@ -1587,7 +1817,7 @@ fn test_time_of_week() {
     assert_eq!(epoch.to_gregorian_utc(), (2022, 12, 01, 00, 00, 00, 01));
 
     // <=>UTC
-    let epoch_utc = epoch.in_time_scale(TimeScale::UTC);
+    let epoch_utc = epoch.with_time_scale(TimeScale::UTC);
     let (week, tow) = epoch_utc.to_time_of_week();
     assert_eq!(
         Epoch::from_time_of_week(week, tow, TimeScale::UTC),
@@ -1599,7 +1829,7 @@ fn test_time_of_week() {
     assert_eq!(epoch.to_gregorian_utc(), (2022, 12, 02, 12, 00, 00, 00));
 
     // <=>UTC
-    let epoch_utc = epoch.in_time_scale(TimeScale::UTC);
+    let epoch_utc = epoch.with_time_scale(TimeScale::UTC);
     let (week, tow) = epoch_utc.to_time_of_week();
     assert_eq!(
         Epoch::from_time_of_week(week, tow, TimeScale::UTC),
@@ -1611,7 +1841,7 @@ fn test_time_of_week() {
     assert_eq!(epoch.to_gregorian_utc(), (2022, 12, 02, 15, 27, 19, 10));
 
     // <=>UTC
-    let epoch_utc = epoch.in_time_scale(TimeScale::UTC);
+    let epoch_utc = epoch.with_time_scale(TimeScale::UTC);
     let (week, tow) = epoch_utc.to_time_of_week();
     assert_eq!(
         Epoch::from_time_of_week(week, tow, TimeScale::UTC),
@@ -1625,7 +1855,7 @@ fn test_time_of_week() {
     assert_eq!(epoch.to_time_of_week(), (0, 3_600_000_000_000));
 
     // <=>UTC
-    let epoch_utc = epoch.in_time_scale(TimeScale::UTC);
+    let epoch_utc = epoch.with_time_scale(TimeScale::UTC);
     let (week, tow) = epoch_utc.to_time_of_week();
     assert_eq!(
         Epoch::from_time_of_week(week, tow, TimeScale::UTC),
@@ -1640,7 +1870,7 @@ fn test_time_of_week() {
     assert_eq!(epoch.to_time_of_week(), (1, 128 * 3600 * 1_000_000_000));
 
     // <=>UTC
-    let epoch_utc = epoch.in_time_scale(TimeScale::UTC);
+    let epoch_utc = epoch.with_time_scale(TimeScale::UTC);
     let (week, tow) = epoch_utc.to_time_of_week();
     assert_eq!(
         Epoch::from_time_of_week(week, tow, TimeScale::UTC),
@@ -1661,7 +1891,7 @@ fn test_time_of_week() {
     );
 
     // <=>UTC
-    let epoch_utc = epoch.in_time_scale(TimeScale::UTC);
+    let epoch_utc = epoch.with_time_scale(TimeScale::UTC);
     let (week, tow) = epoch_utc.to_time_of_week();
     assert_eq!(
         Epoch::from_time_of_week(week, tow, TimeScale::UTC),
@@ -1683,7 +1913,7 @@ fn test_time_of_week() {
     );
 
     // <=>UTC
-    let epoch_utc = epoch.in_time_scale(TimeScale::UTC);
+    let epoch_utc = epoch.with_time_scale(TimeScale::UTC);
     let (week, tow) = epoch_utc.to_time_of_week();
     assert_eq!(
         Epoch::from_time_of_week(week, tow, TimeScale::UTC),
@@ -1704,7 +1934,7 @@ fn test_day_of_year() {
             TimeScale::ET,
             TimeScale::TDB,
         ] {
-            let epoch = utc_epoch.in_time_scale(*ts);
+            let epoch = utc_epoch.with_time_scale(*ts);
             let (year, days) = epoch.year_days_of_year();
             let rebuilt = Epoch::from_day_of_year(year, days, *ts);
             if *ts == TimeScale::ET || *ts == TimeScale::TDB {
@@ -1844,3 +2074,3155 @@ fn test_leap_seconds_file() {
         }
     }
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_leap_seconds_file_from_reader() {
+    use hifitime::leap_seconds::LeapSecondsFile;
+
+    // Subset of the IETF leap-seconds.list, with a single leap second more recent than the embedded data.
+    let contents = "#	Updated through IERS Bulletin C65
+#$	 3676924800
+#@	3896899200
+#
+2272060800	10	# 1 Jan 1972
+3692217600	37	# 1 Jan 2017
+3881174400	38	# 1 Jan 2023
+";
+
+    let provider = LeapSecondsFile::from_reader(contents.as_bytes()).unwrap();
+    assert_eq!(provider[0].delta_at, 10.0);
+    assert_eq!(provider[2].delta_at, 38.0);
+
+    // Expiration date is 28 June 2023.
+    let expiration = provider.expiration().unwrap();
+    assert_eq!(
+        expiration,
+        Epoch::from_gregorian_utc_at_midnight(2023, 6, 28)
+    );
+    assert!(!provider.is_expired_at(Epoch::from_gregorian_utc_at_midnight(2023, 6, 27)));
+    assert!(provider.is_expired_at(expiration));
+
+    let epoch = Epoch::from_gregorian_tai_at_midnight(2023, 3, 1);
+    assert_eq!(epoch.leap_seconds_with(true, &provider), Some(38.0));
+    // The embedded data does not include this fictitious leap second.
+    assert_eq!(epoch.leap_seconds(true), Some(37.0));
+
+    assert_eq!(
+        epoch.to_tai_duration() - epoch.to_utc_duration_with(&provider),
+        38 * Unit::Second
+    );
+    let utc_duration = epoch.to_utc_duration_with(&provider);
+    assert_eq!(Epoch::from_utc_duration_with(utc_duration, provider), epoch);
+
+    // Check that the full file is also correctly parsed.
+    let provider = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
+    assert_eq!(
+        provider.expiration().unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(2023, 6, 28)
+    );
+}
+
+#[test]
+fn test_leap_second_provider() {
+    use hifitime::leap_seconds::{LatestLeapSeconds, LeapSecondProvider};
+
+    /// A provider with a hypothetical leap second on 01 Jan 2025.
+    struct FutureLeapSeconds;
+
+    impl LeapSecondProvider for FutureLeapSeconds {
+        fn leap_seconds_at(&self, tai_seconds: f64, iers_only: bool) -> Option<f64> {
+            let future = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+            if tai_seconds >= future.to_tai_seconds() + 1.0 {
+                Some(38.0)
+            } else {
+                LatestLeapSeconds::default().leap_seconds_at(tai_seconds, iers_only)
+            }
+        }
+    }
+
+    let providers: [&dyn LeapSecondProvider; 2] =
+        [&LatestLeapSeconds::default(), &FutureLeapSeconds];
+
+    let before = Epoch::from_gregorian_tai_at_midnight(2024, 6, 1);
+    let after = Epoch::from_gregorian_tai_at_midnight(2025, 6, 1);
+
+    for provider in providers {
+        assert_eq!(before.leap_seconds_with(true, provider), Some(37.0));
+        assert_eq!(
+            Epoch::from_utc_duration_with(before.to_utc_duration_with(provider), provider),
+            before
+        );
+    }
+
+    assert_eq!(after.leap_seconds_with(true, providers[0]), Some(37.0));
+    assert_eq!(after.leap_seconds_with(true, providers[1]), Some(38.0));
+    assert_eq!(
+        after.to_utc_duration() - after.to_utc_duration_with(providers[1]),
+        Unit::Second * 1
+    );
+    // Default methods still use the built-in table.
+    assert_eq!(after.leap_seconds(true), Some(37.0));
+    assert_eq!(
+        Epoch::from_gregorian_tai_at_midnight(1950, 1, 1).leap_seconds_with(false, providers[1]),
+        None
+    );
+}
+
+#[test]
+fn test_next_previous_leap_second() {
+    let mid_2014 = Epoch::from_gregorian_utc_at_midnight(2014, 6, 15);
+    let next = mid_2014.next_leap_second().unwrap();
+    assert_eq!(next, Epoch::from_gregorian_utc_at_midnight(2015, 7, 1));
+    assert_eq!(next.leap_seconds_iers(), 36);
+    assert_eq!(
+        mid_2014.previous_leap_second().unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(2012, 7, 1)
+    );
+    // Exactly on the boundary, that leap second is the previous one.
+    assert_eq!(next.previous_leap_second().unwrap(), next);
+    assert_eq!(
+        next.next_leap_second().unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(2017, 1, 1)
+    );
+    // No more leap seconds are known after 2017.
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(2018, 1, 1).next_leap_second(),
+        None
+    );
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(1971, 1, 1).previous_leap_second(),
+        None
+    );
+}
+
+#[test]
+fn test_leap_seconds_table() {
+    use hifitime::leap_seconds::{leap_seconds_table, LatestLeapSeconds};
+
+    let table = leap_seconds_table();
+    assert_eq!(table.len(), 42);
+    assert_eq!(
+        table.iter().filter(|(_, _, announced)| *announced).count(),
+        28
+    );
+
+    // The 2017 boundary, stored as 2017 January 01 at midnight counted in TAI seconds
+    let (boundary, delta_at, announced) = table[41];
+    assert_eq!(boundary, Epoch::from_tai_seconds(3_692_217_600.0));
+    assert_eq!(boundary, Epoch::from_gregorian_tai_at_midnight(2017, 1, 1));
+    assert_eq!(boundary.time_scale, TimeScale::TAI);
+    assert_eq!(delta_at, 37.0);
+    assert!(announced);
+
+    // Matches the raw table, in chronological order
+    for ((boundary, delta_at, announced), leap_second) in
+        table.iter().zip(LatestLeapSeconds::default())
+    {
+        assert_eq!(boundary.to_tai_seconds(), leap_second.timestamp_tai_s);
+        assert_eq!(*delta_at, leap_second.delta_at);
+        assert_eq!(*announced, leap_second.announced_by_iers);
+    }
+    assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+    // The UTC midnights returned by the other methods are later than the stored boundaries by ΔAT.
+    let start = Epoch::from_gregorian_utc_at_midnight(1960, 1, 1);
+    let end = Epoch::from_gregorian_utc_at_midnight(2100, 1, 1);
+    assert_eq!(Epoch::leap_seconds_in_range_iter(start, end).count(), 28);
+    let announced = table.iter().filter(|(_, _, announced)| *announced);
+    for ((boundary, delta_at, _), (utc_midnight, _)) in
+        announced.zip(Epoch::leap_seconds_in_range_iter(start, end))
+    {
+        assert_eq!(utc_midnight - *boundary, *delta_at * Unit::Second);
+        assert_eq!(utc_midnight.previous_leap_second(), Some(utc_midnight));
+    }
+}
+
+#[test]
+fn test_leap_second_representation() {
+    let leap = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0);
+    let before = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 0);
+    let after = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    // The leap second is a distinct instant between the last second of the day and midnight.
+    assert_eq!(leap - before, 1 * Unit::Second);
+    assert_eq!(after - leap, 1 * Unit::Second);
+
+    assert_eq!(leap.to_gregorian_utc(), (2016, 12, 31, 23, 59, 60, 0));
+    assert_eq!(before.to_gregorian_utc(), (2016, 12, 31, 23, 59, 59, 0));
+    assert_eq!(after.to_gregorian_utc(), (2017, 1, 1, 0, 0, 0, 0));
+
+    let leap_half = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000);
+    assert_eq!(leap_half - leap, 0.5 * Unit::Second);
+    #[cfg(feature = "std")]
+    {
+        use core::str::FromStr;
+        assert_eq!(format!("{leap}"), "2016-12-31T23:59:60 UTC");
+        assert_eq!(format!("{leap_half}"), "2016-12-31T23:59:60.500000000 UTC");
+        assert_eq!(leap.to_rfc3339(), "2016-12-31T23:59:60+00:00");
+        // Round trip through the string representation.
+        assert_eq!(Epoch::from_str(&format!("{leap}")).unwrap(), leap);
+        assert_eq!(Epoch::from_str(&format!("{leap_half}")).unwrap(), leap_half);
+        assert_eq!(Epoch::from_str(&leap.to_rfc3339()).unwrap(), leap);
+    }
+}
+
+#[test]
+fn test_leap_seconds_since() {
+    let start = Epoch::from_gregorian_utc_at_midnight(1971, 1, 1);
+    let end = Epoch::from_gregorian_utc_at_midnight(2018, 1, 1);
+    assert_eq!(end.leap_seconds_since(start), Some(37.0));
+    assert_eq!(start.leap_seconds_since(end), Some(-37.0));
+    // Hence the UTC interval is shorter than the TAI interval.
+    assert_eq!(
+        (end - start) - (end.to_utc_duration() - start.to_utc_duration()),
+        37 * Unit::Second
+    );
+
+    let mid_2014 = Epoch::from_gregorian_utc_at_midnight(2014, 6, 15);
+    assert_eq!(end.leap_seconds_since(mid_2014), Some(2.0));
+    assert_eq!(mid_2014.leap_seconds_since(mid_2014), Some(0.0));
+
+    // UTC is not defined before 1960
+    let pre_utc = Epoch::from_gregorian_utc_at_midnight(1955, 1, 1);
+    assert_eq!(end.leap_seconds_since(pre_utc), None);
+    assert_eq!(pre_utc.leap_seconds_since(end), None);
+}
+
+#[test]
+fn test_smeared_unix_seconds() {
+    let leap = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    let unix_leap = leap.to_unix_seconds();
+
+    // 24 hour smear, noon to noon, centered on the leap second.
+    let window = 24 * Unit::Hour;
+    // The window spans 86401 SI seconds, so its midpoint is in the middle of the leap second.
+    let midpoint = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000);
+    // At the midpoint, half of the leap second has been smeared: the smeared clock reads midnight,
+    // i.e. half a second behind the UNIX clock prior to the leap second, and half a second ahead of the one after it.
+    assert!((midpoint.to_smeared_unix_seconds(window, true) - unix_leap).abs() < 1e-6);
+    // Roughly a quarter of the way through the smear.
+    let epoch = leap - 6 * Unit::Hour;
+    assert!(
+        (epoch.to_smeared_unix_seconds(window, true) - (epoch.to_unix_seconds() - 0.25)).abs()
+            < 1e-4
+    );
+    // Outside of the window, the smeared time matches UNIX time.
+    for epoch in [
+        leap - 13 * Unit::Hour,
+        leap + 12 * Unit::Hour,
+        leap + 1 * Unit::Day,
+    ] {
+        assert_eq!(
+            epoch.to_smeared_unix_seconds(window, true),
+            epoch.to_unix_seconds()
+        );
+    }
+
+    // Trailing 20 hour smear which absorbs the whole leap second by midnight, roughly half of it is absorbed after 10 hours.
+    let window = 20 * Unit::Hour;
+    let epoch = leap - 10 * Unit::Hour;
+    assert!(
+        (epoch.to_smeared_unix_seconds(window, false) - (epoch.to_unix_seconds() - 0.5)).abs()
+            < 1e-4
+    );
+    assert_eq!(leap.to_smeared_unix_seconds(window, false), unix_leap);
+
+    // The smeared clock is monotonic across the leap second.
+    let leap_second = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000);
+    let mut prev = (leap - 2 * Unit::Second).to_smeared_unix_seconds(window, true);
+    for epoch in [
+        leap - 1 * Unit::Second,
+        leap_second,
+        leap,
+        leap + 1 * Unit::Second,
+    ] {
+        let smeared = epoch.to_smeared_unix_seconds(window, true);
+        assert!(smeared > prev);
+        prev = smeared;
+    }
+}
+
+#[test]
+fn test_gmst() {
+    let one_arcsec = (1.0_f64 / 3600.0).to_radians();
+
+    // Vallado, Fundamentals of Astrodynamics and Applications, 4th ed., example 3-5: 152.578787810 degrees
+    let e = Epoch::from_gregorian_utc_hms(1992, 8, 20, 12, 14, 0);
+    assert!((e.gmst() - 152.578_787_810_f64.to_radians()).abs() < one_arcsec);
+    // Does not depend on the time scale of the epoch
+    assert_eq!(e.with_time_scale(TimeScale::TDB).gmst(), e.gmst());
+
+    // At J2000 (UT1), GMST is 18h41m50.54841s, i.e. 280.46061837 degrees
+    let e = Epoch::from_gregorian_utc_hms(2000, 1, 1, 12, 0, 0);
+    assert!((e.gmst() - 280.460_618_37_f64.to_radians()).abs() < one_arcsec);
+
+    // The angle is always within [0, 2pi) and increases by about 361 degrees per solar day
+    let start = Epoch::from_gregorian_utc_hms(1965, 2, 3, 4, 5, 6);
+    for days in 0..400 {
+        let e = start + days * Unit::Day;
+        let gmst = e.gmst();
+        assert!((0.0..core::f64::consts::TAU).contains(&gmst));
+        let advance = ((e + Unit::Day).gmst() - gmst).rem_euclid(core::f64::consts::TAU);
+        let expected = 0.985_647_366_f64.to_radians();
+        assert!((advance - expected).abs() < one_arcsec, "{e}: {advance}");
+    }
+}
+
+#[test]
+fn test_gast() {
+    let one_arcsec = (1.0_f64 / 3600.0).to_radians();
+    let hms_to_rad = |h: f64, m: f64, s: f64| ((h + m / 60.0 + s / 3600.0) * 15.0).to_radians();
+
+    // Meeus, Astronomical Algorithms, example 12.b: GMST is 8h34m57.0896s and GAST is 8h34m56.853s
+    let e = Epoch::from_gregorian_utc_hms(1987, 4, 10, 19, 21, 0);
+    assert!((e.gmst() - hms_to_rad(8.0, 34.0, 57.0896)).abs() < one_arcsec);
+    assert!((e.gast() - hms_to_rad(8.0, 34.0, 56.853)).abs() < one_arcsec);
+    // Equation of the equinoxes is -0.2317 seconds of time
+    assert!((e.equation_of_equinoxes() - hms_to_rad(0.0, 0.0, -0.2317)).abs() < one_arcsec);
+
+    // SOFA `iauEqeq94` test value at MJD 41234.0 TT
+    let e = Epoch::from_mjd_tai(41_234.0) - Unit::Millisecond * 32_184;
+    assert!((e.equation_of_equinoxes() - 5.357_758_254_609_257e-5).abs() < one_arcsec);
+
+    // At J2000.0, the nutation in longitude is about -13.9 arcseconds, so the equation of the
+    // equinoxes is about -12.8 arcseconds.
+    let e = Epoch::from_gregorian_utc_hms(2000, 1, 1, 12, 0, 0);
+    let eqeq = e.equation_of_equinoxes();
+    assert!((eqeq - (-12.8_f64 / 3600.0).to_radians()).abs() < one_arcsec);
+    assert!((e.gast() - (e.gmst() + eqeq)).abs() < f64::EPSILON);
+    assert!((e.gast() - hms_to_rad(18.0, 41.0, 50.548_41 - 0.853)).abs() < one_arcsec);
+}
+
+#[test]
+fn test_julian_year() {
+    let j2000 = Epoch::from_julian_year(2000.0);
+    assert_eq!(j2000.to_jde_tt_days(), 2_451_545.0);
+    assert_eq!(j2000.to_julian_year(), 2000.0);
+    assert_eq!(j2000.time_scale, TimeScale::TT);
+    // J2000.0 is 2000-01-01 12:00:00 TT
+    assert_eq!(
+        j2000,
+        Epoch::from_gregorian_tai(2000, 1, 1, 11, 59, 27, 816_000_000)
+    );
+
+    // J2015.5 is used as the reference epoch of Gaia DR2, and corresponds to JD 2457206.375 TT
+    let j2015_5 = Epoch::from_julian_year(2015.5);
+    assert_eq!(j2015_5.to_jde_tt_days(), 2_457_206.375);
+    assert_eq!(j2015_5.to_julian_year(), 2015.5);
+    // J2016.0 is used for Gaia DR3
+    assert_eq!(
+        Epoch::from_julian_year(2016.0).to_jde_tt_days(),
+        2_457_389.0
+    );
+
+    // Any epoch can be expressed as a Julian year
+    let e = Epoch::from_gregorian_utc_hms(2022, 7, 2, 12, 0, 0);
+    let year = e.to_julian_year();
+    assert!((year - 2022.5).abs() < 1e-2);
+    // The decimal year is only precise to a few microseconds
+    assert!((Epoch::from_julian_year(year) - e).abs() < 100.microseconds());
+    // Before J2000
+    assert!((Epoch::from_julian_year(1984.25).to_julian_year() - 1984.25).abs() < 1e-12);
+}
+
+#[test]
+fn test_to_gregorian_struct() {
+    use core::convert::TryFrom;
+    use hifitime::Gregorian;
+
+    let e = Epoch::from_gregorian_utc(2022, 10, 3, 17, 44, 29, 898032665);
+    let greg = e.to_gregorian(TimeScale::UTC);
+    assert_eq!(
+        greg,
+        Gregorian {
+            year: 2022,
+            month: 10,
+            day: 3,
+            hour: 17,
+            minute: 44,
+            second: 29,
+            nanos: 898032665,
+            time_scale: TimeScale::UTC,
+        }
+    );
+    assert_eq!(format!("{greg}"), "2022-10-03T17:44:29.898032665 UTC");
+    assert_eq!(format!("{greg}"), format!("{e}"));
+
+    // Matches the tuple representations
+    let (y, mm, dd, hh, min, s, nanos) = e.to_gregorian_tai();
+    let greg = e.to_gregorian(TimeScale::TAI);
+    assert_eq!(
+        (
+            greg.year,
+            greg.month,
+            greg.day,
+            greg.hour,
+            greg.minute,
+            greg.second,
+            greg.nanos
+        ),
+        (y, mm, dd, hh, min, s, nanos)
+    );
+    assert_eq!(format!("{greg}"), format!("{e:x}"));
+
+    // Round trip in all of the time scales
+    for ts in [
+        TimeScale::TAI,
+        TimeScale::TT,
+        TimeScale::TDB,
+        TimeScale::UTC,
+        TimeScale::GPST,
+        TimeScale::GST,
+        TimeScale::BDT,
+    ] {
+        let greg = e.to_gregorian(ts);
+        assert_eq!(greg.time_scale, ts);
+        let back = Epoch::try_from(greg).unwrap();
+        assert!((back - e).abs() < 1.microseconds(), "{ts:?}: {back} != {e}");
+    }
+
+    // Leap second and whole seconds
+    let leap = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0);
+    let greg = leap.to_gregorian(TimeScale::UTC);
+    assert_eq!(greg.second, 60);
+    assert_eq!(format!("{greg}"), "2016-12-31T23:59:60 UTC");
+    assert_eq!(Epoch::try_from(greg).unwrap(), leap);
+
+    // Invalid dates are rejected
+    let mut greg = e.to_gregorian(TimeScale::UTC);
+    greg.month = 13;
+    assert_eq!(Epoch::try_from(greg), Err(Errors::Carry));
+}
+
+#[test]
+fn test_quarter_fractional_year() {
+    for ts in [
+        TimeScale::TAI,
+        TimeScale::TT,
+        TimeScale::ET,
+        TimeScale::TDB,
+        TimeScale::UTC,
+        TimeScale::GPST,
+        TimeScale::GST,
+        TimeScale::BDT,
+    ] {
+        for (month, quarter) in [(1, 1), (4, 2), (7, 3), (10, 4)] {
+            // First instant of the quarter, and the last second of the previous one
+            let start = Epoch::from_gregorian_at_midnight(2023, month, 1, ts);
+            assert_eq!(start.quarter(), quarter, "{start}");
+            assert_eq!(
+                Epoch::from_gregorian(2023, month, 1, 1, 0, 0, 0, ts).quarter(),
+                quarter,
+                "{ts}"
+            );
+            let before = Epoch::from_gregorian_hms(
+                if month == 1 { 2022 } else { 2023 },
+                if month == 1 { 12 } else { month - 1 },
+                if month == 7 || month == 10 { 30 } else { 31 },
+                23,
+                59,
+                59,
+                ts,
+            );
+            assert_eq!(
+                before.quarter(),
+                if quarter == 1 { 4 } else { quarter - 1 },
+                "{before}"
+            );
+            assert_eq!(start.month_name(), hifitime::MonthName::from(month), "{ts}");
+        }
+
+        // Year boundaries
+        let new_year = Epoch::from_gregorian_at_midnight(2023, 1, 1, ts);
+        let eve = Epoch::from_gregorian_hms(2022, 12, 31, 23, 59, 59, ts);
+        assert_eq!(eve.year_days_of_year().0, 2022, "{ts}");
+        assert_eq!(new_year.year_days_of_year().0, 2023, "{ts}");
+        assert!(
+            eve.fractional_year() < 2023.0 && eve.fractional_year() > 2022.999,
+            "{ts}"
+        );
+        if matches!(ts, TimeScale::ET | TimeScale::TDB) {
+            // These time scales are only exact within a few nanoseconds.
+            assert!((new_year.fractional_year() - 2023.0).abs() < 1e-12, "{ts}");
+            assert!(new_year.day_of_year().abs() < 1e-12, "{ts}");
+        } else {
+            assert_eq!(new_year.fractional_year(), 2023.0, "{ts}");
+            assert_eq!(new_year.day_of_year(), 0.0, "{ts}");
+            assert_eq!(
+                Epoch::from_gregorian_hms(2021, 7, 2, 12, 0, 0, ts).fractional_year(),
+                2021.5,
+                "{ts}"
+            );
+        }
+    }
+
+    // 2021 is a common year: mid-year is 182.5 days in
+    let e = Epoch::from_gregorian_utc_hms(2021, 7, 2, 12, 0, 0);
+    assert_eq!(e.fractional_year(), 2021.5);
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(2021, 1, 1).fractional_year(),
+        2021.0
+    );
+    // 2020 is a leap year: mid-year is 183 days in
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(2020, 7, 2).fractional_year(),
+        2020.5
+    );
+    assert_eq!(
+        Epoch::from_gregorian_utc_hms(2020, 7, 2, 12, 0, 0).fractional_year(),
+        2020.0 + 183.5 / 366.0
+    );
+    // Last second of the year remains within that year
+    let e = Epoch::from_gregorian_utc_hms(2020, 12, 31, 23, 59, 59);
+    assert!(e.fractional_year() < 2021.0);
+    assert!(e.fractional_year() > 2020.999);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_local_time() {
+    use hifitime::LocalTime;
+
+    let e = Epoch::from_gregorian_utc_hms(2017, 1, 14, 13, 15, 30);
+    let local = e.to_local(-5.hours());
+    assert_eq!(local, LocalTime::new(e, -5.hours()));
+    assert_eq!(local.to_gregorian(), (2017, 1, 14, 8, 15, 30, 0));
+    assert_eq!(format!("{local}"), "2017-01-14T08:15:30-05:00");
+    assert_eq!(local.to_epoch(), e);
+    assert_eq!(Epoch::from_gregorian_str(&format!("{local}")).unwrap(), e);
+
+    // Offsets with minutes, round trip through the parser
+    let e = Epoch::from_gregorian_utc(2022, 12, 31, 20, 0, 0, 123_000_000);
+    let local = e.to_local(5.hours() + 30.minutes());
+    assert_eq!(format!("{local}"), "2023-01-01T01:30:00.123000000+05:30");
+    assert_eq!(Epoch::from_gregorian_str(&format!("{local}")).unwrap(), e);
+
+    let local = e.to_local(-(9.hours() + 30.minutes()));
+    assert_eq!(format!("{local}"), "2022-12-31T10:30:00.123000000-09:30");
+    assert_eq!(Epoch::from_gregorian_str(&format!("{local}")).unwrap(), e);
+
+    // A zero offset matches the RFC3339 representation
+    assert_eq!(format!("{}", e.to_local(Duration::ZERO)), e.to_rfc3339());
+
+    // The leap second is preserved in local time
+    let leap = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0);
+    assert_eq!(
+        format!("{}", leap.to_local(-5.hours())),
+        "2016-12-31T18:59:60-05:00"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_rfc2822() {
+    let e = Epoch::from_gregorian_utc_hms(2017, 1, 14, 0, 31, 55);
+    assert_eq!(e.to_rfc2822(), "Sat, 14 Jan 2017 00:31:55 +0000");
+    assert_eq!(
+        Epoch::from_rfc2822("Sat, 14 Jan 2017 00:31:55 +0000").unwrap(),
+        e
+    );
+    // Subseconds are truncated
+    assert_eq!(
+        (e + 0.5.seconds()).to_rfc2822(),
+        "Sat, 14 Jan 2017 00:31:55 +0000"
+    );
+
+    // Round trip over several dates, including single digit days
+    for e in [
+        Epoch::from_gregorian_utc_hms(1994, 11, 6, 8, 49, 37),
+        Epoch::from_gregorian_utc_hms(2003, 7, 1, 10, 52, 37),
+        Epoch::from_gregorian_utc_hms(2023, 12, 31, 23, 59, 59),
+        Epoch::from_gregorian_utc_at_midnight(2024, 2, 29),
+    ] {
+        assert_eq!(Epoch::from_rfc2822(&e.to_rfc2822()).unwrap(), e);
+    }
+    assert_eq!(
+        Epoch::from_gregorian_utc_hms(2003, 7, 1, 10, 52, 37).to_rfc2822(),
+        "Tue, 1 Jul 2003 10:52:37 +0000"
+    );
+
+    // HTTP date example from RFC7231
+    assert_eq!(
+        Epoch::from_rfc2822("Sun, 06 Nov 1994 08:49:37 GMT").unwrap(),
+        Epoch::from_gregorian_utc_hms(1994, 11, 6, 8, 49, 37)
+    );
+    assert_eq!(
+        Epoch::from_rfc2822("06 Nov 1994 08:49:37 UT").unwrap(),
+        Epoch::from_gregorian_utc_hms(1994, 11, 6, 8, 49, 37)
+    );
+    // Examples from RFC2822, with offsets and without seconds
+    assert_eq!(
+        Epoch::from_rfc2822("Fri, 21 Nov 1997 09:55:06 -0600").unwrap(),
+        Epoch::from_gregorian_utc_hms(1997, 11, 21, 15, 55, 6)
+    );
+    assert_eq!(
+        Epoch::from_rfc2822("Thu, 13 Feb 1969 23:32 -0330").unwrap(),
+        Epoch::from_gregorian_utc_hms(1969, 2, 14, 3, 2, 0)
+    );
+    assert_eq!(
+        Epoch::from_rfc2822("Tue, 1 Jul 2003 10:52:37 +0200").unwrap(),
+        Epoch::from_gregorian_utc_hms(2003, 7, 1, 8, 52, 37)
+    );
+    // Obsolete two digit years and zone names
+    assert_eq!(
+        Epoch::from_rfc2822("21 Nov 97 09:55:06 CST").unwrap(),
+        Epoch::from_gregorian_utc_hms(1997, 11, 21, 15, 55, 6)
+    );
+
+    // Leap seconds are supported
+    let leap = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0);
+    assert_eq!(leap.to_rfc2822(), "Sat, 31 Dec 2016 23:59:60 +0000");
+    assert_eq!(Epoch::from_rfc2822(&leap.to_rfc2822()).unwrap(), leap);
+
+    // Invalid strings
+    assert_eq!(
+        Epoch::from_rfc2822("Fri, 14 Jan 2017 00:31:55 +0000"),
+        Err(Errors::ParseError(ParsingErrors::WeekdayMismatch {
+            found: Weekday::Friday,
+            expected: Weekday::Saturday
+        }))
+    );
+    for invalid in [
+        "",
+        "Sat, 14 Jan 2017",
+        "Sat, 14 Jan 2017 00:31:55",
+        "Sat, 14 Jan 2017 00:31:55 +000",
+        "Sat, 14 Jan 2017 00:31:55 0000",
+        "Sat, 14 Jan 2017 00:31:55 PST extra",
+        "Sat, 14 Jan 2017 00:31:55:00 +0000",
+        // Non-ASCII zone of five bytes
+        "Sat, 14 Jan 2017 00:31:55 é123",
+        "Sat, 14 Jan 2017 00:31:55 +é12",
+        // Offset minutes out of range
+        "Sat, 14 Jan 2017 00:31:55 +0099",
+        "Sat, 14 Jan 2017 00:31:55 -0160",
+    ] {
+        assert_eq!(
+            Epoch::from_rfc2822(invalid),
+            Err(Errors::ParseError(ParsingErrors::RFC2822)),
+            "{invalid}"
+        );
+    }
+    assert_eq!(
+        Epoch::from_rfc2822("Sat, 14 Jan 2017 00:31:55 +0059").unwrap(),
+        Epoch::from_gregorian_utc_hms(2017, 1, 13, 23, 32, 55)
+    );
+    assert_eq!(
+        Epoch::from_rfc2822("Sat, 14 Janv 2017 00:31:55 +0000"),
+        Err(Errors::ParseError(ParsingErrors::UnknownMonthName))
+    );
+    assert_eq!(
+        Epoch::from_rfc2822("Sat, 31 Feb 2017 00:31:55 +0000"),
+        Err(Errors::Carry)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_iso8601_expanded_years() {
+    use core::str::FromStr;
+
+    let far_future = Epoch::from_gregorian_utc(12023, 1, 14, 0, 31, 55, 811_000_000);
+    assert_eq!(
+        far_future.to_gregorian_utc(),
+        (12023, 1, 14, 0, 31, 55, 811_000_000)
+    );
+    assert_eq!(
+        far_future.to_iso8601_expanded(TimeScale::UTC),
+        "+012023-01-14T00:31:55.811000000 UTC"
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str("+012023-01-14T00:31:55.811 UTC").unwrap(),
+        far_future
+    );
+
+    let far_past = Epoch::from_gregorian_tai_at_midnight(-300, 3, 1);
+    assert_eq!(far_past.to_gregorian_tai(), (-300, 3, 1, 0, 0, 0, 0));
+    assert_eq!(
+        far_past.to_iso8601_expanded(TimeScale::TAI),
+        "-000300-03-01T00:00:00 TAI"
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str("-000300-03-01T00:00:00 TAI").unwrap(),
+        far_past
+    );
+    assert_eq!(
+        Epoch::from_str("-0300-03-01T00:00:00 TAI").unwrap(),
+        far_past
+    );
+    // Year -300 is not a leap year
+    assert_eq!(
+        far_past - Epoch::from_gregorian_tai_at_midnight(-300, 2, 28),
+        1.days()
+    );
+
+    // Usual years also have a sign
+    let e = Epoch::from_gregorian_utc_hms(2017, 1, 14, 0, 31, 55);
+    assert_eq!(
+        e.to_iso8601_expanded(TimeScale::UTC),
+        "+002017-01-14T00:31:55 UTC"
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str(&e.to_iso8601_expanded(TimeScale::UTC)).unwrap(),
+        e
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str("+2017-01-14T00:31:55 UTC").unwrap(),
+        e
+    );
+
+    // Expanded years which do not fit in an Epoch are an error, not a panic
+    for overflow in [
+        "+2000000000-01-01T00:00:00 UTC",
+        "-2000000000-01-01T00:00:00 TAI",
+        "+2147483647-12-31T23:59:59 UTC",
+        "-2147483647-01-01T00:00:00 UTC",
+        "+3300000-01-01T00:00:00 GPST",
+    ] {
+        assert_eq!(
+            Epoch::from_gregorian_str(overflow),
+            Err(Errors::Overflow),
+            "{overflow}"
+        );
+        assert!(Epoch::from_str(overflow).is_err(), "{overflow}");
+    }
+    let far = Epoch::from_gregorian_str("+3000000-01-01T00:00:00 TAI").unwrap();
+    assert_eq!(far.to_gregorian_tai(), (3_000_000, 1, 1, 0, 0, 0, 0));
+}
+
+#[test]
+fn test_gregorian_before_1900() {
+    // Just before the J1900 reference, the time of day is counted forward from the previous midnight.
+    assert_eq!(
+        Epoch::from_tai_duration(-1.nanoseconds()).to_gregorian_tai(),
+        (1899, 12, 31, 23, 59, 59, 999_999_999)
+    );
+    assert_eq!(
+        Epoch::from_tai_duration(-1.days()).to_gregorian_tai(),
+        (1899, 12, 31, 0, 0, 0, 0)
+    );
+    assert_eq!(
+        Epoch::from_tai_duration(-(1.days() + 1.nanoseconds())).to_gregorian_tai(),
+        (1899, 12, 30, 23, 59, 59, 999_999_999)
+    );
+    // More than one century before J1900: there are 24 leap days from 1800 to 1899, so a Julian century is 1799 December 31
+    assert_eq!(
+        Epoch::from_tai_duration(-1.centuries() - 12.hours()).to_gregorian_tai(),
+        (1799, 12, 30, 12, 0, 0, 0)
+    );
+    // Leap days of the centuries divisible by 400 only, and of the years before zero
+    assert_eq!(
+        Epoch::from_gregorian_tai_at_midnight(1600, 3, 1) - 1.days(),
+        Epoch::from_gregorian_tai_at_midnight(1600, 2, 29)
+    );
+    assert_eq!(
+        (Epoch::from_gregorian_tai_at_midnight(1700, 3, 1) - 1.days()).to_gregorian_tai(),
+        (1700, 2, 28, 0, 0, 0, 0)
+    );
+    assert_eq!(
+        (Epoch::from_gregorian_tai_at_midnight(-4, 3, 1) - 1.days()).to_gregorian_tai(),
+        (-4, 2, 29, 0, 0, 0, 0)
+    );
+
+    // Gregorian dates before 1900 round trip
+    let mut e = Epoch::from_gregorian_tai_hms(-401, 12, 31, 23, 59, 59);
+    let end = Epoch::from_gregorian_tai_at_midnight(2101, 1, 1);
+    while e < end {
+        let (y, mm, dd, hh, min, s, nanos) = e.to_gregorian_tai();
+        assert_eq!(
+            Epoch::from_gregorian_tai(y, mm, dd, hh, min, s, nanos),
+            e,
+            "{y}-{mm}-{dd}"
+        );
+        e += 23.hours() + 57.minutes() + 3.seconds();
+        e += 13.days();
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_from_str_comma_decimal_separator() {
+    use core::str::FromStr;
+
+    let e = Epoch::from_gregorian_utc(2017, 1, 14, 0, 31, 55, 811_000_000);
+    for s in [
+        "2017-01-14T00:31:55,811 UTC",
+        "2017-01-14T00:31:55,811Z",
+        "2017-01-14 00:31:55,811000000",
+        "2017-01-14T01:31:55,811+01:00",
+    ] {
+        assert_eq!(Epoch::from_gregorian_str(s).unwrap(), e, "{s}");
+        assert_eq!(Epoch::from_str(s).unwrap(), e, "{s}");
+    }
+    assert_eq!(
+        Epoch::from_gregorian_str("2017-01-14T00:31:55,811 UTC").unwrap(),
+        Epoch::from_gregorian_str("2017-01-14T00:31:55.811 UTC").unwrap()
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str("2017-01-14T00:31:55,811 TAI").unwrap(),
+        Epoch::from_gregorian_tai(2017, 1, 14, 0, 31, 55, 811_000_000)
+    );
+    // Only one decimal separator is allowed
+    assert!(Epoch::from_gregorian_str("2017-01-14T00:31:55,811,5 UTC").is_err());
+}
+
+#[test]
+fn test_from_unix_str() {
+    use core::str::FromStr;
+
+    let unix_epoch = Epoch::from_gregorian_utc_at_midnight(1970, 1, 1);
+    // UNIX time does not count leap seconds, so compare the UTC durations
+    let unix_ns =
+        |e: Epoch| (e.to_utc_duration() - unix_epoch.to_utc_duration()).total_nanoseconds();
+
+    // The fractional part round-trips to the nanosecond
+    let e = Epoch::from_unix_str("1651487955.123456789").unwrap();
+    assert_eq!(unix_ns(e), 1_651_487_955_123_456_789);
+    assert_eq!(
+        e,
+        Epoch::from_gregorian_utc(2022, 5, 2, 10, 39, 15, 123_456_789)
+    );
+    assert_eq!(Epoch::from_str("UNIX 1651487955.123456789").unwrap(), e);
+
+    // Milliseconds, and truncation of sub-nanosecond digits
+    assert_eq!(
+        unix_ns(Epoch::from_str("UNIX 1651487955.123").unwrap()),
+        1_651_487_955_123_000_000
+    );
+    assert_eq!(
+        Epoch::from_unix_str("1651487955.1234567899").unwrap(),
+        Epoch::from_unix_str("1651487955.123456789").unwrap()
+    );
+    assert_eq!(Epoch::from_str("UNIX 0").unwrap(), unix_epoch);
+
+    // Pre-1970 timestamps
+    assert_eq!(
+        unix_ns(Epoch::from_unix_str("-1.5").unwrap()),
+        -1_500_000_000
+    );
+    assert_eq!(
+        Epoch::from_unix_str("-86400").unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(1969, 12, 31)
+    );
+
+    for invalid in ["", "-", ".", "1.2.3", "12a", "1e9", "UNIX"] {
+        assert_eq!(
+            Epoch::from_unix_str(invalid),
+            Err(Errors::ParseError(ParsingErrors::ValueError)),
+            "{invalid}"
+        );
+    }
+}
+
+#[test]
+fn test_duration_since() {
+    let liftoff = Epoch::from_gregorian_utc_hms(2022, 11, 16, 6, 47, 44);
+    for e in [
+        liftoff + 12.minutes() + 34.seconds(),
+        liftoff - 3.days(),
+        Epoch::from_gregorian_tai_at_midnight(1900, 1, 1),
+        // Spans the leap second at the end of 2016
+        Epoch::from_gregorian_utc_at_midnight(2015, 6, 1),
+    ] {
+        assert_eq!(e.duration_since(liftoff), e - liftoff);
+        assert_eq!(e.seconds_since(liftoff), (e - liftoff).to_seconds());
+    }
+    assert_eq!(liftoff.duration_since(liftoff), Duration::ZERO);
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use hifitime::NANOSECONDS_PER_CENTURY;
+
+    let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+    let mut u = Unstructured::new(&bytes);
+    while !u.is_empty() {
+        let epoch = Epoch::arbitrary(&mut u).unwrap();
+        let (_, nanoseconds) = epoch.to_tai_duration().to_parts();
+        assert!(nanoseconds < NANOSECONDS_PER_CENTURY);
+        let duration = Duration::arbitrary(&mut u).unwrap();
+        assert!(duration.to_parts().1 < NANOSECONDS_PER_CENTURY);
+    }
+
+    // Extreme inputs still lead to valid values
+    for byte in [0x00, 0xFF] {
+        let bytes = [byte; 32];
+        let duration = Duration::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert!(duration.to_parts().1 < NANOSECONDS_PER_CENTURY);
+        let ts = TimeScale::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        // Printing must not panic
+        let _ = format!("{}", Epoch::from_duration(duration, ts));
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_uniform_epoch() {
+    use hifitime::UniformEpoch;
+    use rand::distributions::Distribution;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    let mut rng = SmallRng::seed_from_u64(1_234);
+
+    let start = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    let end = start + 1.days();
+    let dist = UniformEpoch::new(start..end);
+
+    let mut first_half = 0;
+    for _ in 0..10_000 {
+        let epoch = dist.sample(&mut rng);
+        assert!(epoch >= start && epoch < end, "{epoch} out of range");
+        assert_eq!(epoch.time_scale, TimeScale::UTC);
+        if epoch < start + 12.hours() {
+            first_half += 1;
+        }
+    }
+    // Loose bound on the uniformity: about half of the draws are in the first half of the range
+    assert!((4_500..5_500).contains(&first_half), "{first_half}");
+
+    // One nanosecond wide range
+    let dist = UniformEpoch::from(start..start + 1.nanoseconds());
+    assert_eq!(dist.sample(&mut rng), start);
+
+    // The largest span does not overflow
+    let min = Epoch::from_tai_duration(Duration::MIN);
+    let max = Epoch::from_tai_duration(Duration::MAX);
+    let dist = UniformEpoch::new(min..max);
+    for _ in 0..1_000 {
+        let epoch = dist.sample(&mut rng);
+        assert!(epoch >= min && epoch < max);
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+#[should_panic]
+fn test_uniform_epoch_empty_range() {
+    let e = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    let _ = hifitime::UniformEpoch::new(e..e);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+#[should_panic]
+fn test_uniform_epoch_reversed_range() {
+    let e = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    let _ = hifitime::UniformEpoch::new(e + 1.seconds()..e);
+}
+
+#[test]
+fn test_et_tdb_round_trip_far_from_j2000() {
+    for year in [-500, 1000, 1500, 1800, 1900, 2000, 2100, 2400, 3000] {
+        for (month, day, hour) in [(1, 1, 0), (4, 15, 7), (9, 30, 18)] {
+            let e = Epoch::from_gregorian_tai(year, month, day, hour, 13, 17, 123_456_789);
+
+            let et = Epoch::from_et_duration(e.to_et_duration());
+            assert!(
+                (et - e).abs() < 10.nanoseconds(),
+                "ET round trip of {e} off by {}",
+                et - e
+            );
+            let et = Epoch::from_et_seconds(e.to_et_seconds());
+            assert!(
+                (et - e).abs() < 10.microseconds(),
+                "ET seconds round trip of {e} off by {}",
+                et - e
+            );
+
+            let tdb = Epoch::from_tdb_duration(e.to_tdb_duration());
+            assert!(
+                (tdb - e).abs() < 10.nanoseconds(),
+                "TDB round trip of {e} off by {}",
+                tdb - e
+            );
+
+            // Initializing in ET and TDB also round trips
+            let et_dur = e.to_et_duration();
+            assert!(
+                (Epoch::from_et_duration(et_dur).to_et_duration() - et_dur).abs()
+                    < 10.nanoseconds()
+            );
+            let tdb_dur = e.to_tdb_duration();
+            assert!(
+                (Epoch::from_tdb_duration(tdb_dur).to_tdb_duration() - tdb_dur).abs()
+                    < 10.nanoseconds()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_strict_utc_before_1960() {
+    // UTC is not defined in 1955, so the strict methods fail, but the lenient ones treat it as TAI
+    assert_eq!(
+        Epoch::maybe_from_gregorian_utc_strict(1955, 6, 1, 12, 0, 0, 0),
+        Err(Errors::UtcUndefined)
+    );
+    let e = Epoch::from_gregorian_utc(1955, 6, 1, 12, 0, 0, 0);
+    assert_eq!(e, Epoch::from_gregorian_tai(1955, 6, 1, 12, 0, 0, 0));
+    assert_eq!(e.try_to_utc_duration(), Err(Errors::UtcUndefined));
+    assert_eq!(e.to_utc_duration(), e.to_tai_duration());
+    assert_eq!(
+        Epoch::try_from_utc_duration(e.to_utc_duration()),
+        Err(Errors::UtcUndefined)
+    );
+
+    // But it is defined in 1965
+    let e = Epoch::maybe_from_gregorian_utc_strict(1965, 6, 1, 12, 0, 0, 0).unwrap();
+    assert_eq!(e, Epoch::from_gregorian_utc(1965, 6, 1, 12, 0, 0, 0));
+    assert_eq!(e.try_to_utc_duration(), Ok(e.to_utc_duration()));
+    assert_eq!(Epoch::try_from_utc_duration(e.to_utc_duration()), Ok(e));
+
+    // Invalid dates are still reported as such
+    assert_eq!(
+        Epoch::maybe_from_gregorian_utc_strict(1965, 2, 30, 12, 0, 0, 0),
+        Err(Errors::Carry)
+    );
+}
+
+#[test]
+fn test_sofa_pre_1972_utc() {
+    use hifitime::leap_seconds::SofaLeapSeconds;
+
+    // Values of TAI-UTC from SOFA's iauDat, which includes the drift of UTC before 1972
+    for (year, month, day, tai_minus_utc_s) in [
+        (1960, 1, 1, 1.417_818 + (36_934.0 - 37_300.0) * 0.001_296),
+        (1962, 6, 1, 1.845_858 + (37_816.0 - 37_665.0) * 0.001_123_2),
+        (1965, 6, 15, 3.853_97),
+        (1968, 3, 1, 4.213_17 + (39_916.0 - 39_126.0) * 0.002_592),
+        (1980, 1, 1, 19.0),
+    ] {
+        let e = Epoch::maybe_from_gregorian_utc_with(year, month, day, 0, 0, 0, 0, SofaLeapSeconds)
+            .unwrap();
+        let tai = Epoch::from_gregorian_tai_at_midnight(year, month, day);
+        assert!(
+            ((e - tai).to_seconds() - tai_minus_utc_s).abs() < 1e-9,
+            "{year}-{month}-{day}: got {} expected {tai_minus_utc_s} s",
+            e - tai
+        );
+
+        // Reciprocity
+        let utc = tai.to_tai_duration();
+        assert_eq!(e.to_utc_duration_with(SofaLeapSeconds), utc);
+        assert_eq!(Epoch::from_utc_duration_with(utc, SofaLeapSeconds), e);
+    }
+
+    // No leap second is detected because of the drift: it's just before midnight
+    let e =
+        Epoch::maybe_from_gregorian_utc_with(1965, 6, 15, 23, 59, 59, 0, SofaLeapSeconds).unwrap();
+    assert_eq!(
+        e.to_utc_duration_with(SofaLeapSeconds),
+        Epoch::from_gregorian_tai(1965, 6, 15, 23, 59, 59, 0).to_tai_duration()
+    );
+
+    // By default, the pre-1972 offsets are ignored
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(1965, 6, 15),
+        Epoch::from_gregorian_tai_at_midnight(1965, 6, 15)
+    );
+    // And UTC is not defined before 1960
+    assert_eq!(
+        Epoch::maybe_from_gregorian_utc_with(1955, 1, 1, 0, 0, 0, 0, SofaLeapSeconds).unwrap(),
+        Epoch::from_gregorian_tai_at_midnight(1955, 1, 1)
+    );
+}
+
+#[test]
+fn test_from_str_subnanosecond_digits() {
+    use core::str::FromStr;
+
+    // Extra digits are truncated to the nanosecond
+    let expected = Epoch::from_gregorian_utc(2017, 1, 14, 0, 31, 55, 811_234_567);
+    assert_eq!(
+        Epoch::from_gregorian_str("2017-01-14T00:31:55.8112345678 UTC").unwrap(),
+        expected
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str("2017-01-14T00:31:55.811234567999").unwrap(),
+        expected
+    );
+    assert_eq!(
+        Epoch::from_str("2017-01-14T00:31:55.811234567891 TAI").unwrap(),
+        Epoch::from_gregorian_tai(2017, 1, 14, 0, 31, 55, 811_234_567)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str("2017-01-14T00:31:55.000000000999Z").unwrap(),
+        Epoch::from_gregorian_utc_hms(2017, 1, 14, 0, 31, 55)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str("2017-01-14T00:31:55.999999999999+01:00").unwrap(),
+        Epoch::from_gregorian_utc(2017, 1, 13, 23, 31, 55, 999_999_999)
+    );
+}
+
+#[test]
+fn test_from_date_str() {
+    assert_eq!(
+        Epoch::from_date_str("2017-01-14", TimeScale::UTC).unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(2017, 1, 14)
+    );
+    assert_eq!(
+        Epoch::from_date_str(" 2020-02-29 ", TimeScale::GPST).unwrap(),
+        Epoch::from_gregorian_at_midnight(2020, 2, 29, TimeScale::GPST)
+    );
+    assert_eq!(
+        Epoch::from_date_str("-000300-03-01", TimeScale::TAI).unwrap(),
+        Epoch::from_gregorian_tai_at_midnight(-300, 3, 1)
+    );
+
+    for invalid in [
+        "",
+        "2017",
+        "2017-01",
+        "17-01-14",
+        "2017-1-14",
+        "2017-01-14T00:00:00",
+        "2017-13-01",
+        "2019-02-29",
+        "2017-01-1a",
+    ] {
+        assert!(
+            Epoch::from_date_str(invalid, TimeScale::UTC).is_err(),
+            "{invalid}"
+        );
+    }
+
+    // Years which do not fit in an Epoch are an error, not a panic
+    for overflow in [
+        "2000000000-01-01",
+        "-2000000000-01-01",
+        "+2147483647-01-01",
+        "-2147483647-12-31",
+        "3300000-01-01",
+    ] {
+        assert_eq!(
+            Epoch::from_date_str(overflow, TimeScale::UTC),
+            Err(Errors::Overflow),
+            "{overflow}"
+        );
+    }
+    assert_eq!(
+        Epoch::maybe_from_gregorian(i32::MAX, 12, 31, 23, 59, 59, 0, TimeScale::TAI),
+        Err(Errors::Overflow)
+    );
+    assert_eq!(
+        Epoch::maybe_from_gregorian(i32::MIN, 1, 1, 0, 0, 0, 0, TimeScale::TAI),
+        Err(Errors::Overflow)
+    );
+    // The largest representable years are still supported
+    for year in [3_000_000, -3_000_000] {
+        let e = Epoch::from_gregorian_tai_at_midnight(year, 1, 1);
+        assert_eq!(e.to_gregorian_tai(), (year, 1, 1, 0, 0, 0, 0));
+    }
+
+    // Combined with a time of day
+    let date = Epoch::from_date_str("2017-01-14", TimeScale::UTC).unwrap();
+    let time_of_day = Duration::from_time_of_day_str("00:31:55.811").unwrap();
+    assert_eq!(
+        date + time_of_day,
+        Epoch::from_gregorian_str("2017-01-14T00:31:55.811 UTC").unwrap()
+    );
+}
+
+#[test]
+fn test_whole_calendar_units() {
+    let utc = Epoch::from_gregorian_utc_at_midnight;
+
+    // Partial months
+    let jan31 = utc(2023, 1, 31);
+    assert_eq!(jan31.whole_months_until(jan31), 0);
+    assert_eq!(jan31.whole_months_until(utc(2023, 2, 28)), 0);
+    assert_eq!(jan31.whole_months_until(utc(2023, 3, 1)), 1);
+    assert_eq!(jan31.whole_months_until(utc(2023, 3, 31)), 2);
+    assert_eq!(
+        jan31.whole_months_until(Epoch::from_gregorian_utc_hms(2023, 3, 30, 23, 59, 59)),
+        1
+    );
+    // The time of day matters
+    let noon = Epoch::from_gregorian_utc_hms(2023, 1, 15, 12, 0, 0);
+    assert_eq!(noon.whole_months_until(utc(2023, 2, 15)), 0);
+    assert_eq!(noon.whole_months_until(noon + 31.days()), 1);
+
+    // Years
+    let birth = utc(1990, 6, 15);
+    assert_eq!(birth.whole_years_until(utc(2023, 6, 14)), 32);
+    assert_eq!(birth.whole_years_until(utc(2023, 6, 15)), 33);
+    assert_eq!(birth.whole_months_until(utc(2023, 6, 15)), 33 * 12);
+
+    // Leap day anniversaries are on March 1 in common years
+    let leap_day = utc(2020, 2, 29);
+    assert_eq!(leap_day.whole_years_until(utc(2021, 2, 28)), 0);
+    assert_eq!(leap_day.whole_years_until(utc(2021, 3, 1)), 1);
+    assert_eq!(leap_day.whole_years_until(utc(2024, 2, 28)), 3);
+    assert_eq!(leap_day.whole_years_until(utc(2024, 2, 29)), 4);
+    assert_eq!(leap_day.whole_months_until(utc(2021, 2, 28)), 11);
+
+    // Negative counts are symmetric
+    assert_eq!(utc(2023, 2, 28).whole_months_until(jan31), 0);
+    assert_eq!(utc(2023, 3, 1).whole_months_until(jan31), -1);
+    assert_eq!(utc(2023, 6, 15).whole_years_until(birth), -33);
+    assert_eq!(utc(2023, 6, 14).whole_years_until(birth), -32);
+
+    // The components are compared in the time scale of self
+    let tai = Epoch::from_gregorian_tai_at_midnight(2023, 2, 1);
+    assert_eq!(tai.whole_months_until(utc(2023, 3, 1)), 1);
+    // In UTC, the first of March in TAI is 37 seconds before midnight
+    assert_eq!(
+        utc(2023, 2, 1).whole_months_until(Epoch::from_gregorian_tai_at_midnight(2023, 3, 1)),
+        0
+    );
+}
+
+#[test]
+fn test_batch_conversions() {
+    // Epochs spanning the pre-1972 leap seconds until after the last leap second, in several time scales.
+    let start = Epoch::from_gregorian_utc_at_midnight(1959, 6, 1);
+    let time_scales = [
+        TimeScale::UTC,
+        TimeScale::TAI,
+        TimeScale::TDB,
+        TimeScale::GPST,
+    ];
+    let mut sorted = Vec::new();
+    for i in 0..2_000 {
+        let epoch = start + i * 11.days() + i * 37.seconds();
+        sorted.push(epoch.with_time_scale(time_scales[i as usize % time_scales.len()]));
+    }
+    // Include the leap seconds themselves.
+    sorted.push(Epoch::from_gregorian_utc(
+        2016,
+        12,
+        31,
+        23,
+        59,
+        60,
+        500_000_000,
+    ));
+    sorted.push(Epoch::from_gregorian_utc(1972, 6, 30, 23, 59, 60, 0));
+    sorted.sort();
+
+    let mut unsorted = sorted.clone();
+    unsorted.reverse();
+
+    for epochs in [&sorted, &unsorted] {
+        let mut utc = vec![Duration::ZERO; epochs.len()];
+        Epoch::to_utc_duration_batch(epochs, &mut utc);
+        let mut unix = vec![0.0; epochs.len()];
+        Epoch::to_unix_seconds_batch(epochs, &mut unix);
+
+        for (i, epoch) in epochs.iter().enumerate() {
+            assert_eq!(utc[i], epoch.to_utc_duration(), "{epoch}");
+            assert_eq!(unix[i], epoch.to_unix_seconds(), "{epoch}");
+        }
+    }
+
+    // Empty slices are fine too.
+    Epoch::to_unix_seconds_batch(&[], &mut []);
+}
+
+#[test]
+#[should_panic]
+fn test_batch_conversion_length_mismatch() {
+    let epochs = [Epoch::from_gregorian_utc_at_midnight(2020, 1, 1)];
+    Epoch::to_unix_seconds_batch(&epochs, &mut [0.0; 2]);
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn test_reference_ops() {
+    let a = Epoch::from_gregorian_utc_at_midnight(2023, 3, 1);
+    let b = Epoch::from_gregorian_tai_at_noon(2023, 3, 2);
+    let d = 6.hours();
+
+    assert_eq!(&b - &a, b - a);
+    assert_eq!(b - &a, b - a);
+    assert_eq!(&b - a, b - a);
+
+    assert_eq!(&a + &d, a + d);
+    assert_eq!(a + &d, a + d);
+    assert_eq!(&a + d, a + d);
+
+    assert_eq!(&a - &d, a - d);
+    assert_eq!(a - &d, a - d);
+    assert_eq!(&a - d, a - d);
+
+    // Iterator adapters hand out references.
+    let epochs = [a, a + 1.hours(), a + 3.hours(), a + 6.hours()];
+    let steps: Vec<Duration> = epochs.windows(2).map(|w| &w[1] - &w[0]).collect();
+    assert_eq!(steps, [1.hours(), 2.hours(), 3.hours()]);
+    let total = steps.iter().fold(Duration::ZERO, |acc, step| acc + step);
+    assert_eq!(total, 6.hours());
+    assert_eq!(&total - &steps[2], 3.hours());
+    assert_eq!(&total - steps[0], 5.hours());
+}
+
+#[test]
+fn test_to_mjd_jde_in_time_scale() {
+    for epoch in [
+        Epoch::from_gregorian_utc_hms(1971, 12, 31, 23, 59, 59),
+        Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0),
+        Epoch::from_gregorian_tai(2023, 7, 14, 6, 21, 43, 123_456_789),
+        Epoch::from_gregorian_utc_at_midnight(2055, 2, 1),
+    ] {
+        assert_eq!(
+            epoch.to_mjd(TimeScale::UTC, Unit::Day),
+            epoch.to_mjd_utc_days()
+        );
+        assert_eq!(
+            epoch.to_mjd(TimeScale::UTC, Unit::Second),
+            epoch.to_mjd_utc_seconds()
+        );
+        assert_eq!(
+            epoch.to_mjd(TimeScale::TAI, Unit::Day),
+            epoch.to_mjd_tai_days()
+        );
+        assert_eq!(
+            epoch.to_mjd(TimeScale::TT, Unit::Day),
+            epoch.to_mjd_tt_days()
+        );
+
+        assert_eq!(
+            epoch.to_jde(TimeScale::UTC, Unit::Day),
+            epoch.to_jde_utc_days()
+        );
+        assert_eq!(
+            epoch.to_jde(TimeScale::TAI, Unit::Second),
+            epoch.to_jde_tai_seconds()
+        );
+        assert_eq!(
+            epoch.to_jde(TimeScale::TT, Unit::Day),
+            epoch.to_jde_tt_days()
+        );
+        assert_eq!(
+            epoch.to_jde(TimeScale::ET, Unit::Day),
+            epoch.to_jde_et_days()
+        );
+        assert_eq!(
+            epoch.to_jde(TimeScale::TDB, Unit::Day),
+            epoch.to_jde_tdb_days()
+        );
+
+        // The JDE and MJD are always offset by the same number of days.
+        for ts in [
+            TimeScale::TAI,
+            TimeScale::TT,
+            TimeScale::ET,
+            TimeScale::TDB,
+            TimeScale::UTC,
+            TimeScale::GPST,
+            TimeScale::GST,
+            TimeScale::BDT,
+        ] {
+            assert_eq!(
+                epoch.to_jde(ts, Unit::Second) - epoch.to_mjd(ts, Unit::Second),
+                MJD_OFFSET * SECONDS_PER_DAY
+            );
+        }
+    }
+
+    // The GNSS time scales are referenced like TAI.
+    let epoch = Epoch::from_gregorian_utc_at_midnight(1980, 1, 6);
+    assert_eq!(
+        epoch.to_mjd(TimeScale::GPST, Unit::Day),
+        epoch.to_mjd_tai_days()
+    );
+}
+
+#[test]
+fn test_from_mjd_jde_in_time_scale() {
+    let epochs = [
+        Epoch::from_gregorian_utc_hms(1971, 12, 31, 23, 59, 59),
+        Epoch::from_gregorian_tai(2023, 7, 14, 6, 21, 43, 123_456_789),
+        Epoch::from_gregorian_utc_at_midnight(2055, 2, 1),
+    ];
+    let time_scales = [
+        TimeScale::TAI,
+        TimeScale::TT,
+        TimeScale::ET,
+        TimeScale::TDB,
+        TimeScale::UTC,
+        TimeScale::GPST,
+        TimeScale::GST,
+        TimeScale::BDT,
+    ];
+
+    for epoch in epochs {
+        for ts in time_scales {
+            let from_mjd = Epoch::from_mjd(epoch.to_mjd(ts, Unit::Day), ts);
+            assert_eq!(from_mjd.time_scale, ts);
+            // A modern MJD in days is only precise to about one microsecond.
+            assert!(
+                (from_mjd - epoch).abs() < 2.microseconds(),
+                "{ts:?}: {}",
+                from_mjd - epoch
+            );
+
+            let from_jde = Epoch::from_jde(epoch.to_jde(ts, Unit::Day), ts);
+            assert_eq!(from_jde.time_scale, ts);
+            // The JDE is about forty times larger than the MJD, hence less precise.
+            assert!(
+                (from_jde - epoch).abs() < 50.microseconds(),
+                "{ts:?}: {}",
+                from_jde - epoch
+            );
+        }
+    }
+
+    // Whole days are exact and match the named constructors.
+    assert_eq!(
+        Epoch::from_mjd(51_544.0, TimeScale::UTC),
+        Epoch::from_mjd_utc(51_544.0)
+    );
+    assert_eq!(
+        Epoch::from_mjd(51_544.0, TimeScale::UTC),
+        Epoch::from_gregorian_utc_at_midnight(2000, 1, 1)
+    );
+    assert_eq!(
+        Epoch::from_jde(2_451_545.0, TimeScale::TT),
+        Epoch::from_jde_tt(2_451_545.0)
+    );
+    assert_eq!(
+        Epoch::from_mjd(51_544.5, TimeScale::TDB),
+        Epoch::from_tdb_duration(Duration::ZERO)
+    );
+    assert_eq!(
+        Epoch::from_jde(2_451_545.0, TimeScale::ET),
+        Epoch::from_et_duration(Duration::ZERO)
+    );
+}
+
+#[test]
+fn test_gregorian_accessors() {
+    let epoch = Epoch::from_gregorian_utc(2022, 12, 1, 10, 11, 12, 13_456_789);
+    assert_eq!(epoch.year(), 2022);
+    assert_eq!(epoch.month(), 12);
+    assert_eq!(epoch.day(), 1);
+    assert_eq!(epoch.hours(), 10);
+    assert_eq!(epoch.minutes(), 11);
+    assert_eq!(epoch.seconds(), 12);
+    assert_eq!(epoch.nanoseconds_of_second(), 13_456_789);
+
+    // The accessors use the time scale of the epoch: a few seconds before midnight in TAI is the next day in TT.
+    let tai = Epoch::from_gregorian_tai(1999, 12, 31, 23, 59, 40, 0);
+    assert_eq!((tai.year(), tai.month(), tai.day()), (1999, 12, 31));
+    let tt = tai.with_time_scale(TimeScale::TT);
+    assert_eq!((tt.year(), tt.month(), tt.day()), (2000, 1, 1));
+    assert_eq!(tt.nanoseconds_of_second(), 184_000_000);
+
+    // Negative years are supported too.
+    let epoch = Epoch::from_gregorian_tai(-5, 2, 28, 0, 0, 0, 1);
+    assert_eq!((epoch.year(), epoch.month(), epoch.day()), (-5, 2, 28));
+    assert_eq!(epoch.nanoseconds_of_second(), 1);
+}
+
+#[test]
+fn test_gregorian_accessors_time_scales() {
+    for ts in [
+        TimeScale::TAI,
+        TimeScale::TT,
+        TimeScale::ET,
+        TimeScale::TDB,
+        TimeScale::UTC,
+        TimeScale::GPST,
+        TimeScale::GST,
+        TimeScale::BDT,
+    ] {
+        let epoch = Epoch::from_gregorian(2023, 4, 1, 1, 2, 3, 4_005_006, ts);
+        let nanos = epoch.to_gregorian(ts).nanos;
+        assert_eq!(
+            (epoch.year(), epoch.month(), epoch.day()),
+            (2023, 4, 1),
+            "{ts}"
+        );
+        assert_eq!(
+            (epoch.hours(), epoch.minutes(), epoch.seconds()),
+            (1, 2, 3),
+            "{ts}"
+        );
+        assert_eq!(epoch.nanoseconds_of_second(), nanos, "{ts}");
+        assert_eq!(
+            (
+                epoch.milliseconds(),
+                epoch.microseconds(),
+                epoch.nanoseconds()
+            ),
+            (
+                u64::from(nanos) / 1_000_000,
+                u64::from(nanos) / 1_000 % 1_000,
+                u64::from(nanos) % 1_000
+            ),
+            "{ts}"
+        );
+        if !matches!(ts, TimeScale::ET | TimeScale::TDB) {
+            assert_eq!(
+                (
+                    epoch.milliseconds(),
+                    epoch.microseconds(),
+                    epoch.nanoseconds()
+                ),
+                (4, 5, 6),
+                "{ts}"
+            );
+        }
+    }
+
+    // During a leap second
+    let epoch = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0);
+    assert_eq!(
+        (epoch.hours(), epoch.minutes(), epoch.seconds()),
+        (23, 59, 60)
+    );
+    // Before the reference epoch of the time scale
+    let epoch = Epoch::from_gregorian(1970, 5, 6, 7, 8, 9, 0, TimeScale::GPST);
+    assert_eq!((epoch.hours(), epoch.minutes(), epoch.seconds()), (7, 8, 9));
+}
+
+#[test]
+fn test_from_year_day() {
+    // Epoch of a two-line element set, with a two digit year and a fractional day of year.
+    let tle_epoch = "23 045.5";
+    let (yy, doy) = tle_epoch.split_once(' ').unwrap();
+    let epoch = Epoch::from_year_day(
+        2000 + yy.parse::<i32>().unwrap(),
+        doy.parse().unwrap(),
+        TimeScale::UTC,
+    )
+    .unwrap();
+    assert_eq!(epoch, Epoch::from_gregorian_utc_hms(2023, 2, 14, 12, 0, 0));
+
+    assert_eq!(
+        Epoch::from_year_day(2023, 1.0, TimeScale::TAI).unwrap(),
+        Epoch::from_gregorian_tai_at_midnight(2023, 1, 1)
+    );
+    assert_eq!(
+        Epoch::from_year_day(2023, 365.75, TimeScale::UTC).unwrap(),
+        Epoch::from_gregorian_utc_hms(2023, 12, 31, 18, 0, 0)
+    );
+    // Leap years have a February 29 and a day 366.
+    assert_eq!(
+        Epoch::from_year_day(2024, 60.0, TimeScale::UTC).unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(2024, 2, 29)
+    );
+    assert_eq!(
+        Epoch::from_year_day(2024, 61.0, TimeScale::UTC).unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(2024, 3, 1)
+    );
+    assert_eq!(
+        Epoch::from_year_day(2024, 366.5, TimeScale::GPST).unwrap(),
+        Epoch::from_gregorian_hms(2024, 12, 31, 12, 0, 0, TimeScale::GPST)
+    );
+    // Non leap years do not.
+    assert_eq!(
+        Epoch::from_year_day(2023, 60.0, TimeScale::UTC).unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(2023, 3, 1)
+    );
+
+    // Every day of the year is the inverse of the day of year accessor, which is zero-based.
+    for year in [2023, 2024] {
+        for day in 1..=365 {
+            let epoch = Epoch::from_year_day(year, f64::from(day) + 0.25, TimeScale::TAI).unwrap();
+            assert_eq!(epoch.day_of_year(), f64::from(day) - 0.75);
+        }
+    }
+
+    for day_of_year in [0.0, 0.999, 366.0, 400.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert_eq!(
+            Epoch::from_year_day(2023, day_of_year, TimeScale::UTC),
+            Err(Errors::Carry),
+            "{day_of_year}"
+        );
+    }
+    assert_eq!(
+        Epoch::from_year_day(2024, 367.0, TimeScale::UTC),
+        Err(Errors::Carry)
+    );
+}
+
+#[test]
+fn test_reference_epoch_consts() {
+    assert_eq!(
+        Epoch::J1900_TAI,
+        Epoch::from_gregorian_tai_at_midnight(1900, 1, 1)
+    );
+    assert_eq!(
+        Epoch::J2000_TAI,
+        Epoch::from_gregorian_tai_at_noon(2000, 1, 1)
+    );
+    assert_eq!(Epoch::J1900_TAI.time_scale, TimeScale::TAI);
+    assert_eq!(Epoch::J2000_TAI.time_scale, TimeScale::TAI);
+
+    assert_eq!(
+        Epoch::GPST_EPOCH,
+        Epoch::from_gregorian_utc_at_midnight(1980, 1, 6)
+    );
+    assert_eq!(Epoch::GPST_EPOCH.to_gpst_seconds(), 0.0);
+    assert_eq!(Epoch::GPST_EPOCH.time_scale, TimeScale::GPST);
+
+    assert_eq!(
+        Epoch::GST_EPOCH,
+        Epoch::from_gregorian_utc_at_midnight(1999, 8, 22) - 13.seconds()
+    );
+    assert_eq!(Epoch::GST_EPOCH.to_gst_seconds(), 0.0);
+    assert_eq!(Epoch::GST_EPOCH.time_scale, TimeScale::GST);
+
+    assert_eq!(
+        Epoch::BDT_EPOCH,
+        Epoch::from_gregorian_utc_at_midnight(2006, 1, 1)
+    );
+    assert_eq!(Epoch::BDT_EPOCH.to_bdt_seconds(), 0.0);
+    assert_eq!(Epoch::BDT_EPOCH.time_scale, TimeScale::BDT);
+
+    assert_eq!(
+        Epoch::UNIX_EPOCH,
+        Epoch::from_gregorian_utc_at_midnight(1970, 1, 1)
+    );
+    assert_eq!(Epoch::UNIX_EPOCH.to_unix_seconds(), 0.0);
+    assert_eq!(format!("{}", Epoch::UNIX_EPOCH), "1970-01-01T00:00:00 UTC");
+
+    // The constants match the time scale reference epochs.
+    assert_eq!(Epoch::GPST_EPOCH, TimeScale::GPST.ref_epoch());
+    assert_eq!(Epoch::GST_EPOCH, TimeScale::GST.ref_epoch());
+    assert_eq!(Epoch::BDT_EPOCH, TimeScale::BDT.ref_epoch());
+    assert_eq!(Epoch::J1900_TAI, TimeScale::TAI.ref_epoch());
+}
+
+#[test]
+fn test_epoch_min_max() {
+    assert!(Epoch::MIN < Epoch::MAX);
+    assert_eq!(Epoch::MIN.time_scale, TimeScale::TAI);
+    assert_eq!(Epoch::MAX.time_scale, TimeScale::TAI);
+    assert_eq!(Epoch::MIN.to_tai_duration(), Duration::MIN);
+    assert_eq!(Epoch::MAX.to_tai_duration(), Duration::MAX);
+    assert_eq!(Epoch::MIN.year(), -3_274_968);
+    assert_eq!(Epoch::MAX.year(), 3_278_767);
+
+    // Any epoch is within the bounds.
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    assert_eq!(epoch.clamp(Epoch::MIN, Epoch::MAX), epoch);
+    assert_eq!([Epoch::MAX, epoch].iter().min(), Some(&epoch));
+    assert_eq!([Epoch::MIN, epoch].iter().max(), Some(&epoch));
+
+    // Arithmetic saturates at the bounds.
+    assert_eq!(Epoch::MAX + 1.days(), Epoch::MAX);
+    assert_eq!(Epoch::MAX + Duration::MAX, Epoch::MAX);
+    assert_eq!(Epoch::MIN - 1.days(), Epoch::MIN);
+}
+
+#[test]
+fn test_tai_minus_utc() {
+    // Just after the first leap second announced by the IERS.
+    let epoch = Epoch::from_gregorian_utc_hms(1972, 1, 1, 0, 0, 1);
+    assert_eq!(epoch.tai_minus_utc(), 10.seconds());
+    assert_eq!(epoch.utc_minus_tai(), -10.seconds());
+
+    // Before 1972, only the IERS leap seconds are accounted for.
+    let epoch = Epoch::from_gregorian_utc_at_midnight(1971, 12, 31);
+    assert_eq!(epoch.tai_minus_utc(), Duration::ZERO);
+
+    // Around the 2017 leap second.
+    let before = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 0);
+    assert_eq!(before.tai_minus_utc(), 36.seconds());
+    let after = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    assert_eq!(after.tai_minus_utc(), 37.seconds());
+    assert_eq!(after.utc_minus_tai(), -37.seconds());
+    // The leap second itself, reached in TAI since UTC arithmetic would skip it.
+    let leap = Epoch::from_tai_duration(after.to_tai_duration() - 1.seconds());
+    assert_eq!(leap.tai_minus_utc(), 37.seconds());
+    assert_eq!(
+        after.to_tai_duration() - after.to_utc_duration(),
+        after.tai_minus_utc()
+    );
+
+    // The offset does not depend on the time scale of the epoch.
+    assert_eq!(
+        after.with_time_scale(TimeScale::GPST).tai_minus_utc(),
+        37.seconds()
+    );
+}
+
+#[test]
+fn test_time_scale_round_trip() {
+    use hifitime::leap_seconds::leap_seconds_table;
+
+    let mut epochs = vec![
+        Epoch::from_gregorian_tai_at_midnight(1600, 1, 1),
+        Epoch::from_gregorian_tai_at_midnight(1800, 1, 1) + 1.nanoseconds(),
+        Epoch::from_gregorian_tai_at_midnight(1850, 3, 14),
+        Epoch::from_gregorian_tai_at_midnight(1900, 1, 1) - 1.nanoseconds(),
+        Epoch::from_gregorian_tai_at_midnight(1900, 1, 1),
+        Epoch::from_gregorian_tai_hms(1959, 12, 31, 23, 59, 59),
+        Epoch::from_gregorian_tai_hms(1965, 6, 15, 12, 0, 0),
+        Epoch::from_gregorian_tai_at_noon(2000, 1, 1),
+        Epoch::from_gregorian_tai(2023, 7, 14, 1, 2, 3, 456_789_012),
+        Epoch::from_gregorian_tai_at_midnight(2150, 1, 1),
+        Epoch::from_gregorian_tai_at_midnight(2900, 1, 1),
+    ];
+    for (boundary, _, _) in leap_seconds_table() {
+        // Around the stored boundary of each leap second
+        for offset_ns in [-40_000_000_001, -1, 0, 1, 40_000_000_000] {
+            epochs.push(*boundary + Duration::from_total_nanoseconds(offset_ns));
+        }
+        // Around the UTC midnight following each leap second, including within the inserted leap second itself
+        let utc_midnight = Epoch::from_utc_duration(boundary.to_tai_duration());
+        for offset_ns in [-1_000_000_001, -1_000_000_000, -500_000_000, -1, 0, 1] {
+            epochs.push(utc_midnight + Duration::from_total_nanoseconds(offset_ns));
+        }
+    }
+
+    for ts in [
+        TimeScale::TAI,
+        TimeScale::TT,
+        TimeScale::ET,
+        TimeScale::TDB,
+        TimeScale::UTC,
+        TimeScale::GPST,
+        TimeScale::GST,
+        TimeScale::BDT,
+    ] {
+        // ET and TDB are computed with floating point values.
+        let tolerance_ns = if matches!(ts, TimeScale::ET | TimeScale::TDB) {
+            10
+        } else {
+            0
+        };
+        for epoch in &epochs {
+            let duration = epoch.to_duration_in_time_scale(ts);
+
+            let rebuilt = Epoch::from_duration(duration, ts);
+            let err_ns = (rebuilt.to_tai_duration() - epoch.to_tai_duration()).total_nanoseconds();
+            let (y, mm, dd, hh, min, s, nanos) = epoch.to_gregorian_utc();
+            if ts == TimeScale::UTC && s == 60 {
+                // An inserted leap second has no UTC duration of its own: it shares that of the last second of the day.
+                assert_eq!(
+                    rebuilt,
+                    Epoch::from_gregorian_utc(y, mm, dd, hh, min, 59, nanos),
+                    "{epoch:?}"
+                );
+                // With only the IERS leap seconds, UTC jumps by ten seconds at the start of 1972 (cf. below), so this
+                // step is explicitly excluded: it is reported as a single leap second, but lasts ten seconds of TAI.
+                if y != 1971 {
+                    assert_eq!(err_ns, -1_000_000_000, "{epoch:?}");
+                }
+            } else {
+                assert!(
+                    err_ns.abs() <= tolerance_ns,
+                    "{ts:?} {epoch:?}: {err_ns} ns"
+                );
+            }
+
+            let offset = 123.nanoseconds();
+            let reread = Epoch::from_duration(duration + offset, ts).to_duration_in_time_scale(ts);
+            let err_ns = (reread - duration - offset).total_nanoseconds();
+            assert!(
+                err_ns.abs() <= tolerance_ns,
+                "{ts:?} {epoch:?}: {err_ns} ns"
+            );
+        }
+    }
+
+    // The first nine seconds of the 1972 step are all reported as the start of the last second of 1971.
+    for seconds in 0..10 {
+        let epoch = Epoch::from_gregorian_tai_hms(1972, 1, 1, 0, 0, seconds);
+        assert_eq!(epoch.to_gregorian_utc(), (1971, 12, 31, 23, 59, 60, 0));
+    }
+    assert_eq!(
+        Epoch::from_gregorian_tai_hms(1972, 1, 1, 0, 0, 10).to_gregorian_utc(),
+        (1972, 1, 1, 0, 0, 0, 0)
+    );
+}
+
+#[test]
+fn test_epoch_floor_ceil_before_1900() {
+    // Epochs before 1900 are stored as negative durations: they must floor and ceil like those after it.
+    let e = Epoch::from_gregorian_tai_hms(1850, 5, 20, 17, 57, 43);
+    assert_eq!(
+        e.floor(1.hours()),
+        Epoch::from_gregorian_tai_hms(1850, 5, 20, 17, 0, 0)
+    );
+    assert_eq!(
+        e.ceil(1.hours()),
+        Epoch::from_gregorian_tai_hms(1850, 5, 20, 18, 0, 0)
+    );
+    assert_eq!(
+        e.round(1.hours()),
+        Epoch::from_gregorian_tai_hms(1850, 5, 20, 18, 0, 0)
+    );
+    assert_eq!(
+        e.floor(1.days()),
+        Epoch::from_gregorian_tai_at_midnight(1850, 5, 20)
+    );
+}
+
+#[test]
+fn test_truncate_to_day_hour_minute() {
+    let e = Epoch::from_gregorian_utc(2023, 3, 14, 15, 9, 26, 535_897_932);
+    assert_eq!(
+        e.truncate_to_day(),
+        Epoch::from_gregorian_utc_at_midnight(2023, 3, 14)
+    );
+    assert_eq!(
+        e.truncate_to_hour(),
+        Epoch::from_gregorian_utc_hms(2023, 3, 14, 15, 0, 0)
+    );
+    assert_eq!(
+        e.truncate_to_minute(),
+        Epoch::from_gregorian_utc_hms(2023, 3, 14, 15, 9, 0)
+    );
+
+    // The day of the leap second at the end of 2016 lasts one more second, so the days after it are not aligned
+    // on a multiple of 86400 UTC seconds from the days before it in TAI.
+    let midnight = Epoch::from_gregorian_utc_at_midnight(2016, 12, 31);
+    let next_midnight = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    assert_eq!(next_midnight - midnight, 1.days() + 1.seconds());
+    // The leap second itself, reached in TAI since UTC arithmetic skips it
+    let leap_second =
+        Epoch::from_tai_duration(next_midnight.to_tai_duration() - 500.milliseconds())
+            .with_time_scale(TimeScale::UTC);
+    assert_eq!(
+        leap_second.to_gregorian_utc(),
+        (2016, 12, 31, 23, 59, 60, 500_000_000)
+    );
+    assert_eq!(leap_second.truncate_to_day(), midnight);
+    assert_eq!(
+        leap_second.truncate_to_hour(),
+        Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 0, 0)
+    );
+    assert_eq!(
+        leap_second.truncate_to_minute(),
+        Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 0)
+    );
+    for e in [next_midnight, next_midnight + 1.nanoseconds()] {
+        assert_eq!(e.truncate_to_day(), next_midnight);
+        assert_eq!(e.truncate_to_hour(), next_midnight);
+        assert_eq!(e.truncate_to_minute(), next_midnight);
+    }
+    let later = next_midnight + 12.hours() + 34.minutes() + 56.seconds();
+    assert_eq!(later.truncate_to_day(), next_midnight);
+    assert_eq!(
+        later.truncate_to_hour(),
+        Epoch::from_gregorian_utc_hms(2017, 1, 1, 12, 0, 0)
+    );
+
+    // The truncation is in the time scale of the epoch, and keeps it.
+    let gpst = later.with_time_scale(TimeScale::GPST);
+    assert_eq!(
+        gpst.truncate_to_day(),
+        Epoch::from_gregorian_at_midnight(2017, 1, 1, TimeScale::GPST)
+    );
+    assert_eq!(gpst.truncate_to_minute().time_scale, TimeScale::GPST);
+    // Before 1900, the truncation does not round up.
+    let old = Epoch::from_gregorian_tai(1850, 5, 20, 17, 57, 43, 1);
+    assert_eq!(
+        old.truncate_to_minute(),
+        Epoch::from_gregorian_tai_hms(1850, 5, 20, 17, 57, 0)
+    );
+}
+
+#[test]
+fn test_leap_seconds_for_year() {
+    use hifitime::leap_seconds_for_year;
+
+    // Evaluated at compile time
+    const DELTA_AT_2020: i32 = leap_seconds_for_year(2020);
+    const _: () = assert!(DELTA_AT_2020 == 37);
+
+    for year in 1900..2100 {
+        let start = Epoch::from_gregorian_utc_at_midnight(year, 1, 1);
+        assert_eq!(
+            leap_seconds_for_year(year),
+            start.leap_seconds(true).unwrap_or(0.0) as i32,
+            "{year}"
+        );
+    }
+    // The leap second at the end of June only counts from the following year.
+    assert_eq!(leap_seconds_for_year(2015), 35);
+    assert_eq!(leap_seconds_for_year(2016), 36);
+    assert_eq!(leap_seconds_for_year(1800), 0);
+}
+
+#[test]
+fn test_same_second_and_day_as() {
+    let e = Epoch::from_gregorian(2023, 3, 14, 15, 9, 26, 100_000_000, TimeScale::TDB);
+    let other = e + 500.milliseconds();
+    assert_ne!(e, other);
+    assert!(e.same_second_as(other, TimeScale::TDB));
+    assert!(other.same_second_as(e, TimeScale::TDB));
+    assert!(e.same_day_as(other, TimeScale::TDB));
+    assert!(!e.same_second_as(e + 900.milliseconds(), TimeScale::TDB));
+    assert!(!e.same_second_as(e - 100_000_001.nanoseconds(), TimeScale::TDB));
+    // The comparison is in the provided time scale: TAI is about 32.184 seconds behind TDB, so these straddle a TAI second.
+    assert!(!e.same_second_as(other, TimeScale::TAI));
+    assert!(e.same_second_as(e + 1.nanoseconds(), TimeScale::ET));
+
+    // The leap second at the end of 2016 is its own second, but part of the last day of 2016.
+    let next_midnight = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    let leap_second = Epoch::from_tai_duration(next_midnight.to_tai_duration() - 1.seconds());
+    let last_second = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 500_000_000);
+    assert_eq!(
+        leap_second.to_gregorian_utc(),
+        (2016, 12, 31, 23, 59, 60, 0)
+    );
+    assert!(leap_second.same_second_as(leap_second + 999.milliseconds(), TimeScale::UTC));
+    assert!(!leap_second.same_second_as(last_second, TimeScale::UTC));
+    assert!(!leap_second.same_second_as(next_midnight, TimeScale::UTC));
+    assert!(leap_second.same_day_as(last_second, TimeScale::UTC));
+    assert!(leap_second.same_day_as(
+        Epoch::from_gregorian_utc_at_midnight(2016, 12, 31),
+        TimeScale::UTC
+    ));
+    assert!(!leap_second.same_day_as(next_midnight, TimeScale::UTC));
+    // In TAI, the leap second already is in 2017.
+    assert!(leap_second.same_day_as(next_midnight, TimeScale::TAI));
+}
+
+#[test]
+fn test_is_before_after_between() {
+    let start = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    let end = start + 1.days();
+
+    assert!(start.is_before(end));
+    assert!(!end.is_before(start));
+    assert!(!start.is_before(start));
+    assert!(end.is_after(start));
+    assert!(!start.is_after(end));
+    assert!(!start.is_after(start));
+
+    // The start is included and the end is excluded, like a range.
+    assert!(start.is_between(start, end));
+    assert!((start + 1.nanoseconds()).is_between(start, end));
+    assert!((end - 1.nanoseconds()).is_between(start, end));
+    assert!(!end.is_between(start, end));
+    assert!(!(start - 1.nanoseconds()).is_between(start, end));
+    assert!(!(end + 1.nanoseconds()).is_between(start, end));
+    for e in [start, start + 12.hours(), end] {
+        assert_eq!(e.is_between(start, end), (start..end).contains(&e));
+    }
+    // Empty and reversed intervals contain nothing.
+    assert!(!start.is_between(start, start));
+    assert!(!start.is_between(end, start));
+    assert!(!(start + 12.hours()).is_between(end, start));
+
+    // The comparison is on the instant, whatever the time scales.
+    let gpst = (start + 12.hours()).with_time_scale(TimeScale::GPST);
+    assert!(gpst.is_between(start, end.with_time_scale(TimeScale::TDB)));
+    assert!(start.with_time_scale(TimeScale::TT).is_between(start, end));
+    assert!(!start.with_time_scale(TimeScale::TT).is_before(start));
+}
+
+#[test]
+fn test_equation_of_time() {
+    // Meeus, Astronomical Algorithms, example 28.b
+    let e = Epoch::from_gregorian_at_midnight(1992, 10, 13, TimeScale::TT);
+    assert!((e.equation_of_time() - (13.minutes() + 42.7.seconds())).abs() < 1.seconds());
+
+    // Find the extrema over one year
+    let start = Epoch::from_gregorian_utc_at_noon(2023, 1, 1);
+    let (mut min, mut max) = ((start, Duration::MAX), (start, Duration::MIN));
+    for day in 0..365 {
+        let epoch = start + day * Unit::Day;
+        let eot = epoch.equation_of_time();
+        if eot < min.1 {
+            min = (epoch, eot);
+        }
+        if eot > max.1 {
+            max = (epoch, eot);
+        }
+    }
+    // About -14 minutes 13 seconds around February 11
+    assert_eq!(min.0.month(), 2);
+    assert!((10..=14).contains(&min.0.day()), "{}", min.0);
+    assert!(
+        (min.1 + 14.minutes() + 13.seconds()).abs() < 30.seconds(),
+        "{}",
+        min.1
+    );
+    // About +16 minutes 25 seconds around November 3
+    assert_eq!(max.0.month(), 11);
+    assert!((1..=5).contains(&max.0.day()), "{}", max.0);
+    assert!(
+        (max.1 - 16.minutes() - 25.seconds()).abs() < 30.seconds(),
+        "{}",
+        max.1
+    );
+}
+
+#[test]
+fn test_parsing_errors() {
+    for (input, expected) in [
+        ("2023-13-01T00:00:00 UTC", ParsingErrors::MonthOutOfRange),
+        ("2023-00-01T00:00:00 UTC", ParsingErrors::MonthOutOfRange),
+        ("2023-01-32T00:00:00 UTC", ParsingErrors::DayOutOfRange),
+        ("2023-01-00T00:00:00 UTC", ParsingErrors::DayOutOfRange),
+        ("2023-02-29T00:00:00 UTC", ParsingErrors::DayOutOfRange),
+        ("2023-04-31T12:00:00 UTC", ParsingErrors::DayOutOfRange),
+        ("2023-01-01T24:00:00 UTC", ParsingErrors::HourOutOfRange),
+        ("2023-01-01T12:60:00 UTC", ParsingErrors::MinuteOutOfRange),
+        ("2023-01-01T12:00:61 UTC", ParsingErrors::SecondOutOfRange),
+        // Only the last second of a day with a leap second may be 60
+        ("2023-01-01T23:59:60 UTC", ParsingErrors::SecondOutOfRange),
+        ("2016-12-31T23:58:60 UTC", ParsingErrors::SecondOutOfRange),
+        ("2023-01-01T12:00:00+24:00", ParsingErrors::InvalidTimeZone),
+        ("2023-01-01T12:00:00-05:60", ParsingErrors::InvalidTimeZone),
+        ("2023-01-01T12:00:00+05/30", ParsingErrors::InvalidTimeZone),
+        (
+            "2023/01/01T12:00:00 UTC",
+            ParsingErrors::InvalidSeparator('/'),
+        ),
+        (
+            "2023-01-01X12:00:00 UTC",
+            ParsingErrors::InvalidSeparator('X'),
+        ),
+        (
+            "2023-01-01T12-00:00 UTC",
+            ParsingErrors::InvalidSeparator('-'),
+        ),
+        (
+            "2023-01-01T12:00:00/123 UTC",
+            ParsingErrors::InvalidSeparator('/'),
+        ),
+    ] {
+        assert_eq!(
+            Epoch::from_gregorian_str(input),
+            Err(Errors::ParseError(expected)),
+            "{input}"
+        );
+    }
+
+    // Offsets of two digits hours are valid
+    assert_eq!(
+        Epoch::from_gregorian_str("2023-01-01T12:00:00+10:00").unwrap(),
+        Epoch::from_gregorian_utc_hms(2023, 1, 1, 2, 0, 0)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str("2023-01-01T12:00:00.5-10:30").unwrap(),
+        Epoch::from_gregorian_utc(2023, 1, 1, 22, 30, 0, 500_000_000)
+    );
+
+    // The leap second itself is valid
+    assert!(Epoch::from_gregorian_str("2016-12-31T23:59:60 UTC").is_ok());
+    assert!(Epoch::from_gregorian_str("2024-02-29T00:00:00 UTC").is_ok());
+
+    // The errors can be reported as is
+    assert_eq!(
+        format!(
+            "{}",
+            Epoch::from_gregorian_str("2023-13-01T00:00:00 UTC").unwrap_err()
+        ),
+        "ParseError: the month must be between 1 and 12"
+    );
+    assert_eq!(
+        format!("{}", Errors::ParseError(ParsingErrors::ISO8601)),
+        "ParseError: ISO8601"
+    );
+}
+
+#[test]
+fn test_instant_key() {
+    use std::collections::{BTreeMap, HashSet};
+
+    let e = Epoch::from_gregorian_utc(2023, 3, 14, 15, 9, 26, 535_897_932);
+    let scales = [
+        TimeScale::TAI,
+        TimeScale::TT,
+        TimeScale::ET,
+        TimeScale::TDB,
+        TimeScale::UTC,
+        TimeScale::GPST,
+        TimeScale::GST,
+        TimeScale::BDT,
+    ];
+    for ts in scales {
+        let other = e.with_time_scale(ts);
+        assert_eq!(other.time_scale, ts);
+        assert_eq!(other.instant_key(), e.instant_key());
+    }
+    assert_eq!(e.instant_key(), e.to_tai_duration().total_nanoseconds());
+    assert_ne!(e.instant_key(), (e + 1.nanoseconds()).instant_key());
+    // Before 1900, the key is negative
+    assert!(Epoch::from_gregorian_tai_at_midnight(1899, 12, 31).instant_key() < 0);
+    assert_eq!(
+        Epoch::from_gregorian_tai_at_midnight(1899, 12, 31).instant_key(),
+        -(1.days()).total_nanoseconds()
+    );
+
+    // The epochs are deduplicated by instant, whatever their time scale
+    let set: HashSet<Epoch> = scales.iter().map(|ts| e.with_time_scale(*ts)).collect();
+    assert_eq!(set.len(), 1);
+    let mut grouped: BTreeMap<i128, Vec<Epoch>> = BTreeMap::new();
+    for ts in scales {
+        grouped
+            .entry(e.with_time_scale(ts).instant_key())
+            .or_default()
+            .push(e.with_time_scale(ts));
+        grouped
+            .entry((e + 1.seconds()).with_time_scale(ts).instant_key())
+            .or_default()
+            .push(e.with_time_scale(ts));
+    }
+    assert_eq!(grouped.len(), 2);
+    assert!(grouped.values().all(|epochs| epochs.len() == scales.len()));
+}
+
+#[test]
+fn test_from_gregorian_fractional() {
+    let e = Epoch::from_gregorian_utc_fractional(2017, 1, 14, 0, 31, 55.811).unwrap();
+    assert_eq!(
+        e,
+        Epoch::from_gregorian_utc(2017, 1, 14, 0, 31, 55, 811_000_000)
+    );
+    assert_eq!(e.to_gregorian_utc(), (2017, 1, 14, 0, 31, 55, 811_000_000));
+
+    let tdb = Epoch::from_gregorian_fractional(2017, 1, 14, 0, 31, 55.811, TimeScale::TDB).unwrap();
+    assert_eq!(tdb.time_scale, TimeScale::TDB);
+    assert_eq!(
+        tdb,
+        Epoch::from_gregorian(2017, 1, 14, 0, 31, 55, 811_000_000, TimeScale::TDB)
+    );
+
+    // Rounded to the nearest nanosecond
+    for (seconds, second, nanos) in [
+        (0.0, 0, 0),
+        (0.000_000_000_4, 0, 0),
+        (0.000_000_000_6, 0, 1),
+        (12.5, 12, 500_000_000),
+        (59.999_999_999, 59, 999_999_999),
+        (59.999_999_999_6, 60, 0),
+    ] {
+        let result = Epoch::from_gregorian_utc_fractional(2016, 12, 31, 23, 59, seconds);
+        assert_eq!(
+            result.unwrap().to_gregorian_utc(),
+            (2016, 12, 31, 23, 59, second, nanos),
+            "{seconds}"
+        );
+    }
+
+    // Rounding up to 60 seconds is only valid during a leap second
+    assert_eq!(
+        Epoch::from_gregorian_utc_fractional(2017, 1, 14, 0, 31, 59.999_999_999_6),
+        Err(Errors::Carry)
+    );
+    for seconds in [-0.5, 61.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(
+            Epoch::from_gregorian_utc_fractional(2016, 12, 31, 23, 59, seconds),
+            Err(Errors::Carry),
+            "{seconds}"
+        );
+    }
+    assert_eq!(
+        Epoch::from_gregorian_utc_fractional(2017, 2, 29, 0, 0, 1.5),
+        Err(Errors::Carry)
+    );
+}
+
+#[test]
+fn test_with_nanoseconds() {
+    let epoch = Epoch::from_gregorian_utc(2022, 12, 1, 10, 11, 12, 13);
+    for nanos in [0, 1, 500_000_000, 999_999_999] {
+        let with = epoch.with_nanoseconds(nanos);
+        assert_eq!(with.nanoseconds_of_second(), nanos);
+        assert_eq!(with.time_scale, TimeScale::UTC);
+        assert_eq!(
+            with,
+            Epoch::from_gregorian_utc(2022, 12, 1, 10, 11, 12, nanos)
+        );
+    }
+    // Wraps into the following seconds
+    assert_eq!(
+        epoch.with_nanoseconds(2_000_000_001),
+        Epoch::from_gregorian_utc(2022, 12, 1, 10, 11, 14, 1)
+    );
+
+    // The leap second is kept
+    let leap = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 123);
+    let with = leap.with_nanoseconds(456);
+    assert_eq!(with.nanoseconds_of_second(), 456);
+    assert_eq!(
+        with,
+        Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 456)
+    );
+
+    // Other time scales
+    for ts in [TimeScale::TAI, TimeScale::GPST, TimeScale::TDB] {
+        let epoch = Epoch::from_gregorian(2023, 6, 30, 1, 2, 3, 4, ts);
+        let with = epoch.with_nanoseconds(789);
+        assert_eq!(with.nanoseconds_of_second(), 789);
+        assert_eq!(with, Epoch::from_gregorian(2023, 6, 30, 1, 2, 3, 789, ts));
+    }
+}
+
+#[test]
+fn test_add_f64_across_leap_second() {
+    // In UTC, the seconds are added to the UTC wall clock, which skips the leap second of 2016-12-31.
+    let before = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 500_000_000);
+    let after = Epoch::from_gregorian_utc(2017, 1, 1, 0, 0, 0, 500_000_000);
+    assert_eq!(before + 1.0, after);
+    assert_eq!(before + 1.0, before + 1.seconds());
+    assert_eq!(after - 1.0, before);
+    assert_eq!(after + -1.0, before);
+    let mut epoch = before;
+    epoch += 1.0;
+    assert_eq!(epoch, after);
+    epoch -= 1.0;
+    assert_eq!(epoch, before);
+    // ... whereas two seconds elapsed in TAI.
+    assert_eq!(after - before, 2.seconds());
+    assert_eq!((before + 1.0).time_scale, TimeScale::UTC);
+
+    // In TAI, the same instant advanced by one second is within the leap second.
+    let before_tai = before.with_time_scale(TimeScale::TAI);
+    let in_leap = before_tai + 1.0;
+    assert_eq!(in_leap - before, 1.seconds());
+    assert_eq!(
+        in_leap,
+        Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000)
+    );
+    assert_eq!(in_leap - 1.0, before);
+
+    // In a dynamical time scale, the seconds are added in that scale, which drifts slightly from TAI.
+    let tdb = before.with_time_scale(TimeScale::TDB);
+    let day_later = tdb + 86_400.0;
+    assert_eq!(
+        day_later.to_tdb_duration() - tdb.to_tdb_duration(),
+        1.days()
+    );
+    assert_eq!(day_later - 86_400.0, tdb);
+    assert_ne!(day_later - tdb, 1.days());
+}
+
+#[test]
+fn test_gregorian_round_trip() {
+    use hifitime::leap_seconds::leap_seconds_table;
+
+    // Pseudo-random epochs between 1850 and 2150 from a linear congruential generator, so the sweep is reproducible.
+    let start = Epoch::from_gregorian_tai_at_midnight(1850, 1, 1).to_tai_duration();
+    let span_ns = (300 * Unit::Century / 100).total_nanoseconds();
+    let mut state: u64 = 0x853c_49e6_748f_ea9b;
+    let mut epochs = Vec::new();
+    for _ in 0..2_000 {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        let offset_ns = i128::from(state >> 1) % span_ns;
+        epochs.push(Epoch::from_tai_duration(
+            start + Duration::from_total_nanoseconds(offset_ns),
+        ));
+    }
+    // Within and around each leap second
+    for (boundary, _, _) in leap_seconds_table() {
+        let utc_midnight = Epoch::from_utc_duration(boundary.to_tai_duration());
+        for offset_ns in [-1_000_000_001, -999_999_999, -500_000_000, -1, 0, 1] {
+            epochs.push(utc_midnight + Duration::from_total_nanoseconds(offset_ns));
+        }
+    }
+
+    for ts in [
+        TimeScale::TAI,
+        TimeScale::TT,
+        TimeScale::UTC,
+        TimeScale::GPST,
+        TimeScale::GST,
+        TimeScale::BDT,
+    ] {
+        for epoch in &epochs {
+            let greg = epoch.to_gregorian(ts);
+            assert_eq!(greg.to_epoch(), Ok(*epoch), "{ts:?} {epoch:?} {greg}");
+            // And the Gregorian representation is itself stable
+            assert_eq!(greg.to_epoch().unwrap().to_gregorian(ts), greg);
+        }
+    }
+
+    // ET and TDB are computed with floating point values.
+    for ts in [TimeScale::ET, TimeScale::TDB] {
+        for epoch in &epochs {
+            let rebuilt = epoch.to_gregorian(ts).to_epoch().unwrap();
+            assert!(
+                (rebuilt - *epoch).abs() <= 10.nanoseconds(),
+                "{ts:?} {epoch:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_duration_of_day() {
+    // Day ending with a leap second, including during that leap second
+    for epoch in [
+        Epoch::from_gregorian_utc_at_midnight(2016, 12, 31),
+        Epoch::from_gregorian_utc_at_noon(2016, 12, 31),
+        Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000),
+    ] {
+        assert_eq!(epoch.duration_of_day(), 86_401.seconds(), "{epoch}");
+    }
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_noon(1972, 6, 30).duration_of_day(),
+        86_401.seconds()
+    );
+    // Ordinary UTC days, including either side of the leap second
+    for epoch in [
+        Epoch::from_gregorian_utc_at_noon(2016, 12, 30),
+        Epoch::from_gregorian_utc_at_midnight(2017, 1, 1),
+        Epoch::from_gregorian_utc_at_noon(2023, 3, 14),
+    ] {
+        assert_eq!(epoch.duration_of_day(), 86_400.seconds(), "{epoch}");
+    }
+    // The leap seconds only matter in UTC
+    let epoch = Epoch::from_gregorian_utc_at_noon(2016, 12, 31);
+    for ts in [
+        TimeScale::TAI,
+        TimeScale::TT,
+        TimeScale::TDB,
+        TimeScale::GPST,
+        TimeScale::BDT,
+    ] {
+        assert_eq!(epoch.with_time_scale(ts).duration_of_day(), 1.days());
+    }
+    // Fraction of the day during the leap second
+    let epoch = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000);
+    let fraction = epoch.seconds_of_day() / epoch.duration_of_day().to_seconds();
+    assert!(fraction < 1.0 && fraction > 86_400.0 / 86_401.0);
+}
+
+#[test]
+fn test_to_et_duration_with_error() {
+    for epoch in [
+        Epoch::from_gregorian_utc_at_noon(1950, 6, 1),
+        Epoch::from_gregorian_utc_at_midnight(2000, 1, 1),
+        Epoch::from_gregorian_utc(2023, 3, 14, 15, 9, 26, 535_897_932),
+        Epoch::from_gregorian_tai_at_midnight(2100, 1, 1),
+    ] {
+        let (et, error) = epoch.to_et_duration_with_error();
+        assert_eq!(et, epoch.to_et_duration());
+        assert!(error >= Duration::ZERO);
+        assert!(error < 10.nanoseconds(), "{epoch}: {error}");
+        // And the round trip through ET is within a few nanoseconds
+        let rebuilt = Epoch::from_et_duration(et);
+        assert!((rebuilt - epoch).abs() <= 10.nanoseconds(), "{epoch}");
+    }
+}
+
+#[test]
+fn test_iso8601_ordinal() {
+    let e = Epoch::from_gregorian_utc_hms(2023, 2, 14, 0, 31, 55);
+    assert_eq!(e.to_iso8601_ordinal(), "2023-045T00:31:55 UTC");
+    for s in [
+        "2023-045T00:31:55Z",
+        "2023-045T00:31:55 UTC",
+        "2023-045 00:31:55",
+        "2023-045T01:31:55+01:00",
+    ] {
+        assert_eq!(Epoch::from_gregorian_str(s).unwrap(), e, "{s}");
+    }
+    assert_eq!(
+        Epoch::from_gregorian_str("2023-045").unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(2023, 2, 14)
+    );
+    // The usual calendar dates are still parsed as such
+    assert_eq!(
+        Epoch::from_gregorian_str("2023-04-05T00:00:00").unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(2023, 4, 5)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str("2023-04-5T00:00:00").unwrap(),
+        Epoch::from_gregorian_utc_at_midnight(2023, 4, 5)
+    );
+
+    // Last day of a leap year, with subseconds and in another time scale
+    let e = Epoch::from_gregorian_tai(2024, 12, 31, 23, 59, 59, 123_456_789);
+    assert_eq!(e.to_iso8601_ordinal(), "2024-366T23:59:59.123456789 TAI");
+    assert_eq!(
+        Epoch::from_gregorian_str(&e.to_iso8601_ordinal()).unwrap(),
+        e
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str("2024-060T00:00:00 TAI").unwrap(),
+        Epoch::from_gregorian_tai_at_midnight(2024, 2, 29)
+    );
+    // Leap second
+    let e = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 5);
+    assert_eq!(e.to_iso8601_ordinal(), "2016-366T23:59:60.000000005 UTC");
+    assert_eq!(
+        Epoch::from_gregorian_str(&e.to_iso8601_ordinal()).unwrap(),
+        e
+    );
+
+    // Round trip of every day of a leap year
+    let start = Epoch::from_gregorian_utc_at_noon(2020, 1, 1);
+    for day in 0..366 {
+        let e = start + day * Unit::Day;
+        let ordinal = e.to_iso8601_ordinal();
+        assert_eq!(&ordinal[5..8], format!("{:03}", day + 1));
+        assert_eq!(Epoch::from_gregorian_str(&ordinal).unwrap(), e);
+    }
+
+    // Negative years are padded after their sign
+    let e = Epoch::from_gregorian_tai_hms(-500, 3, 1, 12, 0, 0);
+    assert_eq!(e.to_iso8601_ordinal(), "-0500-060T12:00:00 TAI");
+    assert_eq!(
+        Epoch::from_gregorian_str(&e.to_iso8601_ordinal()).unwrap(),
+        e
+    );
+    let e = Epoch::from_gregorian_tai(-5, 12, 31, 23, 59, 59, 1);
+    assert_eq!(e.to_iso8601_ordinal(), "-0005-365T23:59:59.000000001 TAI");
+    assert_eq!(
+        Epoch::from_gregorian_str(&e.to_iso8601_ordinal()).unwrap(),
+        e
+    );
+
+    // Invalid days of the year
+    for s in ["2023-366T00:00:00", "2023-000T00:00:00", "2024-367"] {
+        assert_eq!(
+            Epoch::from_gregorian_str(s),
+            Err(Errors::ParseError(ParsingErrors::DayOutOfRange)),
+            "{s}"
+        );
+    }
+    assert_eq!(
+        Epoch::from_gregorian_str("2023-045X00:00:00"),
+        Err(Errors::ParseError(ParsingErrors::InvalidSeparator('X')))
+    );
+}
+
+#[test]
+fn test_to_gregorian_gnss() {
+    // GPST and GST are ahead of UTC by 18 leap seconds since 2017, and BDT by 4.
+    let e = Epoch::from_gregorian_utc(2023, 3, 14, 15, 9, 26, 535_897_932);
+    assert_eq!(e.to_gregorian_gpst(), (2023, 3, 14, 15, 9, 44, 535_897_932));
+    assert_eq!(e.to_gregorian_gst(), (2023, 3, 14, 15, 9, 44, 535_897_932));
+    assert_eq!(e.to_gregorian_bdt(), (2023, 3, 14, 15, 9, 30, 535_897_932));
+    // ... and always behind TAI by 19 and 33 seconds.
+    assert_eq!(e.to_gregorian_tai(), (2023, 3, 14, 15, 10, 3, 535_897_932));
+
+    // Crossing midnight
+    let e = Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 50);
+    assert_eq!(e.to_gregorian_gpst(), (2017, 1, 1, 0, 0, 7, 0));
+    assert_eq!(e.to_gregorian_bdt(), (2016, 12, 31, 23, 59, 53, 0));
+    // During the leap second, the GNSS clocks keep ticking normally.
+    let e = Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 60);
+    assert_eq!(e.to_gregorian_gpst(), (2017, 1, 1, 0, 0, 17, 0));
+    assert_eq!(e.to_gregorian_bdt(), (2017, 1, 1, 0, 0, 3, 0));
+
+    // The reference epochs are at midnight of their own clock.
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(1980, 1, 6).to_gregorian_gpst(),
+        (1980, 1, 6, 0, 0, 0, 0)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(1999, 8, 22).to_gregorian_gst(),
+        (1999, 8, 22, 0, 0, 13, 0)
+    );
+    assert_eq!(GST_REF_EPOCH.to_gregorian_gst(), (1999, 8, 22, 0, 0, 0, 0));
+    assert_eq!(GPST_REF_EPOCH.to_gregorian_gpst(), (1980, 1, 6, 0, 0, 0, 0));
+    assert_eq!(BDT_REF_EPOCH.to_gregorian_bdt(), (2006, 1, 1, 0, 0, 0, 0));
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(2006, 1, 1).to_gregorian_bdt(),
+        (2006, 1, 1, 0, 0, 0, 0)
+    );
+    // Week 2000 of GPS started on 2018-05-06 at midnight of the GPS clock.
+    let week_2000 = Epoch::from_gpst_duration(2000 * Unit::Week);
+    assert_eq!(week_2000.to_gregorian_gpst(), (2018, 5, 6, 0, 0, 0, 0));
+    assert_eq!(week_2000.to_gregorian_utc(), (2018, 5, 5, 23, 59, 42, 0));
+}
+
+#[test]
+fn test_debug_consistency() {
+    use hifitime::leap_seconds::leap_seconds_table;
+
+    let mut epochs = vec![
+        Epoch::from_gregorian_tai_at_midnight(1600, 1, 1),
+        Epoch::from_gregorian_tai_at_midnight(1900, 1, 1) - 1.nanoseconds(),
+        J1900_REF_EPOCH,
+        GPST_REF_EPOCH,
+        GST_REF_EPOCH,
+        BDT_REF_EPOCH,
+        Epoch::from_gregorian_tai_at_noon(2000, 1, 1),
+        Epoch::from_gregorian_tai(2023, 7, 14, 1, 2, 3, 456_789_012),
+        Epoch::from_gregorian_tai_at_midnight(2900, 1, 1),
+    ];
+    // Around and within each leap second
+    for (boundary, _, _) in leap_seconds_table() {
+        let utc_midnight = Epoch::from_utc_duration(boundary.to_tai_duration());
+        for offset_ns in [-1_000_000_001, -1_000_000_000, -500_000_000, -1, 0, 1] {
+            epochs.push(utc_midnight + Duration::from_total_nanoseconds(offset_ns));
+        }
+    }
+
+    for ts in [
+        TimeScale::TAI,
+        TimeScale::TT,
+        TimeScale::ET,
+        TimeScale::TDB,
+        TimeScale::UTC,
+        TimeScale::GPST,
+        TimeScale::GST,
+        TimeScale::BDT,
+    ] {
+        for epoch in &epochs {
+            let epoch = epoch.with_time_scale(ts);
+            assert_eq!(epoch.debug_consistency(), Ok(()), "{ts:?} {epoch:?}");
+        }
+    }
+}
+
+#[test]
+fn test_time_of_week_and_week_number() {
+    // GPS week 2243 started on Sunday 2023-01-01 at midnight GPST, i.e. 18 seconds before midnight UTC.
+    let e = Epoch::from_gregorian_utc(2023, 1, 4, 12, 34, 56, 789);
+    assert_eq!(e.week_number(TimeScale::GPST), 2243);
+    assert_eq!(
+        e.time_of_week(TimeScale::GPST),
+        3.days() + 12.hours() + 34.minutes() + 56.seconds() + 18.seconds() + 789.nanoseconds()
+    );
+    // Start of the week
+    let start = Epoch::from_gregorian_utc_hms(2022, 12, 31, 23, 59, 42);
+    assert_eq!(start.week_number(TimeScale::GPST), 2243);
+    assert_eq!(start.time_of_week(TimeScale::GPST), Duration::ZERO);
+    let end = start - 1.nanoseconds();
+    assert_eq!(end.week_number(TimeScale::GPST), 2242);
+    assert_eq!(
+        end.time_of_week(TimeScale::GPST),
+        1.weeks() - 1.nanoseconds()
+    );
+
+    // Consistent with the rolling time of week and its initializer
+    let (week, nanos) = e.with_time_scale(TimeScale::GPST).to_time_of_week();
+    assert_eq!(week, e.week_number(TimeScale::GPST));
+    assert_eq!(
+        nanos,
+        e.time_of_week(TimeScale::GPST).total_nanoseconds() as u64
+    );
+    for ts in [
+        TimeScale::GPST,
+        TimeScale::GST,
+        TimeScale::BDT,
+        TimeScale::TAI,
+    ] {
+        let tow = e.time_of_week(ts);
+        assert!(tow >= Duration::ZERO && tow < 1.weeks());
+        let rebuilt =
+            Epoch::from_time_of_week(e.week_number(ts), tow.total_nanoseconds() as u64, ts);
+        assert_eq!(rebuilt, e, "{ts:?}");
+        // Counted from the reference epoch of the time scale
+        assert_eq!(
+            (e - ts.ref_epoch()).total_nanoseconds(),
+            (i64::from(e.week_number(ts)) * Unit::Week + tow).total_nanoseconds(),
+            "{ts:?}"
+        );
+    }
+
+    // Before the reference epoch
+    let before = GPST_REF_EPOCH - 1.days();
+    assert_eq!(before.week_number(TimeScale::GPST), 0);
+    assert_eq!(before.time_of_week(TimeScale::GPST), 6.days());
+}
+
+#[test]
+fn test_gps_week_rollovers() {
+    for (k, (rollover, date)) in [
+        (GPST_WEEK_ROLLOVER_1999, (1999, 8, 22)),
+        (GPST_WEEK_ROLLOVER_2019, (2019, 4, 7)),
+        (GPST_WEEK_ROLLOVER_2038, (2038, 11, 21)),
+    ]
+    .iter()
+    .enumerate()
+    {
+        let count = k as u32 + 1;
+        assert_eq!(
+            *rollover,
+            GPST_REF_EPOCH + i64::from(1024 * count) * Unit::Week
+        );
+        assert_eq!(
+            rollover.to_gregorian_gpst(),
+            (date.0, date.1, date.2, 0, 0, 0, 0)
+        );
+        assert_eq!(rollover.week_number(TimeScale::GPST), 1024 * count);
+        assert_eq!(rollover.time_of_week(TimeScale::GPST), Duration::ZERO);
+        assert_eq!(rollover.gps_rollover_count(), count);
+        assert_eq!(
+            (*rollover - 1.nanoseconds()).gps_rollover_count(),
+            count - 1
+        );
+    }
+    assert_eq!(
+        GPST_WEEK_ROLLOVER_1999.to_gregorian_utc(),
+        (1999, 8, 21, 23, 59, 47, 0)
+    );
+    assert_eq!(
+        GPST_WEEK_ROLLOVER_2019.to_gregorian_utc(),
+        (2019, 4, 6, 23, 59, 42, 0)
+    );
+
+    assert_eq!(GPST_REF_EPOCH.gps_rollover_count(), 0);
+    assert!(Epoch::from_gregorian_utc_at_noon(2019, 4, 7).gps_rollover_count() >= 2);
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_noon(2024, 6, 1).gps_rollover_count(),
+        2
+    );
+}
+
+#[test]
+fn test_parse_any() {
+    use hifitime::BareNumber;
+
+    let e = Epoch::from_gregorian_utc(2022, 5, 2, 10, 39, 15, 123_000_000);
+    // ISO 8601 and RFC3339, regardless of the convention of the bare numbers
+    for bare in [
+        BareNumber::UnixSeconds,
+        BareNumber::JulianDays(TimeScale::TAI),
+        BareNumber::ModifiedJulianDays(TimeScale::UTC),
+    ] {
+        for s in [
+            "2022-05-02T10:39:15.123 UTC",
+            "2022-05-02T10:39:15.123Z",
+            "2022-05-02 12:39:15.123+02:00",
+            "2022-122T10:39:15.123",
+        ] {
+            assert_eq!(Epoch::parse_any(s, bare).unwrap(), e, "{s}");
+        }
+        // Format identifiers
+        assert_eq!(Epoch::parse_any("UNIX 1651487955.123", bare).unwrap(), e);
+        assert_eq!(
+            Epoch::parse_any("JD 2451545.0 TT", bare).unwrap(),
+            Epoch::from_jde_tt(2451545.0)
+        );
+        assert_eq!(
+            Epoch::parse_any("MJD 51544.5 TAI", bare).unwrap(),
+            Epoch::from_mjd_tai(51544.5)
+        );
+        assert_eq!(
+            Epoch::parse_any("SEC 0.5 TAI", bare).unwrap(),
+            Epoch::from_tai_seconds(0.5)
+        );
+    }
+
+    // Bare numbers
+    assert_eq!(
+        Epoch::parse_any("1651487955.123", BareNumber::UnixSeconds).unwrap(),
+        e
+    );
+    assert_eq!(
+        Epoch::parse_any(" -1.5 ", BareNumber::UnixSeconds).unwrap(),
+        Epoch::from_unix_seconds(-1.5)
+    );
+    // The exponent notation goes through an f64
+    assert_eq!(
+        Epoch::parse_any("1e9", BareNumber::UnixSeconds).unwrap(),
+        Epoch::from_unix_seconds(1e9)
+    );
+    assert_eq!(
+        Epoch::parse_any("1.5E3", BareNumber::UnixSeconds).unwrap(),
+        Epoch::from_unix_seconds(1500.0)
+    );
+    assert_eq!(
+        Epoch::parse_any("5.1544E4", BareNumber::ModifiedJulianDays(TimeScale::TAI)).unwrap(),
+        Epoch::from_mjd_tai(51544.0)
+    );
+    assert_eq!(
+        Epoch::parse_any("2451545", BareNumber::JulianDays(TimeScale::TT)).unwrap(),
+        Epoch::from_jde_tt(2451545.0)
+    );
+    assert_eq!(
+        Epoch::parse_any("2459701.94392503", BareNumber::JulianDays(TimeScale::UTC)).unwrap(),
+        Epoch::from_jde_utc(2459701.94392503)
+    );
+    assert_eq!(
+        Epoch::parse_any("51544.5", BareNumber::ModifiedJulianDays(TimeScale::TAI)).unwrap(),
+        Epoch::from_mjd_tai(51544.5)
+    );
+    // The convention resolves the ambiguity
+    assert_ne!(
+        Epoch::parse_any("2451545", BareNumber::UnixSeconds).unwrap(),
+        Epoch::parse_any("2451545", BareNumber::JulianDays(TimeScale::UTC)).unwrap()
+    );
+
+    // Neither a known format nor a number
+    assert!(Epoch::parse_any("yesterday", BareNumber::UnixSeconds).is_err());
+    assert!(Epoch::parse_any("2022-13-02T10:39:15", BareNumber::UnixSeconds).is_err());
+    assert!(Epoch::parse_any("", BareNumber::UnixSeconds).is_err());
+}
+
+#[test]
+fn test_round_to_nearest_leap_safe() {
+    let midnight = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    for (nanos, step) in [
+        (0, 1.seconds()),
+        (400_000_000, 1.seconds()),
+        (450_000_000, 100.milliseconds()),
+        (999_000_000, 1.seconds()),
+        (100_000_000, 1.minutes()),
+        (900_000_000, 1.hours()),
+    ] {
+        let e = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, nanos);
+        let rounded = e.round_to_nearest_leap_safe(step);
+        assert_eq!(rounded, midnight, "{e} {step}");
+        // A valid civil instant, less than a second after the epoch
+        assert_ne!(rounded.to_gregorian_utc().5, 60);
+        assert!(rounded > e && rounded - e <= 1.seconds());
+    }
+    // Unlike the plain rounding, which works on the UTC duration shared with the last second of the day
+    let e = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 400_000_000);
+    assert_eq!(
+        e.round(1.seconds()),
+        Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 59)
+    );
+
+    // Around the leap second, this is the plain rounding of the civil time.
+    for (e, step, expected) in [
+        (
+            Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 400_000_000),
+            1.seconds(),
+            Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 59),
+        ),
+        (
+            Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 600_000_000),
+            1.seconds(),
+            midnight,
+        ),
+        (
+            Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 31, 0),
+            1.minutes(),
+            midnight,
+        ),
+        (
+            Epoch::from_gregorian_utc(2017, 1, 1, 0, 0, 0, 400_000_000),
+            1.seconds(),
+            midnight,
+        ),
+    ] {
+        let rounded = e.round_to_nearest_leap_safe(step);
+        assert_eq!(rounded, expected, "{e} {step}");
+        assert_eq!(rounded, e.round(step));
+        assert_ne!(rounded.to_gregorian_utc().5, 60);
+    }
+
+    // Other time scales do not have leap seconds.
+    let e = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 400_000_000)
+        .with_time_scale(TimeScale::TAI);
+    assert_eq!(
+        e.round_to_nearest_leap_safe(1.seconds()),
+        e.round(1.seconds())
+    );
+}
+
+#[test]
+fn test_from_gregorian_normalized() {
+    let utc = |y, m, d, hh, mm, ss, ns| {
+        Epoch::from_gregorian_normalized(y, m, d, hh, mm, ss, ns, TimeScale::UTC)
+    };
+    // Day 32
+    assert_eq!(
+        utc(2023, 1, 32, 0, 0, 0, 0),
+        Epoch::from_gregorian_utc_at_midnight(2023, 2, 1)
+    );
+    // Hour 25
+    assert_eq!(
+        utc(2023, 1, 31, 25, 30, 0, 0),
+        Epoch::from_gregorian_utc_hms(2023, 2, 1, 1, 30, 0)
+    );
+    // Month 13
+    assert_eq!(
+        utc(2023, 13, 15, 12, 0, 0, 0),
+        Epoch::from_gregorian_utc_hms(2024, 1, 15, 12, 0, 0)
+    );
+    // Across February, in a leap year and not
+    assert_eq!(
+        utc(2024, 2, 30, 0, 0, 0, 0),
+        Epoch::from_gregorian_utc_at_midnight(2024, 3, 1)
+    );
+    assert_eq!(
+        utc(2023, 2, 29, 0, 0, 0, 0),
+        Epoch::from_gregorian_utc_at_midnight(2023, 3, 1)
+    );
+    assert_eq!(
+        utc(2024, 2, 28, 24, 0, 0, 0),
+        Epoch::from_gregorian_utc_at_midnight(2024, 2, 29)
+    );
+    // Every field overflowing, carrying into the next year
+    assert_eq!(
+        utc(2023, 12, 31, 23, 59, 59, 1_000_000_000),
+        Epoch::from_gregorian_utc_at_midnight(2024, 1, 1)
+    );
+    assert_eq!(
+        utc(2023, 11, 30, 47, 119, 119, 1_500_000_000),
+        Epoch::from_gregorian_utc(2023, 12, 2, 1, 1, 0, 500_000_000)
+    );
+    // Zero is the last day of the previous month, or the last month of the previous year
+    assert_eq!(
+        utc(2024, 3, 0, 0, 0, 0, 0),
+        Epoch::from_gregorian_utc_at_midnight(2024, 2, 29)
+    );
+    assert_eq!(
+        utc(2024, 0, 1, 0, 0, 0, 0),
+        Epoch::from_gregorian_utc_at_midnight(2023, 12, 1)
+    );
+    // Valid dates are unchanged, in any time scale
+    for ts in [TimeScale::TAI, TimeScale::GPST, TimeScale::TDB] {
+        assert_eq!(
+            Epoch::from_gregorian_normalized(2023, 6, 15, 12, 34, 56, 789, ts),
+            Epoch::from_gregorian(2023, 6, 15, 12, 34, 56, 789, ts)
+        );
+    }
+    // The strict constructor still rejects these
+    assert!(Epoch::maybe_from_gregorian(2023, 1, 32, 0, 0, 0, 0, TimeScale::UTC).is_err());
+}
+
+#[test]
+fn test_leap_seconds_in_range() {
+    let start = Epoch::from_gregorian_utc_at_midnight(2010, 1, 1);
+    let end = Epoch::from_gregorian_utc_at_midnight(2020, 1, 1);
+    let expected = [
+        (Epoch::from_gregorian_utc_at_midnight(2012, 7, 1), 35.0),
+        (Epoch::from_gregorian_utc_at_midnight(2015, 7, 1), 36.0),
+        (Epoch::from_gregorian_utc_at_midnight(2017, 1, 1), 37.0),
+    ];
+    assert_eq!(Epoch::leap_seconds_in_range(start, end), expected);
+    assert!(Epoch::leap_seconds_in_range_iter(start, end).eq(expected));
+    assert!(Epoch::leap_seconds_in_range_iter(start, end)
+        .rev()
+        .eq(expected.into_iter().rev()));
+
+    for (boundary, delta_at) in expected {
+        // The new offset applies from that midnight
+        assert_eq!(boundary.leap_seconds_iers(), delta_at as i32);
+        assert_eq!(
+            (boundary - 1.days()).leap_seconds_iers(),
+            delta_at as i32 - 1
+        );
+        assert_eq!(boundary.previous_leap_second(), Some(boundary));
+    }
+
+    // The range is half-open, and the time scale of the bounds does not matter.
+    let boundary = expected[2].0;
+    assert_eq!(
+        Epoch::leap_seconds_in_range(boundary, boundary + 1.days()),
+        [expected[2]]
+    );
+    assert!(Epoch::leap_seconds_in_range(boundary - 1.days(), boundary).is_empty());
+    assert_eq!(
+        Epoch::leap_seconds_in_range(
+            start.with_time_scale(TimeScale::TAI),
+            end.with_time_scale(TimeScale::GPST)
+        ),
+        expected
+    );
+    assert!(Epoch::leap_seconds_in_range(end, start).is_empty());
+
+    // Only the leap seconds announced by the IERS
+    let all = Epoch::leap_seconds_in_range(
+        Epoch::from_gregorian_utc_at_midnight(1960, 1, 1),
+        Epoch::from_gregorian_utc_at_midnight(2100, 1, 1),
+    );
+    assert_eq!(all.len(), 28);
+    assert_eq!(
+        all[0],
+        (Epoch::from_gregorian_utc_at_midnight(1972, 1, 1), 10.0)
+    );
+}
+
+#[test]
+fn test_since_j2000() {
+    let epochs = [
+        Epoch::from_gregorian_utc(2000, 1, 1, 12, 0, 0, 0),
+        Epoch::from_gregorian_utc_at_midnight(1972, 1, 1),
+        Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0),
+        Epoch::from_gregorian_tai(2023, 6, 15, 1, 2, 3, 456_789),
+        Epoch::from_gregorian_utc_at_midnight(1850, 3, 1),
+    ];
+    for e in epochs {
+        // Cross-check with the existing ad-hoc methods
+        assert_eq!(e.since_j2000(TimeScale::TT), e.to_tt_since_j2k(), "{e}");
+        assert_eq!(
+            e.since_j2000(TimeScale::TT).to_unit(Unit::Century),
+            e.to_tt_centuries_j2k()
+        );
+        assert_eq!(e.since_j2000(TimeScale::ET), e.to_et_duration());
+        assert_eq!(e.since_j2000(TimeScale::TDB), e.to_tdb_duration());
+        assert_eq!(
+            e.since_j2000(TimeScale::TDB).to_unit(Unit::Day),
+            e.to_tdb_days_since_j2000()
+        );
+        assert_eq!(e.since_j2000(TimeScale::TAI), e - Epoch::J2000_TAI);
+        // TT is TAI + 32.184 s on the same J1900 reference
+        assert_eq!(
+            e.since_j2000(TimeScale::TT) - e.since_j2000(TimeScale::TAI),
+            32_184.milliseconds()
+        );
+
+        // Round trips
+        for ts in [
+            TimeScale::TAI,
+            TimeScale::TT,
+            TimeScale::UTC,
+            TimeScale::GPST,
+            TimeScale::GST,
+            TimeScale::BDT,
+        ] {
+            let rebuilt = Epoch::from_since_j2000(e.since_j2000(ts), ts);
+            assert_eq!(rebuilt.time_scale, ts);
+            if ts != TimeScale::UTC || e.to_gregorian_utc().5 != 60 {
+                assert_eq!(rebuilt, e, "{e} {ts}");
+            }
+        }
+        for ts in [TimeScale::ET, TimeScale::TDB] {
+            let rebuilt = Epoch::from_since_j2000(e.since_j2000(ts), ts);
+            assert!((rebuilt - e).abs() < 10.nanoseconds(), "{e} {ts}");
+        }
+    }
+
+    // J2000 on the clock of each time scale is the noon in its own Gregorian representation.
+    for ts in [
+        TimeScale::TAI,
+        TimeScale::TT,
+        TimeScale::UTC,
+        TimeScale::ET,
+        TimeScale::TDB,
+    ] {
+        let noon = Epoch::from_gregorian(2000, 1, 1, 12, 0, 0, 0, ts);
+        assert!(noon.since_j2000(ts).abs() < 10.nanoseconds(), "{ts}");
+        assert_eq!(Epoch::from_since_j2000(Duration::ZERO, ts), noon);
+    }
+    assert_eq!(
+        Epoch::from_since_j2000(Duration::ZERO, TimeScale::TT),
+        Epoch::J2000_TAI - 32_184.milliseconds()
+    );
+    assert_eq!(
+        Epoch::from_since_j2000(Duration::ZERO, TimeScale::TAI),
+        Epoch::J2000_TAI
+    );
+}
+
+#[test]
+fn test_fixed_clock() {
+    // Application code parameterized over the clock
+    fn stamp(clock: &impl Clock) -> String {
+        format!("{}", Epoch::now_with(clock).unwrap())
+    }
+
+    let epoch = Epoch::from_gregorian_utc(2023, 3, 14, 15, 9, 26, 0);
+    let clock = FixedClock(epoch);
+    assert_eq!(clock.now().unwrap(), epoch);
+    assert_eq!(Epoch::now_with(&clock).unwrap(), epoch);
+    // Deterministic, unlike the system clock
+    assert_eq!(stamp(&clock), "2023-03-14T15:09:26 UTC");
+    assert_eq!(stamp(&clock), stamp(&clock));
+    assert_eq!((epoch - 1.hours()).elapsed_with(&clock).unwrap(), 1.hours());
+    assert_eq!((epoch + 1.days()).elapsed_with(&clock).unwrap(), -1.days());
+
+    // Also usable as a trait object, with the time scale of the fixed epoch preserved
+    let gpst = FixedClock(epoch.with_time_scale(TimeScale::GPST));
+    let clocks: [&dyn Clock; 2] = [&clock, &gpst];
+    for c in clocks {
+        assert_eq!(Epoch::now_with(&c).unwrap(), epoch);
+    }
+    assert_eq!(Epoch::now_with(&gpst).unwrap().time_scale, TimeScale::GPST);
+
+    // The system clock is the real time
+    #[cfg(feature = "std")]
+    {
+        use hifitime::SystemClock;
+
+        let before = Epoch::now().unwrap();
+        let now = Epoch::now_with(&SystemClock).unwrap();
+        assert!(now >= before);
+        assert!(before.elapsed_with(&SystemClock).unwrap() >= Duration::ZERO);
+    }
+}
+
+#[test]
+fn test_to_parts_in_scale() {
+    let epochs = [
+        Epoch::from_gregorian_utc_at_midnight(2023, 1, 1),
+        Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 59, 999_999_999),
+        Epoch::from_gregorian_tai(2150, 6, 15, 1, 2, 3, 456_789),
+        Epoch::from_gregorian_tai(1850, 3, 1, 12, 0, 0, 1),
+        GPST_REF_EPOCH,
+    ];
+    for e in epochs {
+        assert_eq!(e.to_parts_in_scale(TimeScale::TAI), e.to_tai_parts());
+        for ts in [
+            TimeScale::TAI,
+            TimeScale::TT,
+            TimeScale::ET,
+            TimeScale::TDB,
+            TimeScale::UTC,
+            TimeScale::GPST,
+            TimeScale::GST,
+            TimeScale::BDT,
+        ] {
+            let (centuries, nanoseconds) = e.to_parts_in_scale(ts);
+            let duration = Duration::from_parts(centuries, nanoseconds);
+            assert_eq!(duration, e.to_duration_in_time_scale(ts));
+            let rebuilt = Epoch::from_duration(duration, ts);
+            assert_eq!(rebuilt.time_scale, ts);
+            if matches!(ts, TimeScale::ET | TimeScale::TDB) {
+                assert!((rebuilt - e).abs() < 10.nanoseconds(), "{e} {ts}");
+            } else {
+                assert_eq!(rebuilt, e, "{e} {ts}");
+            }
+        }
+    }
+    // A leap second has the same UTC parts as the second before it
+    let leap = Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 60);
+    let before = Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 59);
+    assert_eq!(
+        leap.to_parts_in_scale(TimeScale::UTC),
+        before.to_parts_in_scale(TimeScale::UTC)
+    );
+    let (centuries, nanoseconds) = leap.to_parts_in_scale(TimeScale::UTC);
+    assert_eq!(
+        Epoch::from_duration(Duration::from_parts(centuries, nanoseconds), TimeScale::UTC),
+        before
+    );
+    // But its TAI parts are exact
+    let (centuries, nanoseconds) = leap.to_parts_in_scale(TimeScale::TAI);
+    assert_eq!(
+        Epoch::from_duration(Duration::from_parts(centuries, nanoseconds), TimeScale::TAI),
+        leap
+    );
+
+    // The parts are those of the epoch in that time scale
+    let e = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    assert_eq!(e.to_parts_in_scale(TimeScale::GPST).0, 0);
+    assert_eq!(e.to_parts_in_scale(TimeScale::UTC).0, 1);
+    assert_eq!(
+        e.to_parts_in_scale(TimeScale::TAI).1 - e.to_parts_in_scale(TimeScale::UTC).1,
+        37_000_000_000
+    );
+}
+
+#[test]
+fn test_with_time_scale() {
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2022, 9, 6);
+    for ts in [
+        TimeScale::TAI,
+        TimeScale::TT,
+        TimeScale::ET,
+        TimeScale::TDB,
+        TimeScale::UTC,
+        TimeScale::GPST,
+        TimeScale::GST,
+        TimeScale::BDT,
+    ] {
+        let relabeled = epoch.with_time_scale(ts);
+        // Relabeling does not change the instant
+        assert_eq!(relabeled.to_tai_duration(), epoch.to_tai_duration());
+        assert_eq!(relabeled, epoch);
+        assert_eq!(relabeled.time_scale, ts);
+        // But it changes how the duration is interpreted
+        assert_eq!(relabeled.to_duration(), epoch.duration_in(ts));
+        assert_eq!(epoch.duration_in(ts), epoch.to_duration_in_time_scale(ts));
+        #[allow(deprecated)]
+        {
+            assert_eq!(epoch.in_time_scale(ts), relabeled);
+            assert_eq!(epoch.in_time_scale(ts).time_scale, ts);
+        }
+    }
+    assert_eq!(
+        epoch.duration_in(TimeScale::TAI) - epoch.duration_in(TimeScale::UTC),
+        37 * Unit::Second
+    );
+}
+
+#[test]
+fn test_seconds_of_day() {
+    for ts in [
+        TimeScale::TAI,
+        TimeScale::UTC,
+        TimeScale::TT,
+        TimeScale::TDB,
+    ] {
+        let noon = Epoch::from_gregorian(2023, 3, 14, 12, 0, 0, 0, ts);
+        assert_eq!(noon.seconds_of_day(), 43_200.0);
+        assert_eq!(
+            noon.at_midnight(),
+            Epoch::from_gregorian(2023, 3, 14, 0, 0, 0, 0, ts)
+        );
+        assert_eq!(noon.at_midnight().time_scale, ts);
+
+        let just_before_midnight = Epoch::from_gregorian(2023, 3, 14, 23, 59, 59, 500_000_000, ts);
+        assert!((just_before_midnight.seconds_of_day() - 86_399.5).abs() < 1e-9);
+        assert_eq!(just_before_midnight.at_midnight(), noon.at_midnight());
+
+        let midnight = Epoch::from_gregorian(2023, 3, 15, 0, 0, 0, 0, ts);
+        assert_eq!(midnight.seconds_of_day(), 0.0);
+        assert_eq!(midnight.at_midnight(), midnight);
+    }
+
+    // Leap second days in UTC are one second longer
+    let leap = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000);
+    assert!((leap.seconds_of_day() - 86_400.5).abs() < 1e-9);
+    assert_eq!(
+        leap.at_midnight(),
+        Epoch::from_gregorian_utc_at_midnight(2016, 12, 31)
+    );
+    // But not in TAI
+    let leap_tai = leap.with_time_scale(TimeScale::TAI);
+    assert!(leap_tai.seconds_of_day() < 86_400.0);
+
+    // Before 1900
+    let epoch = Epoch::from_gregorian_tai(1850, 6, 1, 6, 0, 0, 0);
+    assert_eq!(epoch.seconds_of_day(), 21_600.0);
+}
+
+#[test]
+fn test_try_with_hms() {
+    let epoch = Epoch::from_gregorian_utc(2016, 12, 31, 10, 11, 12, 13);
+    assert_eq!(
+        epoch.try_with_hms(6, 0, 0).unwrap(),
+        Epoch::from_gregorian_utc_hms(2016, 12, 31, 6, 0, 0)
+    );
+    // Leap second day: second 60 is valid
+    let leap = epoch.try_with_hms(23, 59, 60).unwrap();
+    assert_eq!(leap, Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0));
+    assert_eq!(leap.to_gregorian_utc(), (2016, 12, 31, 23, 59, 60, 0));
+    assert_eq!(epoch.with_time_of_day(86_400 * Unit::Second).unwrap(), leap);
+    assert_eq!(
+        epoch
+            .with_time_of_day(86_400.5 * Unit::Second)
+            .unwrap()
+            .to_gregorian_utc(),
+        (2016, 12, 31, 23, 59, 60, 500_000_000)
+    );
+    assert_eq!(
+        epoch.with_time_of_day(86_401 * Unit::Second),
+        Err(Errors::Carry)
+    );
+    // But not on another day
+    let epoch = Epoch::from_gregorian_utc(2016, 12, 30, 10, 11, 12, 13);
+    assert_eq!(epoch.try_with_hms(23, 59, 60), Err(Errors::Carry));
+    assert_eq!(
+        epoch.with_time_of_day(86_400 * Unit::Second),
+        Err(Errors::Carry)
+    );
+
+    // Out of range values
+    assert_eq!(epoch.try_with_hms(24, 0, 0), Err(Errors::Carry));
+    assert_eq!(epoch.try_with_hms(12, 60, 0), Err(Errors::Carry));
+    assert_eq!(epoch.try_with_hms(12, 0, 61), Err(Errors::Carry));
+    assert_eq!(
+        epoch.with_time_of_day(-1 * Unit::Second),
+        Err(Errors::Carry)
+    );
+
+    // Other time scales keep their own date
+    let epoch = Epoch::from_gregorian(2023, 2, 28, 23, 0, 0, 0, TimeScale::TDB);
+    assert_eq!(
+        epoch.with_time_of_day(6 * Unit::Hour).unwrap(),
+        Epoch::from_gregorian(2023, 2, 28, 6, 0, 0, 0, TimeScale::TDB)
+    );
+}
+
+#[test]
+fn test_add_week() {
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2023, 2, 24);
+    assert_eq!(
+        epoch + Unit::Week,
+        Epoch::from_gregorian_utc_at_midnight(2023, 3, 3)
+    );
+    assert_eq!(
+        epoch - 2.weeks(),
+        Epoch::from_gregorian_utc_at_midnight(2023, 2, 10)
+    );
+}
@@ -0,0 +1,130 @@
+//! The leap seconds registered at runtime are global to the process, so these tests live in their own test binary.
+
+use core::str::FromStr;
+
+use hifitime::{register_leap_second, Epoch, Errors, ParsingErrors, TimeScale, TimeUnits};
+
+#[test]
+fn test_register_leap_second() {
+    let before = Epoch::from_gregorian_utc_at_midnight(2025, 6, 30);
+    let boundary = Epoch::from_gregorian_utc_at_midnight(2025, 7, 1);
+    let after = Epoch::from_gregorian_utc_at_midnight(2025, 7, 2);
+
+    // Before registration, there is no leap second at the end of 2025 June 30.
+    assert_eq!(after - before, 2.days());
+
+    // Leap seconds may only be appended to the built-in table.
+    assert_eq!(
+        register_leap_second(
+            Epoch::from_gregorian_utc_at_midnight(2017, 1, 1),
+            37.0,
+            true
+        ),
+        Err(Errors::LeapSecondOutOfOrder)
+    );
+    assert_eq!(
+        register_leap_second(
+            Epoch::from_gregorian_utc_at_midnight(2012, 7, 1),
+            38.0,
+            true
+        ),
+        Err(Errors::LeapSecondOutOfOrder)
+    );
+
+    // Register out of order to check that the table is kept sorted, and with a TAI boundary.
+    register_leap_second(
+        Epoch::from_gregorian_at_midnight(2030, 1, 1, TimeScale::TAI),
+        39.0,
+        true,
+    )
+    .unwrap();
+    register_leap_second(boundary, 38.0, true).unwrap();
+
+    // The epochs were initialized from UTC before registration, so only the UTC epochs after the boundary are changed.
+    let after = Epoch::from_gregorian_utc_at_midnight(2025, 7, 2);
+    assert_eq!(before.leap_seconds_iers(), 37);
+    assert_eq!(after.leap_seconds_iers(), 38);
+    assert_eq!(after - before, 2.days() + 1.seconds());
+
+    // The second preceding the boundary is now the leap second itself.
+    let boundary = Epoch::from_gregorian_utc_at_midnight(2025, 7, 1);
+    let leap = Epoch::from_tai_duration(boundary.to_tai_duration() - 1.seconds());
+    assert_eq!(leap.to_gregorian_utc(), (2025, 6, 30, 23, 59, 60, 0));
+    assert_eq!(
+        boundary.to_utc_duration(),
+        Epoch::from_gregorian_tai_at_midnight(2025, 7, 1).to_tai_duration()
+    );
+    assert_eq!(format!("{boundary}"), "2025-07-01T00:00:00 UTC");
+
+    // The registered leap second is displayed, built and parsed back like the built-in ones.
+    let leap = leap.with_time_scale(TimeScale::UTC);
+    assert_eq!(format!("{leap}"), "2025-06-30T23:59:60 UTC");
+    assert_eq!(
+        Epoch::maybe_from_gregorian_utc(2025, 6, 30, 23, 59, 60, 0),
+        Ok(leap)
+    );
+    assert_eq!(Epoch::from_gregorian_str(&format!("{leap}")), Ok(leap));
+    assert_eq!(Epoch::from_str(&format!("{leap}")), Ok(leap));
+    assert_eq!(
+        Epoch::from_gregorian_str("2025-06-30T23:59:60.5 UTC"),
+        Ok(Epoch::from_tai_duration(
+            leap.to_tai_duration() + 500.milliseconds()
+        ))
+    );
+    // But there still is no leap second on the other days.
+    assert_eq!(
+        Epoch::maybe_from_gregorian_utc(2025, 6, 29, 23, 59, 60, 0),
+        Err(Errors::Carry)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str("2025-12-31T23:59:60 UTC"),
+        Err(Errors::ParseError(ParsingErrors::SecondOutOfRange))
+    );
+
+    // The second registered leap second applies after its own boundary.
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(2029, 12, 31).leap_seconds_iers(),
+        38
+    );
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(2030, 1, 1).leap_seconds_iers(),
+        39
+    );
+
+    // Registering at the same boundary replaces the entry, and unannounced leap seconds are ignored by the IERS only methods.
+    register_leap_second(
+        Epoch::from_gregorian_utc_at_midnight(2030, 1, 1),
+        40.0,
+        false,
+    )
+    .unwrap();
+    let epoch = Epoch::from_gregorian_tai_at_midnight(2030, 6, 1);
+    assert_eq!(epoch.leap_seconds_iers(), 38);
+    assert_eq!(epoch.leap_seconds(false), Some(40.0));
+
+    // A negative leap second, i.e. a lower total offset than the previous one, removes the last second of the day.
+    // The 2030 entry is first restored to an announced 38 seconds, so that the offset decreases by a single second.
+    register_leap_second(
+        Epoch::from_gregorian_utc_at_midnight(2030, 1, 1),
+        38.0,
+        true,
+    )
+    .unwrap();
+    register_leap_second(
+        Epoch::from_gregorian_utc_at_midnight(2032, 1, 1),
+        37.0,
+        true,
+    )
+    .unwrap();
+    let before = Epoch::from_gregorian_utc_at_noon(2031, 12, 31);
+    let after = Epoch::from_gregorian_utc_at_noon(2032, 1, 1);
+    assert_eq!(before.leap_seconds_iers(), 38);
+    assert_eq!(after.leap_seconds_iers(), 37);
+    assert_eq!(after - before, 1.days() - 1.seconds());
+    assert_eq!(before.duration_of_day(), 86_399.seconds());
+    let boundary = Epoch::from_gregorian_utc_at_midnight(2032, 1, 1);
+    assert_eq!(
+        Epoch::from_tai_duration(boundary.to_tai_duration() - 1.seconds()).to_gregorian_utc(),
+        (2031, 12, 31, 23, 59, 58, 0)
+    );
+}
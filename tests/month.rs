@@ -0,0 +1,78 @@
+use core::str::FromStr;
+
+use hifitime::{Epoch, Month, MonthName};
+
+#[test]
+fn test_month_numbers() {
+    for (i, month) in [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ]
+    .iter()
+    .enumerate()
+    {
+        let num = i as u8 + 1;
+        assert_eq!(month.as_u8(), num);
+        assert_eq!(u8::from(*month), num);
+        assert_eq!(Month::from_u8(num), Some(*month));
+        assert_eq!(Month::from(num), *month);
+        assert_eq!(Month::from(num + 12), *month);
+        assert_eq!(MonthName::from_str(month.name()), Ok(*month));
+        assert_eq!(format!("{month}"), month.name());
+    }
+
+    assert_eq!(Month::from_u8(0), None);
+    assert_eq!(Month::from_u8(13), None);
+    assert_eq!(Month::from(0), Month::January);
+}
+
+#[test]
+fn test_month_lengths() {
+    // 2000 is a leap year, but 1900 is not
+    assert_eq!(Month::February.length(2000), 29);
+    assert_eq!(Month::February.length(1900), 28);
+    assert_eq!(Month::February.length(2024), 29);
+    assert_eq!(Month::February.length(2023), 28);
+    assert_eq!(Month::January.length(2000), 31);
+    assert_eq!(Month::April.length(2000), 30);
+    assert_eq!(Month::December.length(1900), 31);
+
+    for year in [1900, 2000, 2023, 2024] {
+        let mut days = 0;
+        for num in 1..=12 {
+            let month = Month::from_u8(num).unwrap();
+            assert_eq!(month.days_since_year_start(year), days);
+            days += u16::from(month.length(year));
+        }
+        let expected = if year % 400 == 0 || (year % 4 == 0 && year % 100 != 0) {
+            366
+        } else {
+            365
+        };
+        assert_eq!(days, expected);
+    }
+    assert_eq!(Month::March.days_since_year_start(2000), 60);
+    assert_eq!(Month::March.days_since_year_start(1900), 59);
+}
+
+#[test]
+fn test_epoch_month_name() {
+    assert_eq!(
+        Epoch::from_gregorian_tai_at_midnight(2022, 12, 24).month_name(),
+        Month::December
+    );
+    assert_eq!(
+        Epoch::from_gregorian_tai_at_midnight(2022, 1, 24).month_name(),
+        Month::January
+    );
+}
@@ -0,0 +1,44 @@
+extern crate hifitime;
+
+// Only the prelude is imported: this test fails to compile if it no longer provides the common types and traits.
+use hifitime::prelude::*;
+
+#[test]
+fn test_prelude_trait_methods() {
+    // TimeUnits
+    assert_eq!(1.days(), Unit::Day * 1);
+    assert_eq!(1.5.hours(), 90.minutes());
+    assert_eq!(2.seconds() + 500.milliseconds(), 2.5.seconds());
+    assert_eq!(1.microseconds(), 1_000.nanoseconds());
+    assert_eq!(1.centuries(), 36_525.days());
+    // Frequencies
+    assert_eq!(2.Hz(), 500.milliseconds());
+    assert_eq!(1.kHz(), Freq::KiloHertz * 1);
+
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2023, 1, 31);
+    assert_eq!(
+        epoch + 1.days() + 12 * Unit::Hour,
+        Epoch::from_gregorian_utc_at_noon(2023, 2, 1)
+    );
+    assert_eq!(epoch.time_scale, TimeScale::UTC);
+    assert_eq!(epoch.weekday(), Weekday::Tuesday);
+    assert_eq!(epoch.month_name(), MonthName::January);
+    assert_eq!(epoch.month_name(), Month::January);
+    assert_eq!(epoch.to_gregorian(TimeScale::UTC).to_epoch(), Ok(epoch));
+    assert_eq!(epoch.monthly(2).count(), 2);
+    assert_eq!(
+        TimeSeries::exclusive(epoch, epoch + 1.days(), 1.hours()).count(),
+        24
+    );
+    assert_eq!(1.5.seconds().round(1.seconds()), 2.seconds());
+    assert!(matches!(
+        "2023-13-01T00:00:00 UTC".parse::<Epoch>(),
+        Err(Errors::ParseError(ParsingErrors::MonthOutOfRange))
+    ));
+    assert_eq!(
+        format!("{}", EpochFormatter::new(TimeScale::UTC).format(epoch)),
+        "2023-01-31T00:00:00 UTC"
+    );
+    let _: LocalTime = epoch.to_local(-5.hours());
+    let _: RoundMode = RoundMode::HalfEven;
+}
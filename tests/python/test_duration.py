@@ -0,0 +1,60 @@
+'''
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2022 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+'''
+
+import pytest
+
+from hifitime import Duration, Epoch, Unit
+
+
+def test_epoch_difference():
+    start = Epoch("2023-03-01T00:00:00 UTC")
+    end = Epoch("2023-03-02T06:00:00 UTC")
+    elapsed = end - start
+    assert elapsed == Unit.Hour * 30.0
+    assert end - elapsed == start
+
+
+def test_duration_operators():
+    day = Unit.Day * 1.0
+    hour = Unit.Hour * 1.0
+
+    assert day + hour == Unit.Hour * 25.0
+    assert day - hour == Unit.Hour * 23.0
+    assert hour * 1.5 == Unit.Minute * 90.0
+    assert 1.5 * hour == Unit.Minute * 90.0
+    assert day / 4.0 == Unit.Hour * 6.0
+    assert day / hour == 24.0
+    assert hour / day == 1.0 / 24.0
+    # Opposite durations compare equal, so the signs are checked on the nanoseconds
+    assert (-hour).total_nanoseconds() == (Unit.Hour * -1.0).total_nanoseconds()
+    assert (-hour).total_nanoseconds() == -hour.total_nanoseconds()
+    assert abs(-hour).total_nanoseconds() == hour.total_nanoseconds()
+    assert abs(hour).total_nanoseconds() == hour.total_nanoseconds()
+    assert (-Duration.zero()).total_nanoseconds() == 0
+
+
+def test_duration_division_by_zero():
+    hour = Unit.Hour * 1.0
+    with pytest.raises(ZeroDivisionError):
+        hour / Duration.zero()
+    with pytest.raises(ZeroDivisionError):
+        hour / 0.0
+
+
+def test_duration_comparisons():
+    hour = Unit.Hour * 1.0
+    minute = Unit.Minute * 1.0
+    assert minute < hour
+    assert minute <= hour
+    assert hour > minute
+    assert hour >= minute
+    assert hour != minute
+    assert hour == Unit.Minute * 60.0
+    assert -hour < minute
@@ -0,0 +1,73 @@
+'''
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2022 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+'''
+
+import pickle
+from datetime import datetime, timedelta, timezone
+
+from hifitime import Duration, Epoch, Unit, Weekday
+
+
+def test_datetime_round_trip():
+    epoch = Epoch("2023-03-01T12:34:56.123456789 UTC")
+    dt = epoch.to_datetime()
+    assert dt == datetime(2023, 3, 1, 12, 34, 56, 123456, tzinfo=timezone.utc)
+    assert dt.tzinfo == timezone.utc
+
+    # Python's datetime only has a microsecond precision.
+    rebuilt = Epoch.init_from_datetime(dt)
+    assert rebuilt <= epoch
+    assert epoch - Unit.Microsecond * 1.0 < rebuilt
+
+
+def test_datetime_time_zones():
+    expected = Epoch("2023-03-01T17:00:00 UTC")
+    # Aware datetimes are converted to UTC.
+    eastern = timezone(timedelta(hours=-5))
+    assert Epoch.init_from_datetime(datetime(2023, 3, 1, 12, tzinfo=eastern)) == expected
+    # Naive datetimes are treated as UTC.
+    assert Epoch.init_from_datetime(datetime(2023, 3, 1, 17)) == expected
+
+
+def test_hash():
+    epoch = Epoch("2023-03-01T12:34:56.123456789 UTC")
+    same_instant = Epoch.init_from_tai_parts(*epoch.to_tai_parts())
+    assert same_instant == epoch
+    # Equality and hashing ignore the time scale.
+    assert hash(same_instant) == hash(epoch)
+    assert len({epoch, same_instant, epoch + Unit.Second * 1.0}) == 2
+    assert {epoch: "launch"}[same_instant] == "launch"
+
+    duration = Unit.Day * 1.5
+    assert hash(duration) == hash(Unit.Hour * 36.0)
+    assert len({duration, Unit.Hour * 36.0, Unit.Hour * 35.0}) == 2
+
+
+def test_pickle():
+    epoch = Epoch("2023-03-01T12:34:56.123456789 GPST")
+    rebuilt = pickle.loads(pickle.dumps(epoch))
+    assert rebuilt == epoch
+    assert rebuilt.to_tai_parts() == epoch.to_tai_parts()
+    # The time scale is restored too.
+    assert rebuilt.__getstate__() == epoch.__getstate__()
+    assert rebuilt.to_duration() == epoch.to_duration()
+
+    for duration in [Unit.Day * 1.5, Duration.min_negative(), Duration.init_from_max()]:
+        rebuilt = pickle.loads(pickle.dumps(duration))
+        assert rebuilt == duration
+        assert rebuilt.to_parts() == duration.to_parts()
+
+
+def test_gregorian_and_weekday():
+    epoch = Epoch("2023-03-01T12:34:56.123456789 UTC")
+    assert epoch.gregorian_utc() == (2023, 3, 1, 12, 34, 56, 123456789)
+    # TAI is 37 seconds ahead of UTC in 2023.
+    assert epoch.gregorian_tai() == (2023, 3, 1, 12, 35, 33, 123456789)
+    assert epoch.weekday() == Weekday.Wednesday
+    assert int(epoch.weekday()) == 2
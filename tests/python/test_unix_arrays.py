@@ -0,0 +1,53 @@
+'''
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2022 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. AUTHORS.md)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+'''
+
+from array import array
+from time import perf_counter
+
+from hifitime import Epoch
+
+# One timestamp every 15 minutes for a year, i.e. a typical column of telemetry timestamps.
+UNIX_SECONDS = [1_672_531_200.0 + 900.0 * i for i in range(35_040)]
+
+
+def test_unix_seconds_array_round_trip():
+    epochs = Epoch.init_from_unix_seconds_array(array("d", UNIX_SECONDS))
+    assert len(epochs) == len(UNIX_SECONDS)
+    assert epochs[0] == Epoch("2023-01-01T00:00:00 UTC")
+    assert epochs[-1] == Epoch("2023-12-31T23:45:00 UTC")
+    assert Epoch.to_unix_seconds_array(epochs) == UNIX_SECONDS
+
+
+def test_unix_seconds_array_numpy():
+    try:
+        import numpy as np
+    except ImportError:
+        return
+    seconds = np.array(UNIX_SECONDS, dtype=np.float64)
+    epochs = Epoch.init_from_unix_seconds_array(seconds)
+    assert np.array_equal(np.array(Epoch.to_unix_seconds_array(epochs)), seconds)
+
+
+def test_unix_seconds_array_speed():
+    seconds = array("d", UNIX_SECONDS)
+
+    start = perf_counter()
+    looped = [Epoch.init_from_unix_seconds(s) for s in seconds]
+    looped_s = [e.to_unix_seconds() for e in looped]
+    loop_duration = perf_counter() - start
+
+    start = perf_counter()
+    batched = Epoch.init_from_unix_seconds_array(seconds)
+    batched_s = Epoch.to_unix_seconds_array(batched)
+    batch_duration = perf_counter() - start
+
+    assert batched == looped
+    assert batched_s == looped_s
+    print(f"Python loop: {loop_duration * 1e3:.1f} ms, array helpers: {batch_duration * 1e3:.1f} ms")
@@ -60,6 +60,26 @@ fn test_is_gnss() {
     assert!(!ts.is_gnss());
 }
 
+#[test]
+fn test_classification() {
+    // (time scale, is_gnss, is_dynamical, uses_leap_seconds)
+    let expected = [
+        (TimeScale::TAI, false, false, false),
+        (TimeScale::TT, false, true, false),
+        (TimeScale::ET, false, true, false),
+        (TimeScale::TDB, false, true, false),
+        (TimeScale::UTC, false, false, true),
+        (TimeScale::GPST, true, false, false),
+        (TimeScale::GST, true, false, false),
+        (TimeScale::BDT, true, false, false),
+    ];
+    for (ts, gnss, dynamical, leap) in expected {
+        assert_eq!(ts.is_gnss(), gnss, "{ts}");
+        assert_eq!(ts.is_dynamical(), dynamical, "{ts}");
+        assert_eq!(ts.uses_leap_seconds(), leap, "{ts}");
+    }
+}
+
 #[test]
 fn test_default() {
     assert_eq!(TimeScale::default(), TimeScale::TAI);
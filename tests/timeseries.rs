@@ -1,6 +1,6 @@
 extern crate hifitime;
 
-use hifitime::{Epoch, TimeSeries, TimeUnits, Unit};
+use hifitime::{Epoch, MonthlySeries, TimeScale, TimeSeries, TimeUnits, Unit};
 
 #[test]
 fn test_timeseries() {
@@ -120,3 +120,74 @@ fn gh154_reciprocity() {
         assert_eq!(rebuilt, epoch, "got: {rebuilt:x}\nexp: {epoch:x}");
     }
 }
+
+#[test]
+fn test_monthly_from_31st() {
+    // The 31st is remembered across the short months, and February is clamped according to leap years.
+    let start = Epoch::from_gregorian_utc(2023, 12, 31, 10, 20, 30, 40);
+    let expected_days = [31, 31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    let months: Vec<Epoch> = Epoch::monthly(start, 13).collect();
+    assert_eq!(months.len(), 13);
+    for (k, (epoch, day)) in months.iter().zip(expected_days).enumerate() {
+        let (y, mm, dd, hh, min, s, ns) = (
+            epoch.year(),
+            epoch.month(),
+            epoch.day(),
+            epoch.hours(),
+            epoch.minutes(),
+            epoch.seconds(),
+            epoch.nanoseconds_of_second(),
+        );
+        let (exp_y, exp_mm) = if k == 0 { (2023, 12) } else { (2024, k as u8) };
+        assert_eq!((y, mm, dd), (exp_y, exp_mm, day), "month #{k}");
+        assert_eq!((hh, min, s, ns), (10, 20, 30, 40), "month #{k}");
+        assert_eq!(epoch.time_scale, TimeScale::UTC);
+    }
+    assert_eq!(start.whole_months_until(months[12]), 12);
+
+    // Common year
+    let start = Epoch::from_gregorian_utc_at_midnight(2023, 1, 31);
+    let mut monthly = start.monthly(3);
+    assert_eq!(monthly.len(), 3);
+    assert_eq!(monthly.next(), Some(start));
+    assert_eq!(
+        monthly.next(),
+        Some(Epoch::from_gregorian_utc_at_midnight(2023, 2, 28))
+    );
+    assert_eq!(
+        monthly.next(),
+        Some(Epoch::from_gregorian_utc_at_midnight(2023, 3, 31))
+    );
+    assert_eq!(monthly.next(), None);
+
+    // Double ended
+    let forward: Vec<Epoch> = MonthlySeries::new(start, 12).collect();
+    let mut backward: Vec<Epoch> = start.monthly(12).rev().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+    assert_eq!(start.monthly(0).next(), None);
+}
+
+#[test]
+fn test_monthly_time_scales() {
+    let start = Epoch::from_gregorian(2020, 8, 31, 12, 0, 0, 0, TimeScale::GPST);
+    let months: Vec<Epoch> = start.monthly(4).collect();
+    assert_eq!(
+        months,
+        [
+            start,
+            Epoch::from_gregorian(2020, 9, 30, 12, 0, 0, 0, TimeScale::GPST),
+            Epoch::from_gregorian(2020, 10, 31, 12, 0, 0, 0, TimeScale::GPST),
+            Epoch::from_gregorian(2020, 11, 30, 12, 0, 0, 0, TimeScale::GPST),
+        ]
+    );
+
+    // A start on a leap second is clamped on the days without one.
+    let leap = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 0);
+    let months: Vec<Epoch> = leap.monthly(2).collect();
+    assert_eq!(months[0], leap);
+    assert_eq!(
+        months[1],
+        Epoch::from_gregorian_utc(2017, 1, 31, 23, 59, 59, 0)
+    );
+}
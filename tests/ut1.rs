@@ -47,3 +47,20 @@ fn test_ut1_from_jpl() {
         "2022-01-03T03:05:06.679020600 TAI"
     );
 }
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_gmst_ut1() {
+    use hifitime::ut1::Ut1Provider;
+
+    let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+
+    let epoch = Epoch::from_str("2022-01-03 03:05:06.7891").unwrap();
+    let ut1_minus_utc_s =
+        (epoch.to_ut1_duration(provider.clone()) - epoch.to_utc_duration()).to_seconds();
+    // Sidereal time advances by about 15.04 arcseconds per second of UT1
+    let expected = (ut1_minus_utc_s * 360.985_647_366_29 / 86_400.0).to_radians();
+    let delta = epoch.gmst_ut1(provider) - epoch.gmst();
+    assert!((delta - expected).abs() < 1e-9, "{delta} != {expected}");
+    assert!(delta < 0.0);
+}